@@ -7,6 +7,13 @@ use crate::types::{GlobalMetrics, Incentive, Material, Participant, Role, WasteT
 #[contract]
 pub struct ScavengerContract;
 
+/// How an incentive's reward for a material is split between recipients
+struct Distribution {
+    total_reward: i128,
+    charity: Option<(Address, i128)>,
+    payouts: Vec<(Address, i128)>,
+}
+
 #[contractimpl]
 impl ScavengerContract {
     /// Initialize the contract with admin and configuration
@@ -60,6 +67,11 @@ impl ScavengerContract {
         Storage::get_owner_percentage(env).expect("Owner percentage not set")
     }
 
+    /// Get the charity percentage
+    pub fn get_charity_percentage(env: &Env) -> u32 {
+        Storage::get_charity_percentage(env)
+    }
+
     /// Get the total tokens earned
     pub fn get_total_earned(env: &Env) -> i128 {
         Storage::get_total_earned(env)
@@ -87,6 +99,31 @@ impl ScavengerContract {
         Storage::set_token_address(env, &new_address);
     }
 
+    /// Cap the cumulative tokens distributed through rewards (admin only)
+    pub fn set_reward_cap(env: &Env, admin: Address, max_total_distributed: i128) {
+        Self::require_admin(env, &admin);
+        assert!(max_total_distributed >= 0, "Reward cap cannot be negative");
+        Storage::set_reward_cap(env, max_total_distributed);
+    }
+
+    /// Get the reward cap, if one is configured
+    pub fn get_reward_cap(env: &Env) -> Option<i128> {
+        Storage::get_reward_cap(env)
+    }
+
+    /// Limit how many collectors in a transfer chain share a reward (admin only)
+    /// Only the first `max_collectors` collectors are paid; the rest of their
+    /// shares go to the current owner
+    pub fn set_max_collectors(env: &Env, admin: Address, max_collectors: u32) {
+        Self::require_admin(env, &admin);
+        Storage::set_max_collectors(env, max_collectors);
+    }
+
+    /// Get the collector chain cap, if one is configured
+    pub fn get_max_collectors(env: &Env) -> Option<u32> {
+        Storage::get_max_collectors(env)
+    }
+
     /// Update the charity address (admin only)
     pub fn update_charity_address(env: &Env, admin: Address, new_address: Address) {
         Self::require_admin(env, &admin);
@@ -98,8 +135,9 @@ impl ScavengerContract {
         Self::require_admin(env, &admin);
         
         let owner_pct = Storage::get_owner_percentage(env).expect("Owner percentage not set");
+        let charity_pct = Storage::get_charity_percentage(env);
         assert!(
-            new_percentage + owner_pct <= 100,
+            new_percentage + owner_pct + charity_pct <= 100,
             "Total percentages cannot exceed 100"
         );
         
@@ -112,8 +150,9 @@ impl ScavengerContract {
         
         let collector_pct = Storage::get_collector_percentage(env)
             .expect("Collector percentage not set");
+        let charity_pct = Storage::get_charity_percentage(env);
         assert!(
-            collector_pct + new_percentage <= 100,
+            collector_pct + new_percentage + charity_pct <= 100,
             "Total percentages cannot exceed 100"
         );
         
@@ -129,8 +168,9 @@ impl ScavengerContract {
     ) {
         Self::require_admin(env, &admin);
         
+        let charity_pct = Storage::get_charity_percentage(env);
         assert!(
-            collector_percentage + owner_percentage <= 100,
+            collector_percentage + owner_percentage + charity_pct <= 100,
             "Total percentages cannot exceed 100"
         );
         
@@ -138,6 +178,21 @@ impl ScavengerContract {
         Storage::set_owner_percentage(env, owner_percentage);
     }
 
+    /// Update the share of rewards routed to charity (admin only)
+    pub fn update_charity_percentage(env: &Env, admin: Address, new_percentage: u32) {
+        Self::require_admin(env, &admin);
+
+        let collector_pct = Storage::get_collector_percentage(env)
+            .expect("Collector percentage not set");
+        let owner_pct = Storage::get_owner_percentage(env).expect("Owner percentage not set");
+        assert!(
+            collector_pct + owner_pct + new_percentage <= 100,
+            "Total percentages cannot exceed 100"
+        );
+
+        Storage::set_charity_percentage(env, new_percentage);
+    }
+
     /// Transfer admin rights to a new address (admin only)
     pub fn transfer_admin(env: &Env, current_admin: Address, new_admin: Address) {
         Self::require_admin(env, &current_admin);
@@ -343,6 +398,48 @@ impl ScavengerContract {
         incentive
     }
 
+    /// Deactivate an incentive (owner only)
+    pub fn deactivate_incentive(env: &Env, incentive_id: u64, rewarder: Address) -> Incentive {
+        rewarder.require_auth();
+
+        let mut incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+
+        assert!(
+            incentive.rewarder == rewarder,
+            "Only incentive creator can deactivate"
+        );
+
+        incentive.active = false;
+        Storage::set_incentive(env, incentive_id, &incentive);
+
+        incentive
+    }
+
+    /// Withdraw the unused budget of an inactive incentive (owner only)
+    /// Budgets are paid from the rewarder's own balance at distribution time, so
+    /// withdrawing only releases the unused points; `total_budget` keeps the original amount
+    pub fn withdraw_incentive_budget(env: &Env, incentive_id: u64, rewarder: Address) -> u64 {
+        rewarder.require_auth();
+
+        let mut incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+
+        assert!(
+            incentive.rewarder == rewarder,
+            "Only incentive creator can withdraw"
+        );
+        assert!(!incentive.active, "Deactivate before withdrawing");
+
+        let withdrawn = incentive.remaining_budget;
+        incentive.remaining_budget = 0;
+        Storage::set_incentive(env, incentive_id, &incentive);
+
+        events::emit_incentive_withdrawn(env, incentive_id, &rewarder, withdrawn);
+
+        withdrawn
+    }
+
     /// Submit material for recycling
     pub fn submit_material(
         env: &Env,
@@ -540,81 +637,174 @@ impl ScavengerContract {
 
         assert!(incentive.active, "Incentive not active");
 
-        // Calculate total reward (incentive * weight in kg)
-        let weight_kg = material.weight / 1000;
-        let total_reward = (incentive.reward_points as i128) * (weight_kg as i128);
+        let distribution = Self::compute_distribution(env, waste_id, &material, &incentive);
+        let total_reward = distribution.total_reward;
 
         assert!(
             (total_reward as u64) <= incentive.remaining_budget,
             "Insufficient incentive budget"
         );
 
-        // Get waste transfer history
-        let transfers = Storage::get_transfer_history(env, waste_id);
+        if let Some(cap) = Storage::get_reward_cap(env) {
+            assert!(
+                Storage::get_total_earned(env) + total_reward <= cap,
+                "Reward cap reached"
+            );
+        }
+
+        let token_address = Storage::get_token_address(env)
+            .expect("Token address not set");
+        let token_client = token::Client::new(env, &token_address);
+
+        // Route the charity slice first; the rest is split through the supply chain
+        if let Some((charity_address, charity_share)) = distribution.charity {
+            token_client.transfer(&manufacturer, &charity_address, &charity_share);
+            events::emit_charity_donated(env, waste_id, &charity_address, charity_share);
+        }
+
+        // Pay collectors, the original owner and the recycler's remainder
+        for (recipient, amount) in distribution.payouts.iter() {
+            token_client.transfer(&manufacturer, &recipient, &amount);
+            Storage::add_earnings(env, &recipient, amount);
+            events::emit_tokens_rewarded(env, waste_id, &recipient, amount);
+        }
+
+        // Update incentive budget
+        let mut updated_incentive = incentive;
+        updated_incentive.remaining_budget -= total_reward as u64;
+        if updated_incentive.remaining_budget == 0 {
+            updated_incentive.active = false;
+        }
+        Storage::set_incentive(env, incentive_id, &updated_incentive);
+
+        // Update total earned
+        Storage::add_to_total_earned(env, total_reward);
+        Storage::add_distributed_by_type(env, material.waste_type, total_reward as u64);
+
+        total_reward
+    }
+
+    /// Preview how `distribute_rewards` would split an incentive's reward for a material
+    /// Returns (recipient, amount) entries in payout order: charity (if any), each
+    /// paid collector in the transfer history, the original owner and the current
+    /// owner's remainder. Moves no tokens and requires no auth
+    pub fn preview_distribution(env: &Env, waste_id: u64, incentive_id: u64) -> Vec<(Address, u64)> {
+        let material = Storage::get_material(env, waste_id)
+            .expect("Material not found");
+        assert!(material.verified, "Material must be verified");
+
+        let incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+        assert!(
+            incentive.waste_type == material.waste_type,
+            "Waste type mismatch"
+        );
+        assert!(incentive.active, "Incentive not active");
+
+        let distribution = Self::compute_distribution(env, waste_id, &material, &incentive);
+
+        let mut split = Vec::new(env);
+        if let Some((charity_address, charity_share)) = distribution.charity {
+            split.push_back((charity_address, charity_share as u64));
+        }
+        for (recipient, amount) in distribution.payouts.iter() {
+            split.push_back((recipient, amount as u64));
+        }
+
+        split
+    }
+
+    /// Split an incentive's reward for a material the way `distribute_rewards` pays it:
+    /// the charity slice comes off the top, then each collector in the transfer history
+    /// (up to the configured cap) and the original owner get their percentage of the
+    /// rest, and the current owner receives whatever remains
+    fn compute_distribution(
+        env: &Env,
+        waste_id: u64,
+        material: &Material,
+        incentive: &Incentive,
+    ) -> Distribution {
+        // Calculate total reward (incentive * weight in kg)
+        let weight_kg = material.weight / 1000;
+        let total_reward = (incentive.reward_points as i128) * (weight_kg as i128);
 
         // Get configuration
         let collector_pct = Storage::get_collector_percentage(env)
             .expect("Collector percentage not set");
         let owner_pct = Storage::get_owner_percentage(env)
             .expect("Owner percentage not set");
+        let charity_pct = Storage::get_charity_percentage(env);
 
-        let token_address = Storage::get_token_address(env)
-            .expect("Token address not set");
-        let token_client = token::Client::new(env, &token_address);
+        let charity_share = (total_reward * (charity_pct as i128)) / 100;
+        let charity = if charity_share > 0 {
+            let charity_address = Storage::get_charity_address(env)
+                .expect("Charity address not set");
+            Some((charity_address, charity_share))
+        } else {
+            None
+        };
+        let distributable = total_reward - charity_share;
 
-        // Calculate collector shares (5% each from total)
-        let collector_share = (total_reward * (collector_pct as i128)) / 100;
-        
-        // Calculate owner shares (50% of total)
-        let owner_share = (total_reward * (owner_pct as i128)) / 100;
+        // Calculate collector shares (5% each from the distributable amount)
+        let collector_share = (distributable * (collector_pct as i128)) / 100;
+
+        // Calculate owner shares (50% of the distributable amount)
+        let owner_share = (distributable * (owner_pct as i128)) / 100;
 
+        let mut payouts = Vec::new(env);
         let mut total_distributed: i128 = 0;
+        let max_collectors = Storage::get_max_collectors(env).unwrap_or(u32::MAX);
+        let mut collectors_paid: u32 = 0;
 
-        // Iterate through transfer history and reward collectors
-        for transfer in transfers.iter() {
-            let participant = Storage::get_participant(env, &transfer.to);
-            if let Some(p) = participant {
+        // Walk the transfer history and reward collectors, up to the cap
+        for transfer in Storage::get_transfer_history(env, waste_id).iter() {
+            if collectors_paid >= max_collectors {
+                break;
+            }
+            if let Some(p) = Storage::get_participant(env, &transfer.to) {
                 if matches!(p.role, Role::Collector) {
-                    // Transfer tokens to collector
-                    token_client.transfer(&manufacturer, &transfer.to, &collector_share);
-                    
-                    // Update participant statistics
-                    Storage::add_earnings(env, &transfer.to, collector_share);
-                    
-                    // Emit TokensRewarded event
-                    events::emit_tokens_rewarded(env, waste_id, &transfer.to, collector_share);
-                    
+                    collectors_paid += 1;
+                    payouts.push_back((transfer.to, collector_share));
                     total_distributed += collector_share;
                 }
             }
         }
 
         // Reward the original owner (submitter) with their share
-        token_client.transfer(&manufacturer, &material.submitter, &owner_share);
-        Storage::add_earnings(env, &material.submitter, owner_share);
-        events::emit_tokens_rewarded(env, waste_id, &material.submitter, owner_share);
+        payouts.push_back((material.submitter.clone(), owner_share));
         total_distributed += owner_share;
 
         // Recycler gets remaining amount
-        let recycler_amount = total_reward - total_distributed;
+        let recycler_amount = distributable - total_distributed;
         if recycler_amount > 0 {
-            token_client.transfer(&manufacturer, &material.current_owner, &recycler_amount);
-            Storage::add_earnings(env, &material.current_owner, recycler_amount);
-            events::emit_tokens_rewarded(env, waste_id, &material.current_owner, recycler_amount);
+            payouts.push_back((material.current_owner.clone(), recycler_amount));
         }
 
-        // Update incentive budget
-        let mut updated_incentive = incentive;
-        updated_incentive.remaining_budget -= total_reward as u64;
-        if updated_incentive.remaining_budget == 0 {
-            updated_incentive.active = false;
+        Distribution {
+            total_reward,
+            charity,
+            payouts,
         }
-        Storage::set_incentive(env, incentive_id, &updated_incentive);
+    }
 
-        // Update total earned
-        Storage::add_to_total_earned(env, total_reward);
+    /// Get the total tokens distributed for a waste type
+    pub fn get_distributed_by_type(env: &Env, waste_type: WasteType) -> u64 {
+        Storage::get_distributed_by_type(env, waste_type)
+    }
 
-        total_reward
+    /// Get the total tokens distributed for every waste type
+    pub fn get_all_distributed(env: &Env) -> Vec<(WasteType, u64)> {
+        let mut totals = Vec::new(env);
+        for waste_type in [
+            WasteType::Paper,
+            WasteType::PetPlastic,
+            WasteType::Plastic,
+            WasteType::Metal,
+            WasteType::Glass,
+        ] {
+            totals.push_back((waste_type, Storage::get_distributed_by_type(env, waste_type)));
+        }
+        totals
     }
 
     /// Get participant statistics
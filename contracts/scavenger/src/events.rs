@@ -5,7 +5,9 @@ use crate::types::{Role, WasteType};
 const PARTICIPANT_REGISTERED: Symbol = symbol_short!("reg");
 const INCENTIVE_SET: Symbol = symbol_short!("inc_set");
 const INCENTIVE_UPDATED: Symbol = symbol_short!("inc_upd");
+const INCENTIVE_WITHDRAWN: Symbol = symbol_short!("inc_wdrw");
 const TOKENS_REWARDED: Symbol = symbol_short!("rewarded");
+const CHARITY_DONATED: Symbol = symbol_short!("donated");
 const WASTE_DEACTIVATED: Symbol = symbol_short!("wst_deact");
 const WASTE_CONFIRMED: Symbol = symbol_short!("wst_conf");
 const WASTE_CONFIRMATION_RESET: Symbol = symbol_short!("wst_rst");
@@ -54,6 +56,19 @@ pub fn emit_incentive_updated(
     );
 }
 
+/// Emit event when unused incentive budget is withdrawn
+pub fn emit_incentive_withdrawn(
+    env: &Env,
+    incentive_id: u64,
+    rewarder: &Address,
+    amount: u64,
+) {
+    env.events().publish(
+        (INCENTIVE_WITHDRAWN, incentive_id),
+        (rewarder, amount),
+    );
+}
+
 /// Emit event when tokens are rewarded
 pub fn emit_tokens_rewarded(
     env: &Env,
@@ -67,6 +82,19 @@ pub fn emit_tokens_rewarded(
     );
 }
 
+/// Emit event when a share of rewards is routed to charity
+pub fn emit_charity_donated(
+    env: &Env,
+    waste_id: u64,
+    charity: &Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (CHARITY_DONATED, waste_id),
+        (charity, amount),
+    );
+}
+
 /// Emit event when waste is deactivated
 pub fn emit_waste_deactivated(
     env: &Env,
@@ -8,7 +8,10 @@ const TOKEN_ADDR: Symbol = symbol_short!("TOKEN");
 const CHARITY: Symbol = symbol_short!("CHARITY");
 const COLLECTOR_PCT: Symbol = symbol_short!("COL_PCT");
 const OWNER_PCT: Symbol = symbol_short!("OWN_PCT");
+const CHARITY_PCT: Symbol = symbol_short!("CHR_PCT");
 const TOTAL_EARNED: Symbol = symbol_short!("EARNED");
+const REWARD_CAP: Symbol = symbol_short!("RWD_CAP");
+const MAX_COLLECTORS: Symbol = symbol_short!("MAX_COL");
 const TOTAL_WEIGHT: Symbol = symbol_short!("TOT_WGT");
 const INCENTIVE_COUNTER: Symbol = symbol_short!("INC_CNT");
 
@@ -64,6 +67,15 @@ impl Storage {
         env.storage().instance().set(&OWNER_PCT, &percentage);
     }
 
+    // Charity percentage functions
+    pub fn get_charity_percentage(env: &Env) -> u32 {
+        env.storage().instance().get(&CHARITY_PCT).unwrap_or(0)
+    }
+
+    pub fn set_charity_percentage(env: &Env, percentage: u32) {
+        env.storage().instance().set(&CHARITY_PCT, &percentage);
+    }
+
     // Total tokens earned functions
     pub fn get_total_earned(env: &Env) -> i128 {
         env.storage().instance().get(&TOTAL_EARNED).unwrap_or(0)
@@ -78,6 +90,24 @@ impl Storage {
         Self::set_total_earned(env, current + amount);
     }
 
+    // Reward cap functions
+    pub fn get_reward_cap(env: &Env) -> Option<i128> {
+        env.storage().instance().get(&REWARD_CAP)
+    }
+
+    pub fn set_reward_cap(env: &Env, cap: i128) {
+        env.storage().instance().set(&REWARD_CAP, &cap);
+    }
+
+    // Collector chain cap functions
+    pub fn get_max_collectors(env: &Env) -> Option<u32> {
+        env.storage().instance().get(&MAX_COLLECTORS)
+    }
+
+    pub fn set_max_collectors(env: &Env, max_collectors: u32) {
+        env.storage().instance().set(&MAX_COLLECTORS, &max_collectors);
+    }
+
     // Total weight functions
     pub fn get_total_weight(env: &Env) -> u64 {
         env.storage().instance().get(&TOTAL_WEIGHT).unwrap_or(0)
@@ -158,6 +188,21 @@ impl Storage {
         env.storage().instance().get(&key).unwrap_or(Vec::new(env))
     }
 
+    // Distributed rewards by waste type
+    pub fn get_distributed_by_type(env: &Env, waste_type: WasteType) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("dist_by_type", waste_type))
+            .unwrap_or(0)
+    }
+
+    pub fn add_distributed_by_type(env: &Env, waste_type: WasteType, amount: u64) {
+        let current = Self::get_distributed_by_type(env, waste_type);
+        env.storage()
+            .instance()
+            .set(&("dist_by_type", waste_type), &(current + amount));
+    }
+
     // Material storage functions
     pub fn next_material_id(env: &Env) -> u64 {
         let key = symbol_short!("MAT_CNT");
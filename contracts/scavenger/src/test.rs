@@ -860,3 +860,357 @@ fn test_recycler_gets_remaining_amount() {
     // Recycler is both owner and final holder, gets owner share (500) + remaining (500) = 1000
     assert_eq!(recycler_stats.total_earned, 1000);
 }
+
+// Charity Reward Routing Tests
+
+fn setup_charity_distribution(
+    env: &Env,
+) -> (ScavengerContractClient, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let charity_address = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let contract_id = env.register(
+        ScavengerContract,
+        (&admin, &token_address, &charity_address, 10u32, 40u32),
+    );
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    (client, admin, token_address, charity_address)
+}
+
+#[test]
+fn test_distribute_rewards_routes_charity_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, token_address, charity_address) = setup_charity_distribution(&env);
+    client.update_charity_percentage(&admin, &10);
+    assert_eq!(client.get_charity_percentage(), 10);
+
+    let manufacturer = Address::generate(&env);
+    let recycler = Address::generate(&env);
+    let collector = Address::generate(&env);
+
+    client.register_participant(&manufacturer, &Role::Manufacturer, &String::from_str(&env, "Manufacturer"), &100, &200);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(&env, "Recycler"), &300, &400);
+    client.register_participant(&collector, &Role::Collector, &String::from_str(&env, "Collector"), &500, &600);
+
+    token::StellarAssetClient::new(&env, &token_address).mint(&manufacturer, &10000);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &200, &200000);
+    let material = client.submit_material(&recycler, &WasteType::Metal, &10000);
+
+    // Mark the material verified directly in contract storage
+    env.as_contract(&client.address, || {
+        let mut stored = crate::storage::Storage::get_material(&env, material.id).unwrap();
+        stored.verified = true;
+        crate::storage::Storage::set_material(&env, material.id, &stored);
+    });
+
+    client.transfer_waste(&material.id, &recycler, &collector);
+
+    let total = client.distribute_rewards(&material.id, &incentive.id, &manufacturer);
+
+    // Total = 10kg * 200 = 2000
+    // Charity gets 10% = 200, leaving 1800 to split
+    // Collector gets 10% of 1800 = 180
+    // Owner gets 40% of 1800 = 720
+    // Current owner (the collector) gets the remaining 900
+    assert_eq!(total, 2000);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&charity_address), 200);
+    assert_eq!(token_client.balance(&recycler), 720);
+    assert_eq!(token_client.balance(&collector), 180 + 900);
+    assert_eq!(token_client.balance(&manufacturer), 8000);
+}
+
+#[test]
+#[should_panic(expected = "Total percentages cannot exceed 100")]
+fn test_charity_percentage_exceeding_total_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = setup_charity_distribution(&env);
+
+    // Collector 10% + owner 40% + charity 60% > 100%
+    client.update_charity_percentage(&admin, &60);
+}
+
+#[test]
+#[should_panic(expected = "Total percentages cannot exceed 100")]
+fn test_percentages_respect_existing_charity_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = setup_charity_distribution(&env);
+    client.update_charity_percentage(&admin, &20);
+
+    // Collector 10% + owner 80% + charity 20% > 100%
+    client.update_owner_percentage(&admin, &80);
+}
+
+// Incentive Budget Withdrawal Tests
+
+#[test]
+fn test_withdraw_incentive_budget_after_deactivation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, token_address, _charity) = setup_charity_distribution(&env);
+
+    let manufacturer = Address::generate(&env);
+    client.register_participant(&manufacturer, &Role::Manufacturer, &String::from_str(&env, "Manufacturer"), &100, &200);
+
+    token::StellarAssetClient::new(&env, &token_address).mint(&manufacturer, &3000);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &50, &3000);
+
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+    assert_eq!(client.withdraw_incentive_budget(&incentive.id, &manufacturer), 3000);
+
+    let withdrawn = client.get_incentive_by_id(&incentive.id).unwrap();
+    assert_eq!(withdrawn.remaining_budget, 0);
+    assert_eq!(withdrawn.total_budget, 3000);
+    assert!(!withdrawn.active);
+
+    // Budgets are never escrowed, so the rewarder's balance is untouched
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&manufacturer), 3000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Deactivate before withdrawing")]
+fn test_withdraw_incentive_budget_while_active() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, _token, _charity) = setup_charity_distribution(&env);
+
+    let manufacturer = Address::generate(&env);
+    client.register_participant(&manufacturer, &Role::Manufacturer, &String::from_str(&env, "Manufacturer"), &100, &200);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &50, &3000);
+    client.withdraw_incentive_budget(&incentive.id, &manufacturer);
+}
+
+// Reward Cap Tests
+
+fn setup_capped_distribution(env: &Env, cap: i128) -> (ScavengerContractClient, Address, Address, u64) {
+    let (client, admin, token_address, _charity) = setup_charity_distribution(env);
+    client.set_reward_cap(&admin, &cap);
+
+    let manufacturer = Address::generate(env);
+    let recycler = Address::generate(env);
+    client.register_participant(&manufacturer, &Role::Manufacturer, &String::from_str(env, "Manufacturer"), &100, &200);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(env, "Recycler"), &300, &400);
+
+    token::StellarAssetClient::new(env, &token_address).mint(&manufacturer, &10000);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &200, &200000);
+
+    (client, manufacturer, recycler, incentive.id)
+}
+
+fn verified_material(env: &Env, client: &ScavengerContractClient, recycler: &Address) -> u64 {
+    let material = client.submit_material(recycler, &WasteType::Metal, &10000);
+    env.as_contract(&client.address, || {
+        let mut stored = crate::storage::Storage::get_material(env, material.id).unwrap();
+        stored.verified = true;
+        crate::storage::Storage::set_material(env, material.id, &stored);
+    });
+    material.id
+}
+
+#[test]
+fn test_distribute_rewards_up_to_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, manufacturer, recycler, incentive_id) = setup_capped_distribution(&env, 4000);
+    assert_eq!(client.get_reward_cap(), Some(4000));
+
+    // Each distribution pays 10kg * 200 = 2000
+    let first = verified_material(&env, &client, &recycler);
+    let second = verified_material(&env, &client, &recycler);
+    client.distribute_rewards(&first, &incentive_id, &manufacturer);
+    client.distribute_rewards(&second, &incentive_id, &manufacturer);
+
+    assert_eq!(client.get_total_earned(), 4000);
+}
+
+#[test]
+#[should_panic(expected = "Reward cap reached")]
+fn test_distribute_rewards_past_cap_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, manufacturer, recycler, incentive_id) = setup_capped_distribution(&env, 3000);
+
+    let first = verified_material(&env, &client, &recycler);
+    let second = verified_material(&env, &client, &recycler);
+    client.distribute_rewards(&first, &incentive_id, &manufacturer);
+
+    // 2000 + 2000 would exceed the 3000 cap
+    client.distribute_rewards(&second, &incentive_id, &manufacturer);
+}
+
+// Distribution By Type Tests
+
+#[test]
+fn test_distributed_totals_by_type() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, token_address, _charity) = setup_charity_distribution(&env);
+
+    let manufacturer = Address::generate(&env);
+    let recycler = Address::generate(&env);
+    client.register_participant(&manufacturer, &Role::Manufacturer, &String::from_str(&env, "Manufacturer"), &100, &200);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(&env, "Recycler"), &300, &400);
+
+    token::StellarAssetClient::new(&env, &token_address).mint(&manufacturer, &10000);
+
+    let metal_incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &200, &200000);
+    let paper_incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &50, &200000);
+
+    let metal = client.submit_material(&recycler, &WasteType::Metal, &10000);
+    let paper = client.submit_material(&recycler, &WasteType::Paper, &4000);
+    env.as_contract(&client.address, || {
+        for id in [metal.id, paper.id] {
+            let mut stored = crate::storage::Storage::get_material(&env, id).unwrap();
+            stored.verified = true;
+            crate::storage::Storage::set_material(&env, id, &stored);
+        }
+    });
+
+    // Metal: 10kg * 200 = 2000, Paper: 4kg * 50 = 200
+    client.distribute_rewards(&metal.id, &metal_incentive.id, &manufacturer);
+    client.distribute_rewards(&paper.id, &paper_incentive.id, &manufacturer);
+
+    assert_eq!(client.get_distributed_by_type(&WasteType::Metal), 2000);
+    assert_eq!(client.get_distributed_by_type(&WasteType::Paper), 200);
+    assert_eq!(client.get_distributed_by_type(&WasteType::Glass), 0);
+
+    let all = client.get_all_distributed();
+    assert_eq!(all.len(), 5);
+    assert_eq!(all.get(0).unwrap(), (WasteType::Paper, 200));
+    assert_eq!(all.get(1).unwrap(), (WasteType::PetPlastic, 0));
+    assert_eq!(all.get(2).unwrap(), (WasteType::Plastic, 0));
+    assert_eq!(all.get(3).unwrap(), (WasteType::Metal, 2000));
+    assert_eq!(all.get(4).unwrap(), (WasteType::Glass, 0));
+}
+
+#[test]
+fn test_preview_distribution_matches_multi_collector_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, token_address, _charity) = setup_charity_distribution(&env);
+
+    let manufacturer = Address::generate(&env);
+    let recycler = Address::generate(&env);
+    let collector1 = Address::generate(&env);
+    let collector2 = Address::generate(&env);
+    client.register_participant(&manufacturer, &Role::Manufacturer, &String::from_str(&env, "Manufacturer"), &100, &200);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(&env, "Recycler"), &300, &400);
+    client.register_participant(&collector1, &Role::Collector, &String::from_str(&env, "Collector 1"), &500, &600);
+    client.register_participant(&collector2, &Role::Collector, &String::from_str(&env, "Collector 2"), &700, &800);
+
+    token::StellarAssetClient::new(&env, &token_address).mint(&manufacturer, &10000);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &50000);
+    let material = client.submit_material(&recycler, &WasteType::Plastic, &8000);
+    env.as_contract(&client.address, || {
+        let mut stored = crate::storage::Storage::get_material(&env, material.id).unwrap();
+        stored.verified = true;
+        crate::storage::Storage::set_material(&env, material.id, &stored);
+    });
+
+    client.transfer_waste(&material.id, &recycler, &collector1);
+    client.transfer_waste(&material.id, &collector1, &collector2);
+
+    // Total = 8kg * 50 = 400
+    // Each collector gets 10% = 40, owner gets 40% = 160, current owner the remaining 160
+    let preview = client.preview_distribution(&material.id, &incentive.id);
+    assert_eq!(preview.len(), 4);
+    assert_eq!(preview.get(0).unwrap(), (collector1.clone(), 40));
+    assert_eq!(preview.get(1).unwrap(), (collector2.clone(), 40));
+    assert_eq!(preview.get(2).unwrap(), (recycler.clone(), 160));
+    assert_eq!(preview.get(3).unwrap(), (collector2.clone(), 160));
+
+    // Previewing moves nothing
+    assert_eq!(client.get_participant_stats(&collector1).total_earned, 0);
+    assert_eq!(client.get_incentive_by_id(&incentive.id).unwrap().remaining_budget, 50000);
+
+    let total = client.distribute_rewards(&material.id, &incentive.id, &manufacturer);
+    assert_eq!(total, 400);
+
+    // Each participant's earnings equal the sum of their preview entries
+    for address in [&collector1, &collector2, &recycler] {
+        let expected: u64 = preview
+            .iter()
+            .filter(|(recipient, _)| recipient == address)
+            .map(|(_, amount)| amount)
+            .sum();
+        assert_eq!(client.get_participant_stats(address).total_earned, expected as i128);
+    }
+}
+
+// Collector Chain Cap Tests
+
+#[test]
+fn test_distribute_rewards_caps_collector_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, token_address, _charity) = setup_charity_distribution(&env);
+    client.set_max_collectors(&admin, &2);
+    assert_eq!(client.get_max_collectors(), Some(2));
+
+    let manufacturer = Address::generate(&env);
+    let recycler = Address::generate(&env);
+    client.register_participant(&manufacturer, &Role::Manufacturer, &String::from_str(&env, "Manufacturer"), &100, &200);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(&env, "Recycler"), &300, &400);
+
+    let collectors: [Address; 5] = core::array::from_fn(|_| {
+        let collector = Address::generate(&env);
+        client.register_participant(&collector, &Role::Collector, &String::from_str(&env, "Collector"), &500, &600);
+        collector
+    });
+
+    token::StellarAssetClient::new(&env, &token_address).mint(&manufacturer, &10000);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &50000);
+    let material = client.submit_material(&recycler, &WasteType::Plastic, &10000);
+    env.as_contract(&client.address, || {
+        let mut stored = crate::storage::Storage::get_material(&env, material.id).unwrap();
+        stored.verified = true;
+        crate::storage::Storage::set_material(&env, material.id, &stored);
+    });
+
+    let mut holder = recycler.clone();
+    for collector in collectors.iter() {
+        client.transfer_waste(&material.id, &holder, collector);
+        holder = collector.clone();
+    }
+
+    // Total = 10kg * 50 = 500; only the first two collectors get 10% = 50 each,
+    // the owner gets 40% = 200 and the current owner takes the remaining 200
+    let preview = client.preview_distribution(&material.id, &incentive.id);
+    assert_eq!(preview.len(), 4);
+
+    let total = client.distribute_rewards(&material.id, &incentive.id, &manufacturer);
+    assert_eq!(total, 500);
+
+    assert_eq!(client.get_participant_stats(&collectors[0]).total_earned, 50);
+    assert_eq!(client.get_participant_stats(&collectors[1]).total_earned, 50);
+    assert_eq!(client.get_participant_stats(&collectors[2]).total_earned, 0);
+    assert_eq!(client.get_participant_stats(&collectors[3]).total_earned, 0);
+    assert_eq!(client.get_participant_stats(&collectors[4]).total_earned, 200);
+    assert_eq!(client.get_participant_stats(&recycler).total_earned, 200);
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::contracterror;
+
+/// Typed errors returned by the core material and incentive entry points
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ScavengerError {
+    /// Participant is unknown or has been deregistered
+    NotRegistered = 1,
+    /// Caller is not allowed to perform the action
+    Unauthorized = 2,
+    /// No waste/material exists with the given ID
+    WasteNotFound = 3,
+    /// No incentive exists with the given ID
+    IncentiveNotFound = 4,
+    /// Incentive has been deactivated
+    IncentiveInactive = 5,
+    /// Arithmetic overflow while updating totals
+    Overflow = 6,
+    /// Material has not been verified yet
+    NotVerified = 7,
+    /// Reward for this incentive/material pair was already claimed
+    AlreadyClaimed = 8,
+    /// Material waste type does not match the incentive
+    WasteTypeMismatch = 9,
+    /// Incentive budget cannot cover the reward
+    InsufficientBudget = 10,
+    /// Material has been retired and can no longer change
+    MaterialRetired = 11,
+    /// Material was rejected and can no longer be verified
+    MaterialRejected = 12,
+    /// Material has already been verified
+    AlreadyVerified = 13,
+}
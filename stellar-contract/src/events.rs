@@ -2,10 +2,27 @@ use soroban_sdk::{symbol_short, Address, Env, Symbol};
 
 use crate::types::WasteType;
 
+/// Leading topic of versioned events; bump when their topics or data change shape
+const EVENT_SCHEMA_VERSION: Symbol = symbol_short!("v1");
+
 const WASTE_REGISTERED: Symbol = symbol_short!("recycled");
 const DONATION_MADE: Symbol = symbol_short!("donated");
+const MATERIAL_SUBMITTED: Symbol = symbol_short!("submitted");
+const MATERIAL_VERIFIED: Symbol = symbol_short!("verified");
+const INCENTIVE_CLAIMED: Symbol = symbol_short!("claimed");
+const INCENTIVE_EXHAUSTED: Symbol = symbol_short!("exhausted");
+const ESCROW_WITHDRAWN: Symbol = symbol_short!("esc_wdrw");
+const WASTE_TRANSFERRED: Symbol = symbol_short!("transfer");
+const BULK_TRANSFERRED: Symbol = symbol_short!("bulk_xfr");
+const SUBMITTER_PENALIZED: Symbol = symbol_short!("penalized");
+const INCENTIVE_UPDATED: Symbol = symbol_short!("inc_upd");
+const WASTE_CONFIRMED: Symbol = symbol_short!("confirmed");
+const WASTE_REJECTED: Symbol = symbol_short!("rejected");
+const WASTE_DEACTIVATED: Symbol = symbol_short!("deactive");
+const MATERIAL_RETIRED: Symbol = symbol_short!("retired");
+const VERIFICATION_REVOKED: Symbol = symbol_short!("revoked");
 
-/// Emit event when waste is registered
+/// Emit versioned event when waste is registered
 pub fn emit_waste_registered(
     env: &Env,
     waste_id: u128,
@@ -16,12 +33,12 @@ pub fn emit_waste_registered(
     longitude: i128,
 ) {
     env.events().publish(
-        (WASTE_REGISTERED, waste_id),
+        (EVENT_SCHEMA_VERSION, WASTE_REGISTERED, waste_id),
         (waste_type, weight, recycler, latitude, longitude),
     );
 }
 
-/// Emit event when a donation is made to charity
+/// Emit versioned event when a donation is made to charity
 pub fn emit_donation_made(
     env: &Env,
     donor: &Address,
@@ -29,7 +46,180 @@ pub fn emit_donation_made(
     charity_contract: &Address,
 ) {
     env.events().publish(
-        (DONATION_MADE, donor),
+        (EVENT_SCHEMA_VERSION, DONATION_MADE, donor),
         (amount, charity_contract),
     );
 }
+
+/// Emit versioned event when a material is submitted
+pub fn emit_submitted(
+    env: &Env,
+    waste_id: u64,
+    waste_type: WasteType,
+    weight: u64,
+    submitter: &Address,
+) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, MATERIAL_SUBMITTED, waste_id),
+        (waste_type, weight, submitter),
+    );
+}
+
+/// Emit versioned event when a material is verified
+pub fn emit_verified(
+    env: &Env,
+    material_id: u64,
+    verifier: &Address,
+    submitter: &Address,
+    tokens_earned: u64,
+) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, MATERIAL_VERIFIED, material_id),
+        (verifier, submitter, tokens_earned),
+    );
+}
+
+/// Emit versioned event when a v2 waste changes hands
+pub fn emit_transferred(env: &Env, waste_id: u128, from: &Address, to: &Address, timestamp: u64) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, WASTE_TRANSFERRED, waste_id),
+        (from, to, timestamp),
+    );
+}
+
+/// Emit versioned event when a collector hands aggregated waste to a manufacturer
+pub fn emit_bulk_transferred(
+    env: &Env,
+    waste_id: u128,
+    collector: &Address,
+    manufacturer: &Address,
+    waste_type: WasteType,
+    timestamp: u64,
+) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, BULK_TRANSFERRED, waste_id),
+        (collector, manufacturer, waste_type, timestamp),
+    );
+}
+
+/// Emit versioned event when an admin moves a disputed waste
+pub fn emit_admin_transferred(
+    env: &Env,
+    waste_id: u128,
+    admin: &Address,
+    from: &Address,
+    to: &Address,
+) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, Symbol::new(env, "admin_xfer"), waste_id),
+        (admin, from, to),
+    );
+}
+
+/// Emit versioned event when an incentive reward is claimed
+pub fn emit_claimed(
+    env: &Env,
+    incentive_id: u64,
+    claimer: &Address,
+    material_id: u64,
+    reward: u64,
+    remaining_budget: u64,
+) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, INCENTIVE_CLAIMED, incentive_id, claimer),
+        (material_id, reward, remaining_budget),
+    );
+}
+
+/// Emit versioned event when a claim exhausts an incentive's budget
+pub fn emit_incentive_exhausted(env: &Env, incentive_id: u64) {
+    env.events().publish((EVENT_SCHEMA_VERSION, INCENTIVE_EXHAUSTED, incentive_id), ());
+}
+
+/// Emit versioned event when a participant's lifetime totals saturate at u128::MAX
+pub fn emit_stats_saturated(
+    env: &Env,
+    participant: &Address,
+    total_waste_processed: u128,
+    total_tokens_earned: u128,
+) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, Symbol::new(env, "stats_saturated"), participant),
+        (total_waste_processed, total_tokens_earned),
+    );
+}
+
+/// Emit versioned event when a rejected submission costs its submitter reward points
+pub fn emit_penalized(env: &Env, submitter: &Address, material_id: u64, penalty: u64, total_points: u64) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, SUBMITTER_PENALIZED, submitter),
+        (material_id, penalty, total_points),
+    );
+}
+
+/// Emit versioned event when a participant withdraws their escrowed rewards
+pub fn emit_escrow_withdrawn(env: &Env, participant: &Address, amount: u64) {
+    env.events().publish((EVENT_SCHEMA_VERSION, ESCROW_WITHDRAWN, participant), amount);
+}
+
+/// Emit versioned event when a rewarder changes an incentive's reward points
+pub fn emit_incentive_updated(env: &Env, incentive_id: u64, rewarder: &Address, reward_points: u64) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, INCENTIVE_UPDATED, incentive_id),
+        (rewarder, reward_points),
+    );
+}
+
+/// Emit versioned event when a v2 waste is confirmed
+pub fn emit_confirmed(env: &Env, waste_id: u128, confirmer: &Address) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, WASTE_CONFIRMED, waste_id),
+        (confirmer, env.ledger().timestamp()),
+    );
+}
+
+/// Emit versioned event when a v2 waste's details are rejected
+pub fn emit_rejected(env: &Env, waste_id: u128, confirmer: &Address, reason: Symbol) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, WASTE_REJECTED, waste_id),
+        (confirmer, reason),
+    );
+}
+
+/// Emit versioned event when a v2 waste's confirmation is reset
+pub fn emit_unconfirmed(env: &Env, waste_id: u128, caller: &Address) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, Symbol::new(env, "unconfirmed"), waste_id),
+        (caller, env.ledger().timestamp()),
+    );
+}
+
+/// Emit versioned event when an admin deactivates a v2 waste
+pub fn emit_deactivated(env: &Env, waste_id: u128, admin: &Address) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, WASTE_DEACTIVATED, waste_id),
+        (admin, env.ledger().timestamp()),
+    );
+}
+
+/// Emit versioned event when a material is retired
+pub fn emit_retired(env: &Env, material_id: u64, caller: &Address) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, MATERIAL_RETIRED, material_id),
+        (caller, env.ledger().timestamp()),
+    );
+}
+
+/// Emit versioned event when a verification is revoked
+pub fn emit_revoked(
+    env: &Env,
+    material_id: u64,
+    verifier: &Address,
+    submitter: &Address,
+    tokens_revoked: u64,
+) {
+    env.events().publish(
+        (EVENT_SCHEMA_VERSION, VERIFICATION_REVOKED, material_id),
+        (verifier, submitter, tokens_revoked),
+    );
+}
@@ -1,17 +1,23 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
+mod errors;
 mod events;
 mod types;
 
 
-
+pub use errors::ScavengerError;
 pub use types::{
-    Material, ParticipantRole, RecyclingStats, TransferItemType, TransferRecord, TransferStatus,
-    Waste, WasteTransfer, WasteType,
+    ConfigChange, ConfigLogEntry, ConfigProposal, ConfigValue, Dispute, DisputeStatus, GlobalMetrics, Incentive, Material, ParticipantRole, RecyclingStats, TransferItemType, TransferRecord,
+    TransferStatus, Waste, WasteBuilder, WasteStatus, WasteTransfer, WasteType, WeightUnit,
 };
+use types::DEFAULT_SCALE_FACTOR;
 
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, symbol_short, xdr::ToXdr, Address,
+    BytesN, Env, String, Symbol, Vec,
+};
 
 // Storage keys
 const ADMIN: Symbol = symbol_short!("ADMIN");
@@ -21,6 +27,24 @@ const OWNER_PCT: Symbol = symbol_short!("OWN_PCT");
 const TOTAL_WEIGHT: Symbol = symbol_short!("TOT_WGT");
 const TOTAL_TOKENS: Symbol = symbol_short!("TOT_TKN");
 
+// Upper bound on records scanned by a single paged query
+const MAX_PAGE_SIZE: u32 = 100;
+
+// Most recent configuration changes kept in the config log; older entries are dropped
+const CONFIG_LOG_CAPACITY: u32 = 100;
+
+// Default minimum material weight in grams
+const DEFAULT_MIN_WEIGHT: u64 = 100;
+
+// Default lifetime of an incentive reservation in seconds
+const DEFAULT_RESERVATION_TTL: u64 = 300;
+
+// Storage layout version this build migrates to
+const CONTRACT_VERSION: u32 = 2;
+
+// Largest divisor reward halving may apply; rewards are effectively zero beyond it
+const MAX_HALVING_DIVISOR: u64 = 1_000_000_000;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Participant {
@@ -35,32 +59,38 @@ pub struct Participant {
     pub registered_at: u64,
 }
 
-
-/// Represents a manufacturer incentive program for recycling specific waste types
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Incentive {
-    /// Unique identifier for the incentive
-    pub id: u64,
-    /// Type of waste this incentive applies to
-    pub waste_type: WasteType,
-    /// Reward amount per unit (in tokens)
-    pub reward: u128,
-    /// Maximum waste amount eligible for this incentive (in grams)
-    pub max_waste_amount: u128,
-    /// Address of the manufacturer offering the incentive
-    pub rewarder: Address,
-    /// Whether this incentive is currently active
-    pub is_active: bool,
-    /// Timestamp when the incentive was created
-    pub created_at: u64,
+pub struct ParticipantInfo {
+    pub participant: Participant,
+    pub stats: RecyclingStats,
 }
 
+/// Everything a dApp needs to render a participant in a single call
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ParticipantInfo {
+pub struct ParticipantProfile {
     pub participant: Participant,
     pub stats: RecyclingStats,
+    /// Owned waste IDs, capped at MAX_PAGE_SIZE entries
+    pub waste_ids: Vec<u64>,
+    /// IDs of incentives created by the participant
+    pub incentive_ids: Vec<u64>,
+}
+
+/// Contract-wide configuration in a single call
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractConfig {
+    /// Admin address, if initialized
+    pub admin: Option<Address>,
+    /// Charity contract address, if configured
+    pub charity: Option<Address>,
+    pub collector_percentage: Option<u32>,
+    pub owner_percentage: Option<u32>,
+    /// Minimum accepted material weight in grams
+    pub min_weight: u64,
+    pub paused: bool,
 }
 
 #[contract]
@@ -80,6 +110,10 @@ impl ScavengerContract {
         }
         
         env.storage().instance().set(&ADMIN, &admin);
+        // Reward halving epochs are counted from the contract start
+        env.storage()
+            .instance()
+            .set(&("start_time",), &env.ledger().timestamp());
     }
 
     /// Get the current admin address
@@ -90,6 +124,18 @@ impl ScavengerContract {
             .expect("Admin not set")
     }
 
+    /// Get the contract-wide configuration in one call
+    pub fn get_config(env: Env) -> ContractConfig {
+        ContractConfig {
+            admin: env.storage().instance().get(&ADMIN),
+            charity: Self::get_charity_contract(env.clone()),
+            collector_percentage: Self::get_collector_percentage(env.clone()),
+            owner_percentage: Self::get_owner_percentage(env.clone()),
+            min_weight: Self::get_min_weight(env.clone()),
+            paused: Self::is_paused(env),
+        }
+    }
+
     /// Check if caller is admin
     fn require_admin(env: &Env, caller: &Address) {
         let admin: Address = env
@@ -105,19 +151,108 @@ impl ScavengerContract {
         caller.require_auth();
     }
 
+    // ========== Version Functions ==========
+
+    /// Get the storage layout version (0 for deployments that predate versioning)
+    pub fn get_version(env: Env) -> u32 {
+        env.storage().instance().get(&("version",)).unwrap_or(0)
+    }
+
+    /// Run the migration steps between the stored version and `target_version` (admin only)
+    /// Each step is idempotent; migrating to the current version is a no-op
+    pub fn migrate(env: Env, admin: Address, target_version: u32) -> u32 {
+        Self::require_admin(&env, &admin);
+
+        if target_version > CONTRACT_VERSION {
+            panic!("Unknown target version");
+        }
+
+        let current = Self::get_version(env.clone());
+        if target_version < current {
+            panic!("Cannot downgrade version");
+        }
+
+        for version in (current + 1)..=target_version {
+            match version {
+                // v1: persist global metrics so reads no longer fall back to defaults
+                1 => {
+                    let metrics = Self::load_global_metrics(&env);
+                    Self::save_global_metrics(&env, &metrics);
+                }
+                // v2: rebuild the waste type indexes from the stored materials
+                2 => Self::rebuild_type_indexes(&env),
+                _ => {}
+            }
+        }
+
+        if target_version != current {
+            env.storage().instance().set(&("version",), &target_version);
+            Self::log_config_change(
+                &env,
+                &admin,
+                "version",
+                ConfigValue::Number(current as u64),
+                ConfigValue::Number(target_version as u64),
+            );
+        }
+
+        target_version
+    }
+
+    /// Rebuild every waste type index in submission order
+    fn rebuild_type_indexes(env: &Env) {
+        let waste_types = [
+            WasteType::Paper,
+            WasteType::PetPlastic,
+            WasteType::Plastic,
+            WasteType::Metal,
+            WasteType::Glass,
+        ];
+        for waste_type in waste_types {
+            env.storage()
+                .instance()
+                .set(&("type_index", waste_type), &Vec::<u64>::new(env));
+        }
+
+        for material_id in 1..=Self::get_waste_count(env) {
+            if let Some(material) = Self::get_waste_internal(env, material_id) {
+                Self::add_to_type_index(env, material.waste_type, material_id);
+            }
+        }
+    }
+
     // ========== Charity Contract Functions ==========
 
     /// Set the charity contract address (admin only)
     pub fn set_charity_contract(env: Env, admin: Address, charity_address: Address) {
         Self::require_admin(&env, &admin);
-        
+        Self::require_no_admin_set(&env);
+
+        Self::apply_charity_contract(&env, &admin, &charity_address);
+    }
+
+    /// Validate and store the charity contract address
+    fn apply_charity_contract(env: &Env, admin: &Address, charity_address: &Address) {
+        let stored_admin: Address = env.storage().instance().get(&ADMIN).expect("Admin not set");
+
         // Validate address (basic check - address should not be the zero address)
         // In Soroban, we can't easily check for zero address, but we can ensure it's different from admin
-        if charity_address == admin {
+        if *charity_address == stored_admin {
             panic!("Charity address cannot be the same as admin");
         }
-        
-        env.storage().instance().set(&CHARITY, &charity_address);
+
+        let old_value = match Self::get_charity_contract(env.clone()) {
+            Some(address) => ConfigValue::Address(address),
+            None => ConfigValue::Unset,
+        };
+        env.storage().instance().set(&CHARITY, charity_address);
+        Self::log_config_change(
+            env,
+            admin,
+            "charity",
+            old_value,
+            ConfigValue::Address(charity_address.clone()),
+        );
     }
 
     /// Get the charity contract address
@@ -159,14 +294,38 @@ impl ScavengerContract {
         owner_percentage: u32,
     ) {
         Self::require_admin(&env, &admin);
-        
+        Self::require_no_admin_set(&env);
+
+        Self::apply_percentages(&env, &admin, collector_percentage, owner_percentage);
+    }
+
+    /// Validate and store the collector and owner percentages
+    fn apply_percentages(
+        env: &Env,
+        admin: &Address,
+        collector_percentage: u32,
+        owner_percentage: u32,
+    ) {
         // Validate percentages sum
         if collector_percentage + owner_percentage > 100 {
             panic!("Total percentages cannot exceed 100");
         }
-        
-        env.storage().instance().set(&COLLECTOR_PCT, &collector_percentage);
-        env.storage().instance().set(&OWNER_PCT, &owner_percentage);
+
+        Self::store_percentage(env, admin, COLLECTOR_PCT, "collector_pct", collector_percentage);
+        Self::store_percentage(env, admin, OWNER_PCT, "owner_pct", owner_percentage);
+    }
+
+    /// Store a single percentage and record the change in the config log
+    fn store_percentage(env: &Env, admin: &Address, key: Symbol, field: &str, percentage: u32) {
+        let old_value: Option<u32> = env.storage().instance().get(&key);
+        env.storage().instance().set(&key, &percentage);
+        Self::log_config_change(
+            env,
+            admin,
+            field,
+            ConfigValue::from_number(old_value.map(u64::from)),
+            ConfigValue::Number(percentage as u64),
+        );
     }
 
     /// Get the collector percentage
@@ -182,6 +341,7 @@ impl ScavengerContract {
     /// Update only the collector percentage (admin only)
     pub fn set_collector_percentage(env: Env, admin: Address, new_percentage: u32) {
         Self::require_admin(&env, &admin);
+        Self::require_no_admin_set(&env);
         
         // Get current owner percentage to validate total
         let owner_pct: u32 = env.storage()
@@ -193,12 +353,13 @@ impl ScavengerContract {
             panic!("Total percentages cannot exceed 100");
         }
         
-        env.storage().instance().set(&COLLECTOR_PCT, &new_percentage);
+        Self::store_percentage(&env, &admin, COLLECTOR_PCT, "collector_pct", new_percentage);
     }
 
     /// Update only the owner percentage (admin only)
     pub fn set_owner_percentage(env: Env, admin: Address, new_percentage: u32) {
         Self::require_admin(&env, &admin);
+        Self::require_no_admin_set(&env);
         
         // Get current collector percentage to validate total
         let collector_pct: u32 = env.storage()
@@ -210,1360 +371,5329 @@ impl ScavengerContract {
             panic!("Total percentages cannot exceed 100");
         }
         
-        env.storage().instance().set(&OWNER_PCT, &new_percentage);
+        Self::store_percentage(&env, &admin, OWNER_PCT, "owner_pct", new_percentage);
     }
 
-    // ========== Participant Storage Functions ==========
+    // ========== Multi-Signature Admin Functions ==========
 
-    /// Store a participant record
-    /// Internal helper function for efficient participant storage
-    fn set_participant(env: &Env, address: &Address, participant: &Participant) {
-        let key = (address.clone(),);
-        env.storage().instance().set(&key, participant);
-    }
+    /// Configure the admin set and approval threshold (admin only)
+    /// Once configured, percentage, charity and admin set changes require `threshold`
+    /// approvals; later admin set changes go through `ConfigChange::Admins`
+    pub fn set_admins(env: Env, admin: Address, admins: Vec<Address>, threshold: u32) {
+        Self::require_admin(&env, &admin);
+        Self::require_no_admin_set(&env);
 
-    /// Check if a participant is registered
-    pub fn is_participant_registered(env: Env, address: Address) -> bool {
-        let key = (address,);
-        env.storage().instance().has(&key)
+        Self::apply_admin_set(&env, &admin, &admins, threshold);
     }
 
-    /// Register a new participant with a specific role
-    /// Prevents duplicate registrations
-    pub fn register_participant(
-        env: Env,
-        address: Address,
-        role: ParticipantRole,
-        name: soroban_sdk::Symbol,
-        latitude: i128,
-        longitude: i128,
-    ) -> Participant {
-        address.require_auth();
+    /// Validate and store the admin set and approval threshold
+    fn apply_admin_set(env: &Env, admin: &Address, admins: &Vec<Address>, threshold: u32) {
+        if threshold == 0 || threshold > admins.len() {
+            panic!("Invalid approval threshold");
+        }
 
-        // Check if already registered
-        if Self::is_participant_registered(env.clone(), address.clone()) {
-            panic!("Participant already registered");
+        for (i, candidate) in admins.iter().enumerate() {
+            if admins.first_index_of(&candidate) != Some(i as u32) {
+                panic!("Duplicate admin address");
+            }
         }
 
-        let participant = Participant {
-            address: address.clone(),
-            role,
-            name,
-            latitude,
-            longitude,
-            is_registered: true,
-            total_waste_processed: 0,
-            total_tokens_earned: 0,
-            registered_at: env.ledger().timestamp(),
-        };
+        let old_threshold = Self::get_admin_threshold(env.clone());
+        env.storage().instance().set(&("admins",), admins);
+        env.storage().instance().set(&("admin_threshold",), &threshold);
+        Self::log_config_change(
+            env,
+            admin,
+            "admin_threshold",
+            ConfigValue::Number(old_threshold as u64),
+            ConfigValue::Number(threshold as u64),
+        );
+    }
 
-        // Store participant using helper function
-        Self::set_participant(&env, &address, &participant);
+    /// Get the configured admin set (empty if multi-signature is not enabled)
+    pub fn get_admins(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&("admins",))
+            .unwrap_or(Vec::new(&env))
+    }
 
-        participant
+    /// Get the number of approvals required for configuration changes
+    pub fn get_admin_threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&("admin_threshold",))
+            .unwrap_or(0)
     }
 
-    /// Update participant statistics after processing waste
-    /// Uses checked arithmetic to prevent overflow
-    fn update_participant_stats(
-        env: &Env,
-        address: &Address,
-        waste_weight: u64,
-        tokens_earned: u64,
-    ) {
-        let key = (address.clone(),);
-        if let Some(mut participant) = env.storage().instance().get::<_, Participant>(&key) {
-            // Use checked arithmetic to prevent overflow
-            participant.total_waste_processed = participant
-                .total_waste_processed
-                .checked_add(waste_weight as u128)
-                .expect("Overflow in total_waste_processed");
-            
-            participant.total_tokens_earned = participant
-                .total_tokens_earned
-                .checked_add(tokens_earned as u128)
-                .expect("Overflow in total_tokens_earned");
-            
-            env.storage().instance().set(&key, &participant);
-            
-            // Update global total tokens if tokens were earned
-            if tokens_earned > 0 {
-                Self::add_to_total_tokens(env, tokens_earned as u128);
-            }
+    /// Propose a configuration change (admin set members only)
+    /// The proposer's approval is counted; returns the proposal ID
+    pub fn propose_config_change(env: Env, proposer: Address, change: ConfigChange) -> BytesN<32> {
+        Self::require_admin_set_member(&env, &proposer);
+
+        let proposal_id = Self::config_proposal_id(&env, &change);
+        let key = ("proposal", proposal_id.clone());
+        if env.storage().instance().has(&key) {
+            panic!("Proposal already pending");
         }
+
+        let proposal = ConfigProposal::new(&env, change, proposer.clone());
+        Self::settle_config_proposal(&env, &proposer, &proposal_id, &proposal);
+
+        proposal_id
     }
 
-    /// Validate that a participant is registered before allowing restricted actions
-    fn require_registered(env: &Env, address: &Address) {
-        let key = (address.clone(),);
-        let participant: Option<Participant> = env.storage().instance().get(&key);
-        
-        match participant {
-            Some(p) if p.is_registered => {},
-            Some(_) => panic!("Participant is not registered"),
-            None => panic!("Participant not found"),
+    /// Approve a pending configuration change (admin set members only)
+    /// Returns true if this approval reached the threshold and the change was applied
+    pub fn approve_config_change(env: Env, approver: Address, proposal_id: BytesN<32>) -> bool {
+        Self::require_admin_set_member(&env, &approver);
+
+        let mut proposal: ConfigProposal = env
+            .storage()
+            .instance()
+            .get(&("proposal", proposal_id.clone()))
+            .expect("Proposal not found");
+
+        if proposal.has_approved(&approver) {
+            panic!("Admin already approved");
         }
-    }
 
-    /// Store a waste record by ID
-    /// Internal helper function for efficient waste storage
-    fn set_waste(env: &Env, waste_id: u64, material: &Material) {
-        let key = ("waste", waste_id);
-        env.storage().instance().set(&key, material);
+        proposal.approve(approver.clone());
+        Self::settle_config_proposal(&env, &approver, &proposal_id, &proposal)
     }
 
-    /// Retrieve a waste record by ID (internal helper)
-    /// Returns None if waste doesn't exist
-    fn get_waste_internal(env: &Env, waste_id: u64) -> Option<Material> {
-        let key = ("waste", waste_id);
-        env.storage().instance().get(&key)
+    /// Get a pending configuration proposal
+    pub fn get_config_proposal(env: Env, proposal_id: BytesN<32>) -> Option<ConfigProposal> {
+        env.storage().instance().get(&("proposal", proposal_id))
     }
 
-    /// Check if a waste record exists
-    pub fn waste_exists(env: Env, waste_id: u64) -> bool {
-        let key = ("waste", waste_id);
-        env.storage().instance().has(&key)
+    /// Proposals are keyed by a hash of their parameters
+    fn config_proposal_id(env: &Env, change: &ConfigChange) -> BytesN<32> {
+        env.crypto().sha256(&change.clone().to_xdr(env)).into()
     }
 
-    /// Convert waste type enum to a human-readable string.
-    pub fn get_waste_type_string(env: Env, waste_type: WasteType) -> String {
-        String::from_str(&env, waste_type.as_str())
-    }
+    /// Apply the proposal if it has enough approvals, otherwise store it as pending
+    /// The change is logged against the admin whose approval completed it
+    fn settle_config_proposal(
+        env: &Env,
+        admin: &Address,
+        proposal_id: &BytesN<32>,
+        proposal: &ConfigProposal,
+    ) -> bool {
+        let key = ("proposal", proposal_id.clone());
+
+        let admins = Self::get_admins(env.clone());
+        if !proposal.is_approved(&admins, Self::get_admin_threshold(env.clone())) {
+            env.storage().instance().set(&key, proposal);
+            return false;
+        }
 
-    /// Convert participant role enum to a human-readable string.
-    pub fn get_participant_role_string(env: Env, role: ParticipantRole) -> String {
-        String::from_str(&env, role.as_str())
+        match &proposal.change {
+            ConfigChange::Percentages(collector_percentage, owner_percentage) => {
+                Self::apply_percentages(env, admin, *collector_percentage, *owner_percentage)
+            }
+            ConfigChange::Charity(charity_address) => {
+                Self::apply_charity_contract(env, admin, charity_address)
+            }
+            ConfigChange::Admins(admins, threshold) => {
+                Self::apply_admin_set(env, admin, admins, *threshold)
+            }
+        }
+        env.storage().instance().remove(&key);
+
+        true
     }
 
-    /// Validate whether a transfer route is allowed between two participants.
-    /// Valid routes:
-    /// - Recycler -> Collector
-    /// - Recycler -> Manufacturer
-    /// - Collector -> Manufacturer
-    pub fn is_valid_transfer(env: Env, from: Address, to: Address) -> bool {
-        let from_participant: Option<Participant> = env.storage().instance().get(&(from,));
-        let to_participant: Option<Participant> = env.storage().instance().get(&(to,));
+    /// Check caller belongs to the configured admin set
+    fn require_admin_set_member(env: &Env, caller: &Address) {
+        if !Self::get_admins(env.clone()).contains(caller) {
+            panic!("Unauthorized: caller is not in admin set");
+        }
 
-        let (Some(from_p), Some(to_p)) = (from_participant, to_participant) else {
-            return false;
-        };
+        caller.require_auth();
+    }
 
-        if !from_p.is_registered || !to_p.is_registered {
-            return false;
+    /// Block single-admin configuration changes once an admin set is configured
+    fn require_no_admin_set(env: &Env) {
+        if env.storage().instance().has(&("admins",)) {
+            panic!("Change requires admin set approval");
         }
-
-        matches!(
-            (from_p.role, to_p.role),
-            (ParticipantRole::Recycler, ParticipantRole::Collector)
-                | (ParticipantRole::Recycler, ParticipantRole::Manufacturer)
-                | (ParticipantRole::Collector, ParticipantRole::Manufacturer)
-        )
     }
 
-    /// Get the total count of waste records
-    fn get_waste_count(env: &Env) -> u64 {
-        env.storage().instance().get(&("waste_count",)).unwrap_or(0)
+    // ========== Config Audit Log Functions ==========
+
+    /// Append an applied configuration change to the audit log
+    fn log_config_change(
+        env: &Env,
+        admin: &Address,
+        field: &str,
+        old_value: ConfigValue,
+        new_value: ConfigValue,
+    ) {
+        let mut log: Vec<ConfigLogEntry> = env
+            .storage()
+            .instance()
+            .get(&("config_log",))
+            .unwrap_or(Vec::new(env));
+
+        log.push_back(ConfigLogEntry {
+            admin: admin.clone(),
+            field: Symbol::new(env, field),
+            old_value,
+            new_value,
+            timestamp: env.ledger().timestamp(),
+        });
+        while log.len() > CONFIG_LOG_CAPACITY {
+            log.pop_front();
+        }
+        env.storage().instance().set(&("config_log",), &log);
     }
 
-    /// Increment and return the next waste ID
-    fn next_waste_id(env: &Env) -> u64 {
-        let count = Self::get_waste_count(env);
-        let next_id = count + 1;
-        env.storage().instance().set(&("waste_count",), &next_id);
-        next_id
+    /// Get applied configuration changes, oldest first
+    /// Only the latest CONFIG_LOG_CAPACITY changes are kept
+    /// Returns at most `limit` entries (capped at MAX_PAGE_SIZE) starting at `offset`
+    pub fn get_config_log(env: Env, offset: u32, limit: u32) -> Vec<ConfigLogEntry> {
+        let log: Vec<ConfigLogEntry> = env
+            .storage()
+            .instance()
+            .get(&("config_log",))
+            .unwrap_or(Vec::new(&env));
+
+        let end = offset
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(log.len());
+
+        let mut page = Vec::new(&env);
+        for index in offset..end {
+            page.push_back(log.get_unchecked(index));
+        }
+        page
     }
 
-    /// Get the total count of incentive records
-    #[allow(dead_code)]
-    fn get_incentive_count(env: &Env) -> u64 {
+    // ========== Reward Multiplier Functions ==========
+
+    /// Set the per-kilogram reward multiplier for a waste type (admin only)
+    pub fn set_reward_multiplier(
+        env: Env,
+        admin: Address,
+        waste_type: WasteType,
+        multiplier: u32,
+    ) {
+        Self::require_admin(&env, &admin);
+
+        let old_value = Self::get_reward_multiplier(env.clone(), waste_type);
         env.storage()
             .instance()
-            .get(&("incentive_count",))
-            .unwrap_or(0)
+            .set(&("reward_mult", waste_type), &multiplier);
+        Self::log_config_change(
+            &env,
+            &admin,
+            "reward_mult",
+            ConfigValue::Number(old_value as u64),
+            ConfigValue::Number(multiplier as u64),
+        );
     }
 
-    /// Increment and return the next incentive ID
-    #[allow(dead_code)]
-    fn next_incentive_id(env: &Env) -> u64 {
-        let count = Self::get_incentive_count(env);
-        let next_id = count + 1;
+    /// Get the reward multiplier for a waste type
+    /// Falls back to the built-in default when no value is configured
+    pub fn get_reward_multiplier(env: Env, waste_type: WasteType) -> u32 {
         env.storage()
             .instance()
-            .set(&("incentive_count",), &next_id);
-        next_id
+            .get(&("reward_mult", waste_type))
+            .unwrap_or(waste_type.default_reward_multiplier())
     }
 
-    /// Store an incentive record by ID
-    /// Internal helper function for efficient incentive storage
-    fn set_incentive(env: &Env, incentive_id: u64, incentive: &Incentive) {
-        let key = ("incentive", incentive_id);
-        env.storage().instance().set(&key, incentive);
-    }
+    // ========== Carbon Impact Functions ==========
 
-    /// Retrieve an incentive record by ID
-    /// Returns None if incentive doesn't exist
-    fn get_incentive_internal(env: &Env, incentive_id: u64) -> Option<Incentive> {
-        let key = ("incentive", incentive_id);
-        env.storage().instance().get(&key)
-    }
+    /// Set the grams of CO2 avoided per kilogram recycled for a waste type (admin only)
+    pub fn set_co2_coefficient(
+        env: Env,
+        admin: Address,
+        waste_type: WasteType,
+        grams_co2_per_kg: u64,
+    ) {
+        Self::require_admin(&env, &admin);
 
-    /// Retrieve an incentive by ID (internal compatibility alias).
-    fn get_incentive(env: &Env, incentive_id: u64) -> Option<Incentive> {
-        Self::get_incentive_internal(env, incentive_id)
+        let old_value = Self::get_co2_coefficient(env.clone(), waste_type);
+        env.storage()
+            .instance()
+            .set(&("co2_coeff", waste_type), &grams_co2_per_kg);
+        Self::log_config_change(
+            &env,
+            &admin,
+            "co2_coeff",
+            ConfigValue::Number(old_value),
+            ConfigValue::Number(grams_co2_per_kg),
+        );
     }
 
-    /// Check if an incentive record exists
-    pub fn incentive_exists(env: Env, incentive_id: u64) -> bool {
-        let key = ("incentive", incentive_id);
-        env.storage().instance().has(&key)
+    /// Get the grams of CO2 avoided per kilogram for a waste type (defaults to 0)
+    pub fn get_co2_coefficient(env: Env, waste_type: WasteType) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("co2_coeff", waste_type))
+            .unwrap_or(0)
     }
 
-    /// Get global total weight
-    fn get_total_weight(env: &Env) -> u64 {
-        env.storage().instance().get(&TOTAL_WEIGHT).unwrap_or(0)
+    /// Get the grams of CO2 a material avoids: whole kilograms times its type's coefficient
+    /// Returns 0 for unknown materials
+    pub fn get_submission_co2_avoided(env: Env, material_id: u64) -> u64 {
+        Self::get_waste_internal(&env, material_id)
+            .map(|material| Self::calculate_co2_avoided(&env, &material))
+            .unwrap_or(0)
     }
 
-    /// Add to global total weight
-    fn add_to_total_weight(env: &Env, weight: u64) {
-        let current = Self::get_total_weight(env);
-        let new_total = current.checked_add(weight).expect("Overflow in total weight");
-        env.storage().instance().set(&TOTAL_WEIGHT, &new_total);
+    /// Get the grams of CO2 avoided across a participant's verified submissions
+    pub fn get_participant_co2_avoided(env: Env, address: Address) -> u64 {
+        let mut total: u64 = 0;
+        for material_id in Self::get_material_index(&env, &address).iter() {
+            let Some(material) = Self::get_waste_internal(&env, material_id) else {
+                continue;
+            };
+            if material.verified {
+                total = total
+                    .checked_add(Self::calculate_co2_avoided(&env, &material))
+                    .expect("Overflow in CO2 total");
+            }
+        }
+        total
     }
 
-    /// Get global total tokens earned
-    fn get_total_tokens(env: &Env) -> u128 {
-        env.storage().instance().get(&TOTAL_TOKENS).unwrap_or(0)
+    /// Calculate the grams of CO2 a material avoids
+    fn calculate_co2_avoided(env: &Env, material: &Material) -> u64 {
+        (material.weight / 1000)
+            .checked_mul(Self::get_co2_coefficient(env.clone(), material.waste_type))
+            .expect("Overflow in CO2 calculation")
     }
 
-    /// Add to global total tokens
-    fn add_to_total_tokens(env: &Env, tokens: u128) {
-        let current = Self::get_total_tokens(env);
-        let new_total = current.checked_add(tokens).expect("Overflow in total tokens");
-        env.storage().instance().set(&TOTAL_TOKENS, &new_total);
+    /// Calculate reward points for a material using the configured multiplier
+    fn calculate_material_reward(env: &Env, material: &Material) -> Result<u64, ScavengerError> {
+        let multiplier = Self::get_reward_multiplier(env.clone(), material.waste_type);
+        material
+            .calculate_reward_points_with(multiplier)
+            .ok_or(ScavengerError::Overflow)
     }
 
-    /// Calculate total weight for active waste entries in v2 storage.
-    /// Iterates once across the waste ID range to keep reads linear and allocation-free.
-    fn get_total_active_waste_weight(env: &Env) -> u64 {
-        let mut total_weight: u64 = 0;
-        let total_wastes = Self::get_waste_count(env);
-
-        for waste_id in 1..=total_wastes {
-            if let Some(waste) = env
-                .storage()
-                .instance()
-                .get::<_, Waste>(&("waste_v2", waste_id as u128))
-            {
-                if waste.is_active {
-                    let weight = u64::try_from(waste.weight)
-                        .expect("Waste weight exceeds u64 range");
-                    total_weight = total_weight
-                        .checked_add(weight)
-                        .expect("Overflow in active waste total weight");
-                }
-            }
-        }
-
-        total_weight
+    /// Calculate the points awarded when a material is verified now
+    /// Applies the current halving divisor to the speed-adjusted reward
+    fn calculate_verification_reward(env: &Env, material: &Material) -> Result<u64, ScavengerError> {
+        Ok(Self::calculate_speed_adjusted_reward(env, material)? / Self::get_halving_divisor(env))
     }
 
-    /// Create a new manufacturer incentive program
-    /// Only manufacturers can create incentives
-    pub fn create_incentive(
-        env: Env,
-        waste_type: WasteType,
-        reward: u128,
-        max_waste_amount: u128,
-        rewarder: Address,
-    ) -> Incentive {
-        rewarder.require_auth();
-
-        // Validate rewarder is a registered manufacturer
-        let rewarder_key = (rewarder.clone(),);
-        let participant: Participant = env
-            .storage()
-            .instance()
-            .get(&rewarder_key)
-            .expect("Rewarder not registered");
-
-        if !participant.role.can_manufacture() {
-            panic!("Only manufacturers can create incentives");
-        }
+    /// Calculate a material's reward before halving
+    /// Adds the configured speed bonus if verification falls within the bonus window
+    fn calculate_speed_adjusted_reward(env: &Env, material: &Material) -> Result<u64, ScavengerError> {
+        let base = Self::calculate_material_reward(env, material)?;
 
-        // Validate input values
-        if reward == 0 {
-            panic!("Reward must be greater than zero");
-        }
+        let Some((window_seconds, bonus_percent)) = Self::get_speed_bonus(env.clone()) else {
+            return Ok(base);
+        };
 
-        if max_waste_amount == 0 {
-            panic!("Max waste amount must be greater than zero");
+        let elapsed = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(material.submitted_at);
+        if elapsed > window_seconds {
+            return Ok(base);
         }
 
-        // Get next incentive ID
-        let incentive_id = Self::next_incentive_id(&env);
+        let bonus = base
+            .checked_mul(bonus_percent as u64)
+            .ok_or(ScavengerError::Overflow)?
+            / 100;
+        base.checked_add(bonus).ok_or(ScavengerError::Overflow)
+    }
 
-        // Create incentive
-        let incentive = Incentive {
-            id: incentive_id,
-            waste_type,
-            reward,
-            max_waste_amount,
-            rewarder: rewarder.clone(),
-            is_active: true,
-            created_at: env.ledger().timestamp(),
-        };
+    /// Set how many grams earn one incentive `reward_points` unit (admin only)
+    /// The default of 1000 rewards per kilogram; 100 rewards per 100 grams
+    pub fn set_scale_factor(env: Env, admin: Address, scale_factor: u64) {
+        Self::require_admin(&env, &admin);
 
-        // Store incentive
-        Self::set_incentive(&env, incentive_id, &incentive);
+        if scale_factor == 0 {
+            panic!("Scale factor must be greater than zero");
+        }
 
-        incentive
+        let old_value = Self::get_scale_factor(env.clone());
+        env.storage().instance().set(&("scale_factor",), &scale_factor);
+        Self::log_config_change(
+            &env,
+            &admin,
+            "scale_factor",
+            ConfigValue::Number(old_value),
+            ConfigValue::Number(scale_factor),
+        );
     }
 
-    /// Get incentive by ID
-    pub fn get_incentive_by_id(env: Env, incentive_id: u64) -> Option<Incentive> {
-        Self::get_incentive(&env, incentive_id)
+    /// Get the grams per incentive reward unit (defaults to 1000)
+    pub fn get_scale_factor(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("scale_factor",))
+            .unwrap_or(DEFAULT_SCALE_FACTOR)
     }
 
-    /// Update incentive active status
-    /// Only the rewarder can update their incentive
-    pub fn update_incentive_status(
-        env: Env,
-        incentive_id: u64,
-        is_active: bool,
-    ) -> Incentive {
-        let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
-            .expect("Incentive not found");
+    /// Calculate an incentive's base reward for a weight using the configured scale factor
+    /// Returns None if the calculation overflows
+    fn calculate_incentive_base_reward(
+        env: &Env,
+        incentive: &Incentive,
+        weight: u64,
+    ) -> Option<u64> {
+        incentive.calculate_reward_scaled(weight, Self::get_scale_factor(env.clone()))
+    }
 
-        // Require auth from the rewarder
-        incentive.rewarder.require_auth();
+    // ========== Speed Bonus Functions ==========
 
-        incentive.is_active = is_active;
-        Self::set_incentive(&env, incentive_id, &incentive);
+    /// Award an extra percentage of points for verifications made within
+    /// `window_seconds` of submission (admin only)
+    pub fn set_speed_bonus(env: Env, admin: Address, window_seconds: u64, bonus_percent: u32) {
+        Self::require_admin(&env, &admin);
 
-        incentive
+        let old_value = Self::get_speed_bonus(env.clone());
+        env.storage()
+            .instance()
+            .set(&("speed_bonus",), &(window_seconds, bonus_percent));
+        Self::log_config_change(
+            &env,
+            &admin,
+            "bonus_window",
+            ConfigValue::from_number(old_value.map(|(window, _)| window)),
+            ConfigValue::Number(window_seconds),
+        );
+        Self::log_config_change(
+            &env,
+            &admin,
+            "bonus_percent",
+            ConfigValue::from_number(old_value.map(|(_, percent)| percent as u64)),
+            ConfigValue::Number(bonus_percent as u64),
+        );
     }
 
-    /// Update an existing incentive's reward and maximum waste amount
-    /// Only the rewarder can update their incentive
-    /// Only active incentives can be updated
-    pub fn update_incentive(
-        env: Env,
-        incentive_id: u64,
-        new_reward: u128,
-        new_max_waste_amount: u128,
-    ) -> Incentive {
-        // Step 1: Retrieve incentive (existence check)
-        let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
-            .expect("Incentive not found");
+    /// Get the speed bonus as (window_seconds, bonus_percent), if configured
+    pub fn get_speed_bonus(env: Env) -> Option<(u64, u32)> {
+        env.storage().instance().get(&("speed_bonus",))
+    }
 
-        // Step 2: Authorization check
-        incentive.rewarder.require_auth();
+    // ========== Reward Halving Functions ==========
 
-        // Step 3: Active status check
-        if !incentive.is_active {
-            panic!("Incentive is not active");
-        }
+    /// Configure reward halving (admin only)
+    /// Verification rewards are divided by `initial_divisor ^ epochs_elapsed`, where
+    /// epochs of `epoch_seconds` are counted from the contract start time recorded when
+    /// the admin was initialized. A divisor of 1 disables halving
+    pub fn set_halving(env: Env, admin: Address, epoch_seconds: u64, initial_divisor: u64) {
+        Self::require_admin(&env, &admin);
 
-        // Step 4: Input validation
-        if new_reward == 0 {
-            panic!("Reward must be greater than zero");
+        if epoch_seconds == 0 {
+            panic!("Epoch length must be positive");
         }
-        if new_max_waste_amount == 0 {
-            panic!("Max waste amount must be greater than zero");
+        if initial_divisor == 0 {
+            panic!("Halving divisor must be positive");
         }
 
-        // Step 5: Update fields (atomic)
-        incentive.reward = new_reward;
-        incentive.max_waste_amount = new_max_waste_amount;
-
-        // Step 6: Persist to storage
-        Self::set_incentive(&env, incentive_id, &incentive);
+        // Contracts initialized before the start time was recorded start counting now
+        if !env.storage().instance().has(&("start_time",)) {
+            env.storage()
+                .instance()
+                .set(&("start_time",), &env.ledger().timestamp());
+        }
 
-        // Step 7: Emit event
-        env.events().publish(
-            (symbol_short!("inc_upd"), incentive_id),
-            (incentive.rewarder.clone(), new_reward, new_max_waste_amount)
+        let old_value = Self::get_halving(env.clone());
+        env.storage()
+            .instance()
+            .set(&("halving",), &(epoch_seconds, initial_divisor));
+        Self::log_config_change(
+            &env,
+            &admin,
+            "halving_epoch",
+            ConfigValue::from_number(old_value.map(|(epoch, _)| epoch)),
+            ConfigValue::Number(epoch_seconds),
+        );
+        Self::log_config_change(
+            &env,
+            &admin,
+            "halving_divisor",
+            ConfigValue::from_number(old_value.map(|(_, divisor)| divisor)),
+            ConfigValue::Number(initial_divisor),
         );
-
-        incentive
     }
 
+    /// Get the halving config as (epoch_seconds, initial_divisor), if configured
+    pub fn get_halving(env: Env) -> Option<(u64, u64)> {
+        env.storage().instance().get(&("halving",))
+    }
 
-    /// Calculate reward for a given waste amount based on an incentive
-    /// Returns the reward amount, respecting max_waste_amount and is_active status
-    pub fn calculate_incentive_reward(
-        env: Env,
-        incentive_id: u64,
-        waste_amount: u64,
-    ) -> u128 {
-        let incentive: Incentive = Self::get_incentive(&env, incentive_id)
-            .expect("Incentive not found");
-
-        // Check if incentive is active
-        if !incentive.is_active {
+    /// Get the number of halving epochs elapsed (0 when halving is not configured)
+    pub fn get_halving_epoch(env: Env) -> u64 {
+        let Some((epoch_seconds, _)) = Self::get_halving(env.clone()) else {
             return 0;
-        }
+        };
+        let start: u64 = env
+            .storage()
+            .instance()
+            .get(&("start_time",))
+            .unwrap_or(0);
 
-        // Convert waste_amount to u128 for calculation
-        let waste_amount_u128 = waste_amount as u128;
+        env.ledger().timestamp().saturating_sub(start) / epoch_seconds
+    }
 
-        // Cap waste amount at max_waste_amount
-        let eligible_amount = if waste_amount_u128 > incentive.max_waste_amount {
-            incentive.max_waste_amount
-        } else {
-            waste_amount_u128
+    /// Divisor applied to verification rewards for the current epoch, capped at MAX_HALVING_DIVISOR
+    fn get_halving_divisor(env: &Env) -> u64 {
+        let Some((_, initial_divisor)) = Self::get_halving(env.clone()) else {
+            return 1;
         };
+        let epochs = u32::try_from(Self::get_halving_epoch(env.clone())).unwrap_or(u32::MAX);
 
-        // Calculate reward using checked arithmetic
-        // reward_per_gram * eligible_amount
-        eligible_amount
-            .checked_mul(incentive.reward)
-            .and_then(|result| result.checked_div(1000)) // Assuming reward is per kg, divide by 1000 for grams
-            .expect("Overflow in reward calculation")
+        initial_divisor
+            .saturating_pow(epochs)
+            .clamp(1, MAX_HALVING_DIVISOR)
     }
 
-    /// Get all incentives for a specific waste type
-    pub fn get_incentives_by_waste_type(
-        env: Env,
-        waste_type: WasteType,
-    ) -> soroban_sdk::Vec<Incentive> {
-        let mut results = soroban_sdk::Vec::new(&env);
-        let count = Self::get_incentive_count(&env);
+    // ========== Minimum Weight Functions ==========
 
-        for i in 1..=count {
-            if let Some(incentive) = Self::get_incentive(&env, i) {
-                if incentive.waste_type == waste_type {
-                    results.push_back(incentive);
-                }
-            }
-        }
+    /// Set the minimum accepted material weight in grams (admin only)
+    pub fn set_min_weight(env: Env, admin: Address, grams: u64) {
+        Self::require_admin(&env, &admin);
 
-        results
+        let old_value = Self::get_min_weight(env.clone());
+        env.storage().instance().set(&("min_weight",), &grams);
+        Self::log_config_change(
+            &env,
+            &admin,
+            "min_weight",
+            ConfigValue::Number(old_value),
+            ConfigValue::Number(grams),
+        );
     }
 
-    /// Get all active incentives
-    pub fn get_active_incentives(env: Env) -> soroban_sdk::Vec<Incentive> {
-        let mut results = soroban_sdk::Vec::new(&env);
-        let count = Self::get_incentive_count(&env);
+    /// Get the minimum accepted material weight in grams (defaults to 100g)
+    pub fn get_min_weight(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("min_weight",))
+            .unwrap_or(DEFAULT_MIN_WEIGHT)
+    }
 
-        for i in 1..=count {
-            if let Some(incentive) = Self::get_incentive(&env, i) {
-                if incentive.is_active {
-                    results.push_back(incentive);
-                }
-            }
+    /// Reject submissions lighter than the configured minimum weight
+    fn require_min_weight(env: &Env, weight: u64) {
+        if weight < Self::get_min_weight(env.clone()) {
+            panic!("Weight below minimum");
         }
-
-        results
     }
 
+    // ========== Rejection Penalty Functions ==========
 
-    /// Get participant information
-    pub fn get_participant(env: Env, address: Address) -> Option<Participant> {
-        let key = (address,);
-        env.storage().instance().get(&key)
-    }
+    /// Set the reward points deducted from a submitter when their material is rejected (admin only)
+    pub fn set_rejection_penalty(env: Env, admin: Address, points: u64) {
+        Self::require_admin(&env, &admin);
 
-    /// Get participant information with current statistics
-    /// Returns participant details along with their recycling statistics
-    /// Returns None if participant is not registered
-    pub fn get_participant_info(env: Env, address: Address) -> Option<ParticipantInfo> {
-        let participant = Self::get_participant(env.clone(), address.clone())?;
-        let stats = Self::get_stats(env, address.clone())
-            .unwrap_or_else(|| RecyclingStats::new(address));
-        
-        Some(ParticipantInfo {
-            participant,
-            stats,
-        })
+        let old_value = Self::get_rejection_penalty(env.clone());
+        env.storage()
+            .instance()
+            .set(&("rejection_penalty",), &points);
+        Self::log_config_change(
+            &env,
+            &admin,
+            "rejection_penalty",
+            ConfigValue::Number(old_value),
+            ConfigValue::Number(points),
+        );
     }
 
-    /// Update participant role
-    /// Preserves registration timestamp and other data
-    pub fn update_role(env: Env, address: Address, new_role: ParticipantRole) -> Participant {
-        address.require_auth();
+    /// Get the rejection penalty in reward points (defaults to 0, no penalty)
+    pub fn get_rejection_penalty(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("rejection_penalty",))
+            .unwrap_or(0)
+    }
 
-        let mut participant: Participant =
-            Self::get_participant(env.clone(), address.clone()).expect("Participant not found");
+    // ========== Donation Bonus Functions ==========
 
-        // Validate participant is registered
-        if !participant.is_registered {
-            panic!("Participant is not registered");
-        }
+    /// Set the reward points per kg awarded to an owner for donating a waste to charity (admin only)
+    pub fn set_donation_bonus(env: Env, admin: Address, points: u64) {
+        Self::require_admin(&env, &admin);
 
-        participant.role = new_role;
-        Self::set_participant(&env, &address, &participant);
+        let old_value = Self::get_donation_bonus(env.clone());
+        env.storage().instance().set(&("donation_bonus",), &points);
+        Self::log_config_change(
+            &env,
+            &admin,
+            "donation_bonus",
+            ConfigValue::Number(old_value),
+            ConfigValue::Number(points),
+        );
+    }
 
-        participant
+    /// Get the donation bonus in reward points per kg (defaults to 0, no bonus)
+    pub fn get_donation_bonus(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("donation_bonus",))
+            .unwrap_or(0)
     }
 
+    // ========== Auto-Verification Functions ==========
 
-    /// Deregister a participant (sets is_registered to false)
-    pub fn deregister_participant(env: Env, address: Address) -> Participant {
-        address.require_auth();
+    /// Set the weight in grams below which submissions from verified contributors are
+    /// verified automatically (admin only); 0 disables auto-verification
+    pub fn set_auto_verify_threshold(env: Env, admin: Address, max_weight: u64) {
+        Self::require_admin(&env, &admin);
 
-        let key = (address.clone(),);
-        let mut participant: Participant = env
-            .storage()
+        let old_value = Self::get_auto_verify_threshold(env.clone());
+        env.storage()
             .instance()
-            .get(&key)
-            .expect("Participant not found");
-
-        participant.is_registered = false;
-        env.storage().instance().set(&key, &participant);
-
-        participant
+            .set(&("auto_verify_threshold",), &max_weight);
+        Self::log_config_change(
+            &env,
+            &admin,
+            "auto_verify_threshold",
+            ConfigValue::Number(old_value),
+            ConfigValue::Number(max_weight),
+        );
     }
 
-    /// Update participant location
-    pub fn update_location(
-        env: Env,
-        address: Address,
-        latitude: i128,
-        longitude: i128,
-    ) -> Participant {
-        address.require_auth();
-
-        let key = (address.clone(),);
-        let mut participant: Participant = env
-            .storage()
+    /// Get the auto-verification weight threshold in grams (defaults to 0, disabled)
+    pub fn get_auto_verify_threshold(env: Env) -> u64 {
+        env.storage()
             .instance()
-            .get(&key)
-            .expect("Participant not found");
+            .get(&("auto_verify_threshold",))
+            .unwrap_or(0)
+    }
 
-        // Validate participant is registered
-        if !participant.is_registered {
-            panic!("Participant is not registered");
+    /// Whether a submission qualifies for auto-verification
+    /// Trust is judged on the submitter's record before this submission
+    fn qualifies_for_auto_verify(env: &Env, submitter: &Address, weight: u64) -> bool {
+        if weight >= Self::get_auto_verify_threshold(env.clone()) {
+            return false;
         }
 
-        participant.latitude = latitude;
-        participant.longitude = longitude;
-        env.storage().instance().set(&key, &participant);
-
-        participant
+        Self::get_stats(env.clone(), submitter.clone())
+            .map(|stats| stats.is_verified_contributor())
+            .unwrap_or(false)
     }
 
-    // ========== Waste Transfer History Functions ==========
+    // ========== Pause Functions ==========
 
-    /// Get transfer history for a specific waste
-    /// Returns chronologically ordered list of transfers
-    pub fn get_transfer_history(env: Env, waste_id: u64) -> Vec<WasteTransfer> {
-        let key = ("transfers", waste_id);
-        env.storage().instance().get(&key).unwrap_or(Vec::new(&env))
-    }
+    /// Halt all state-mutating activity (admin only)
+    pub fn pause(env: Env, admin: Address) {
+        Self::require_admin(&env, &admin);
 
-    /// Get complete transfer history for a waste (alias for get_transfer_history)
-    /// Returns chronologically ordered list of all transfers
-    /// Includes all transfer details: from, to, timestamp, and notes
-    pub fn get_waste_transfer_history(env: Env, waste_id: u64) -> Vec<WasteTransfer> {
-        Self::get_transfer_history(env, waste_id)
+        Self::set_paused(&env, &admin, true);
     }
 
-    /// Record a waste transfer
-    /// Appends to immutable history
-    fn record_transfer(env: &Env, waste_id: u64, from: Address, to: Address, note: String) {
-        let key = ("transfers", waste_id);
-        let mut history: Vec<WasteTransfer> =
-            env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+    /// Resume normal activity after a pause (admin only)
+    pub fn unpause(env: Env, admin: Address) {
+        Self::require_admin(&env, &admin);
 
-        let transfer = WasteTransfer::new(
-            waste_id as u128,
-            from,
-            to,
-            env.ledger().timestamp(),
-            0,
-            0,
-            soroban_sdk::symbol_short!("note"),
-        );
+        Self::set_paused(&env, &admin, false);
+    }
 
-        history.push_back(transfer);
-        env.storage().instance().set(&key, &history);
+    /// Store the pause flag and record the change in the config log
+    fn set_paused(env: &Env, admin: &Address, paused: bool) {
+        let old_value = Self::is_paused(env.clone());
+        env.storage().instance().set(&("paused",), &paused);
+        Self::log_config_change(
+            env,
+            admin,
+            "paused",
+            ConfigValue::Flag(old_value),
+            ConfigValue::Flag(paused),
+        );
     }
 
-    /// Transfer waste ownership from one participants to another
-    pub fn transfer_waste(
-        env: Env,
-        waste_id: u64,
-        from: Address,
-        to: Address,
-        note: String,
-    ) -> Material {
-        from.require_auth();
+    /// Check whether the contract is currently paused
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&("paused",))
+            .unwrap_or(false)
+    }
 
-        // Verify both participants are registered
-        if !Self::is_participant_registered(env.clone(), from.clone()) {
-            panic!("Sender not registered");
-        }
-        if !Self::is_participant_registered(env.clone(), to.clone()) {
-            panic!("Receiver not registered");
+    /// Reject state-mutating calls while the contract is paused
+    fn require_not_paused(env: &Env) {
+        if Self::is_paused(env.clone()) {
+            panic!("Contract is paused");
         }
+    }
 
-        // Get and update material
-        let mut material: Material = Self::get_waste_internal(&env, waste_id).expect("Waste not found");
-
-        // Verify sender owns the waste
-        if material.submitter != from {
-            panic!("Only waste owner can transfer");
-        }
+    // ========== Submission Cap Functions ==========
 
-        // Update ownership
-        material.submitter = to.clone();
-        Self::set_waste(&env, waste_id, &material);
+    /// Cap the weight a participant of the given role may submit per window (admin only)
+    pub fn set_submission_cap(
+        env: Env,
+        admin: Address,
+        role: ParticipantRole,
+        max_weight_per_window: u64,
+        window_seconds: u64,
+    ) {
+        Self::require_admin(&env, &admin);
 
-        // Record transfer in history
-        Self::record_transfer(&env, waste_id, from, to, note);
+        if window_seconds == 0 {
+            panic!("Window must be greater than zero");
+        }
 
-        material
+        let old_value = Self::get_submission_cap(env.clone(), role);
+        env.storage()
+            .instance()
+            .set(&("sub_cap", role), &(max_weight_per_window, window_seconds));
+        Self::log_config_change(
+            &env,
+            &admin,
+            "cap_weight",
+            ConfigValue::from_number(old_value.map(|(weight, _)| weight)),
+            ConfigValue::Number(max_weight_per_window),
+        );
+        Self::log_config_change(
+            &env,
+            &admin,
+            "cap_window",
+            ConfigValue::from_number(old_value.map(|(_, window)| window)),
+            ConfigValue::Number(window_seconds),
+        );
     }
 
-    /// Get all transfers for a participants (as sender)
-    pub fn get_transfers_from(env: Env, _address: Address) -> Vec<(u64, Vec<WasteTransfer>)> {
-        // Note: This is a simplified implementation
-        // In production, you'd want to maintain an index for efficient queries
-        // This would need to iterate through all wastes
-        // For now, returning empty as this requires additional indexing
-        Vec::new(&env)
+    /// Get the submission cap for a role as (max_weight_per_window, window_seconds)
+    pub fn get_submission_cap(env: Env, role: ParticipantRole) -> Option<(u64, u64)> {
+        env.storage().instance().get(&("sub_cap", role))
     }
 
-    /// Get all transfers for a participant (as receiver)
-    pub fn get_transfers_to(env: Env, _address: Address) -> Vec<(u64, Vec<WasteTransfer>)> {
-        // Note: This is a simplified implementation
-        // In production, you'd want to maintain an index for efficient queries
-        // This would need to iterate through all wastes
-        // For now, returning empty as this requires additional indexing
-        Vec::new(&env)
+    /// Add weight to the submitter's rolling window, resetting it once expired
+    /// Panics if the role's cap would be crossed
+    fn apply_submission_cap(env: &Env, submitter: &Address, weight: u64) {
+        let Some(participant) = Self::get_participant(env.clone(), submitter.clone()) else {
+            return;
+        };
+        let Some((max_weight, window_seconds)) =
+            Self::get_submission_cap(env.clone(), participant.role)
+        else {
+            return;
+        };
 
-    }
+        let now = env.ledger().timestamp();
+        let key = ("sub_window", submitter.clone());
+        let (mut window_start, mut accumulated): (u64, u64) =
+            env.storage().instance().get(&key).unwrap_or((now, 0));
 
-    /// Validate if a participant can perform a specific action
-    pub fn can_collect(env: Env, address: Address) -> bool {
-        let key = (address,);
-        if let Some(participant) = env.storage().instance().get::<_, Participant>(&key) {
-            participant.is_registered && participant.role.can_collect_materials()
-        } else {
-            false
+        if now >= window_start.saturating_add(window_seconds) {
+            window_start = now;
+            accumulated = 0;
         }
-    }
 
-    /// Validate if a participants can manufacture
-    pub fn can_manufacture(env: Env, address: Address) -> bool {
-        let key = (address,);
-        if let Some(participant) = env.storage().instance().get::<_, Participant>(&key) {
-            participant.is_registered && participant.role.can_manufacture()
-        } else {
-            false
+        let accumulated = accumulated
+            .checked_add(weight)
+            .expect("Overflow in submission window");
+        if accumulated > max_weight {
+            panic!("Submission cap exceeded");
         }
-    }
 
-    /// Submit a new material for recycling
-    pub fn submit_material(
-        env: Env,
-        waste_type: WasteType,
-        weight: u64,
-        submitter: Address,
-        description: String,
-    ) -> Material {
-        submitter.require_auth();
+        env.storage().instance().set(&key, &(window_start, accumulated));
+    }
 
-        // Validate submitter is registered
-        Self::require_registered(&env, &submitter);
+    /// Reject latitude/longitude outside the valid range (scaled by 1e6)
+    fn validate_coordinates(latitude: i128, longitude: i128) {
+        if !(-90_000_000..=90_000_000).contains(&latitude)
+            || !(-180_000_000..=180_000_000).contains(&longitude)
+        {
+            panic!("Invalid coordinates");
+        }
+    }
 
-        // Get next waste ID using the new storage system
-        let waste_id = Self::next_waste_id(&env);
+    // ========== Participant Storage Functions ==========
 
-        // Create material
-        let material = Material::new(
-            waste_id,
-            waste_type,
-            weight,
-            submitter.clone(),
-            env.ledger().timestamp(),
-            description,
-        );
+    /// Store a participant record
+    /// Internal helper function for efficient participant storage
+    fn set_participant(env: &Env, address: &Address, participant: &Participant) {
+        let key = (address.clone(),);
+        env.storage().instance().set(&key, participant);
+    }
 
-        // Store waste using the new storage systems
-        Self::set_waste(&env, waste_id, &material);
+    /// Check if a participant is registered
+    pub fn is_participant_registered(env: Env, address: Address) -> bool {
+        let key = (address,);
+        env.storage().instance().has(&key)
+    }
 
-        // Update stats
-        let mut stats: RecyclingStats = env
-            .storage()
-            .instance()
-            .get(&("stats", submitter.clone()))
-            .unwrap_or_else(|| RecyclingStats::new(submitter.clone()));
+    /// Register a new participant with a specific role
+    /// Prevents duplicate registrations
+    pub fn register_participant(
+        env: Env,
+        address: Address,
+        role: ParticipantRole,
+        name: soroban_sdk::Symbol,
+        latitude: i128,
+        longitude: i128,
+    ) -> Participant {
+        address.require_auth();
 
-        stats.record_submission(&material);
-        env.storage().instance().set(&("stats", submitter.clone()), &stats);
+        Self::register_participant_internal(&env, address, role, name, latitude, longitude)
+    }
 
-        // Update participant stats
-        Self::update_participant_stats(&env, &submitter, weight, 0);
+    /// Register many participants on their behalf (admin only)
+    /// Already registered addresses are skipped when `skip_existing` is set,
+    /// otherwise they abort the whole batch; returns the newly registered participants
+    pub fn register_participants_batch(
+        env: Env,
+        admin: Address,
+        entries: Vec<(Address, ParticipantRole, Symbol, i128, i128)>,
+        skip_existing: bool,
+    ) -> Vec<Participant> {
+        Self::require_admin(&env, &admin);
 
-        // Update global total weight
-        Self::add_to_total_weight(&env, weight);
+        let mut registered = Vec::new(&env);
+        for (address, role, name, latitude, longitude) in entries.iter() {
+            if skip_existing && Self::is_participant_registered(env.clone(), address.clone()) {
+                continue;
+            }
+            registered.push_back(Self::register_participant_internal(
+                &env, address, role, name, latitude, longitude,
+            ));
+        }
 
-        material
+        registered
     }
 
-    /// Register new waste with location data
-    pub fn recycle_waste(
-        env: Env,
-        waste_type: WasteType,
-        weight: u128,
-        recycler: Address,
+    /// Registration logic shared by the authorized entrypoints
+    fn register_participant_internal(
+        env: &Env,
+        address: Address,
+        role: ParticipantRole,
+        name: Symbol,
         latitude: i128,
         longitude: i128,
-    ) -> u128 {
-        recycler.require_auth();
-
-        if !Self::is_participant_registered(env.clone(), recycler.clone()) {
-            panic!("Participant not registered");
+    ) -> Participant {
+        // Check if already registered
+        if Self::is_participant_registered(env.clone(), address.clone()) {
+            panic!("Participant already registered");
         }
 
-        let waste_id = Self::next_waste_id(&env) as u128;
-        let timestamp = env.ledger().timestamp();
+        Self::validate_coordinates(latitude, longitude);
 
-        let waste = types::Waste::new(
-            waste_id,
-            waste_type,
-            weight,
-            recycler.clone(),
+        let participant = Participant {
+            address: address.clone(),
+            role,
+            name,
             latitude,
             longitude,
-            timestamp,
-            true,
-            false,
-            recycler.clone(),
-        );
+            is_registered: true,
+            total_waste_processed: 0,
+            total_tokens_earned: 0,
+            registered_at: env.ledger().timestamp(),
+        };
 
-        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        // Store participant using helper function
+        Self::set_participant(env, &address, &participant);
+        Self::add_to_participant_registry(env, &address);
 
-        let mut waste_list: Vec<u128> = env
-            .storage()
-            .instance()
-            .get(&("participant_wastes", recycler.clone()))
-            .unwrap_or(Vec::new(&env));
-        waste_list.push_back(waste_id);
+        participant
+    }
+
+    /// Get every address that has ever registered as a participant
+    fn get_participant_registry(env: &Env) -> Vec<Address> {
         env.storage()
             .instance()
-            .set(&("participant_wastes", recycler.clone()), &waste_list);
+            .get(&("all_participants",))
+            .unwrap_or(Vec::new(env))
+    }
 
-        // Emit waste registered event
-        events::emit_waste_registered(
-            &env,
-            waste_id,
-            &recycler,
-            waste_type,
-            weight,
-            latitude,
-            longitude,
-        );
+    /// Append an address to the participant registry (once per address)
+    /// Deregistered participants stay in the registry; callers filter by `is_registered`
+    fn add_to_participant_registry(env: &Env, address: &Address) {
+        let mut registry = Self::get_participant_registry(env);
+        if registry.contains(address) {
+            return;
+        }
+        registry.push_back(address.clone());
+        env.storage()
+            .instance()
+            .set(&("all_participants",), &registry);
+    }
 
-        waste_id
+    /// Get the number of addresses that have registered as participants
+    pub fn get_participant_count(env: Env) -> u32 {
+        Self::get_participant_registry(&env).len()
     }
 
-    /// Transfer waste between participants with location tracking
-    pub fn transfer_waste_v2(
-        env: Env,
-        waste_id: u128,
+    /// Get a page of registered participant addresses in registration order
+    /// Returns at most `limit` addresses (capped at MAX_PAGE_SIZE) starting at `offset`
+    pub fn get_all_participants(env: Env, offset: u32, limit: u32) -> Vec<Address> {
+        let registry = Self::get_participant_registry(&env);
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut page = Vec::new(&env);
+
+        if offset >= registry.len() {
+            return page;
+        }
+
+        let end = offset.saturating_add(limit).min(registry.len());
+        for i in offset..end {
+            page.push_back(registry.get(i).unwrap());
+        }
+
+        page
+    }
+
+    /// Find registered participants who joined before `since_ts` and never submitted
+    /// Scans the registry in registration order and returns at most `limit`
+    /// addresses (capped at MAX_PAGE_SIZE)
+    pub fn get_inactive_participants(env: Env, since_ts: u64, limit: u32) -> Vec<Address> {
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut inactive = Vec::new(&env);
+
+        for address in Self::get_participant_registry(&env).iter() {
+            if inactive.len() >= limit {
+                break;
+            }
+
+            let Some(participant) = Self::get_participant(env.clone(), address.clone()) else {
+                continue;
+            };
+            if !participant.is_registered || participant.registered_at >= since_ts {
+                continue;
+            }
+
+            let has_submitted = Self::get_stats(env.clone(), address.clone())
+                .is_some_and(|stats| stats.total_submissions > 0);
+            if !has_submitted {
+                inactive.push_back(address);
+            }
+        }
+
+        inactive
+    }
+
+    /// Update participant statistics after processing waste
+    /// Uses checked arithmetic to prevent overflow
+    fn update_participant_stats(
+        env: &Env,
+        address: &Address,
+        waste_weight: u64,
+        tokens_earned: u64,
+    ) {
+        let key = (address.clone(),);
+        if let Some(mut participant) = env.storage().instance().get::<_, Participant>(&key) {
+            // Saturate rather than abort so a participant near u128::MAX isn't locked out
+            let waste_total = participant
+                .total_waste_processed
+                .checked_add(waste_weight as u128);
+            let tokens_total = participant
+                .total_tokens_earned
+                .checked_add(tokens_earned as u128);
+
+            participant.total_waste_processed = waste_total.unwrap_or(u128::MAX);
+            participant.total_tokens_earned = tokens_total.unwrap_or(u128::MAX);
+
+            env.storage().instance().set(&key, &participant);
+
+            if waste_total.is_none() || tokens_total.is_none() {
+                events::emit_stats_saturated(
+                    env,
+                    address,
+                    participant.total_waste_processed,
+                    participant.total_tokens_earned,
+                );
+            }
+            
+            // Update global total tokens if tokens were earned
+            if tokens_earned > 0 {
+                Self::add_to_total_tokens(env, tokens_earned as u128);
+            }
+        }
+    }
+
+    /// Remove previously awarded tokens from a participant, saturating at zero
+    fn revoke_participant_tokens(env: &Env, address: &Address, tokens: u64) {
+        let key = (address.clone(),);
+        if let Some(mut participant) = env.storage().instance().get::<_, Participant>(&key) {
+            participant.total_tokens_earned =
+                participant.total_tokens_earned.saturating_sub(tokens as u128);
+            env.storage().instance().set(&key, &participant);
+
+            if tokens > 0 {
+                Self::subtract_from_total_tokens(env, tokens as u128);
+            }
+        }
+    }
+
+    /// Validate that a participant is registered before allowing restricted actions
+    /// Deregistered participants keep their record, so existence alone is not enough
+    fn require_registered(env: &Env, address: &Address) -> Result<(), ScavengerError> {
+        let key = (address.clone(),);
+        let participant: Option<Participant> = env.storage().instance().get(&key);
+
+        match participant {
+            Some(p) if p.is_registered => Ok(()),
+            _ => Err(ScavengerError::NotRegistered),
+        }
+    }
+
+    /// Store a waste record by ID
+    /// Internal helper function for efficient waste storage
+    fn set_waste(env: &Env, waste_id: u64, material: &Material) {
+        let key = ("waste", waste_id);
+        env.storage().instance().set(&key, material);
+    }
+
+    /// Retrieve a waste record by ID (internal helper)
+    /// Returns None if waste doesn't exist
+    fn get_waste_internal(env: &Env, waste_id: u64) -> Option<Material> {
+        let key = ("waste", waste_id);
+        env.storage().instance().get(&key)
+    }
+
+    /// Check if a waste record exists
+    pub fn waste_exists(env: Env, waste_id: u64) -> bool {
+        let key = ("waste", waste_id);
+        env.storage().instance().has(&key)
+    }
+
+    /// Get the IDs of materials currently owned by a participant
+    fn get_material_index(env: &Env, owner: &Address) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&("material_index", owner.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Append a material ID to its owner's index
+    fn add_to_material_index(env: &Env, owner: &Address, waste_id: u64) {
+        let mut index = Self::get_material_index(env, owner);
+        index.push_back(waste_id);
+        env.storage()
+            .instance()
+            .set(&("material_index", owner.clone()), &index);
+    }
+
+    /// Get the IDs of materials submitted with a given waste type, in submission order
+    fn get_type_index(env: &Env, waste_type: WasteType) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&("type_index", waste_type))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Append a material ID to its waste type's index
+    fn add_to_type_index(env: &Env, waste_type: WasteType, waste_id: u64) {
+        let mut index = Self::get_type_index(env, waste_type);
+        index.push_back(waste_id);
+        env.storage()
+            .instance()
+            .set(&("type_index", waste_type), &index);
+    }
+
+    /// Remove a material ID from its owner's index
+    fn remove_from_material_index(env: &Env, owner: &Address, waste_id: u64) {
+        let index = Self::get_material_index(env, owner);
+        let mut new_index = Vec::new(env);
+        for id in index.iter() {
+            if id != waste_id {
+                new_index.push_back(id);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&("material_index", owner.clone()), &new_index);
+    }
+
+    /// Convert waste type enum to a human-readable string.
+    pub fn get_waste_type_string(env: Env, waste_type: WasteType) -> String {
+        String::from_str(&env, waste_type.as_str())
+    }
+
+    /// Convert participant role enum to a human-readable string.
+    pub fn get_participant_role_string(env: Env, role: ParticipantRole) -> String {
+        String::from_str(&env, role.as_str())
+    }
+
+    /// Validate whether a transfer route is allowed between two participants.
+    /// Valid routes:
+    /// - Recycler -> Collector
+    /// - Recycler -> Manufacturer
+    /// - Collector -> Manufacturer
+    pub fn is_valid_transfer(env: Env, from: Address, to: Address) -> bool {
+        let from_participant: Option<Participant> = env.storage().instance().get(&(from,));
+        let to_participant: Option<Participant> = env.storage().instance().get(&(to,));
+
+        let (Some(from_p), Some(to_p)) = (from_participant, to_participant) else {
+            return false;
+        };
+
+        if !from_p.is_registered || !to_p.is_registered {
+            return false;
+        }
+
+        matches!(
+            (from_p.role, to_p.role),
+            (ParticipantRole::Recycler, ParticipantRole::Collector)
+                | (ParticipantRole::Recycler, ParticipantRole::Manufacturer)
+                | (ParticipantRole::Collector, ParticipantRole::Manufacturer)
+        )
+    }
+
+    /// Get the total count of waste records
+    fn get_waste_count(env: &Env) -> u64 {
+        env.storage().instance().get(&("waste_count",)).unwrap_or(0)
+    }
+
+    /// Increment and return the next waste ID
+    fn next_waste_id(env: &Env) -> u64 {
+        let count = Self::get_waste_count(env);
+        let next_id = count + 1;
+        env.storage().instance().set(&("waste_count",), &next_id);
+        next_id
+    }
+
+    /// Get the total count of incentive records
+    #[allow(dead_code)]
+    fn get_incentive_count(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("incentive_count",))
+            .unwrap_or(0)
+    }
+
+    /// Increment and return the next incentive ID
+    #[allow(dead_code)]
+    fn next_incentive_id(env: &Env) -> u64 {
+        let count = Self::get_incentive_count(env);
+        let next_id = count + 1;
+        env.storage()
+            .instance()
+            .set(&("incentive_count",), &next_id);
+        next_id
+    }
+
+    /// Store an incentive record by ID
+    /// Internal helper function for efficient incentive storage
+    fn set_incentive(env: &Env, incentive_id: u64, incentive: &Incentive) {
+        let key = ("incentive", incentive_id);
+        env.storage().instance().set(&key, incentive);
+    }
+
+    /// Retrieve an incentive record by ID
+    /// Returns None if incentive doesn't exist
+    fn get_incentive_internal(env: &Env, incentive_id: u64) -> Option<Incentive> {
+        let key = ("incentive", incentive_id);
+        env.storage().instance().get(&key)
+    }
+
+    /// Retrieve an incentive by ID (internal compatibility alias).
+    fn get_incentive(env: &Env, incentive_id: u64) -> Option<Incentive> {
+        Self::get_incentive_internal(env, incentive_id)
+    }
+
+    /// Check if an incentive record exists
+    pub fn incentive_exists(env: Env, incentive_id: u64) -> bool {
+        let key = ("incentive", incentive_id);
+        env.storage().instance().has(&key)
+    }
+
+    /// Get global total weight
+    fn get_total_weight(env: &Env) -> u64 {
+        env.storage().instance().get(&TOTAL_WEIGHT).unwrap_or(0)
+    }
+
+    /// Add to global total weight
+    fn add_to_total_weight(env: &Env, weight: u64) {
+        let current = Self::get_total_weight(env);
+        let new_total = current.checked_add(weight).expect("Overflow in total weight");
+        env.storage().instance().set(&TOTAL_WEIGHT, &new_total);
+    }
+
+    /// Get global total tokens earned
+    fn get_total_tokens(env: &Env) -> u128 {
+        env.storage().instance().get(&TOTAL_TOKENS).unwrap_or(0)
+    }
+
+    /// Add to global total tokens
+    fn add_to_total_tokens(env: &Env, tokens: u128) {
+        let current = Self::get_total_tokens(env);
+        let new_total = current.checked_add(tokens).expect("Overflow in total tokens");
+        env.storage().instance().set(&TOTAL_TOKENS, &new_total);
+    }
+
+    /// Subtract from global total tokens, saturating at zero
+    fn subtract_from_total_tokens(env: &Env, tokens: u128) {
+        let new_total = Self::get_total_tokens(env).saturating_sub(tokens);
+        env.storage().instance().set(&TOTAL_TOKENS, &new_total);
+    }
+
+    /// Get ecosystem-wide recycling metrics
+    fn load_global_metrics(env: &Env) -> GlobalMetrics {
+        env.storage()
+            .instance()
+            .get(&("global_metrics",))
+            .unwrap_or_default()
+    }
+
+    /// Persist ecosystem-wide recycling metrics
+    fn save_global_metrics(env: &Env, metrics: &GlobalMetrics) {
+        env.storage().instance().set(&("global_metrics",), metrics);
+    }
+
+    /// Calculate total weight for active waste entries in v2 storage.
+    /// Iterates once across the waste ID range to keep reads linear and allocation-free.
+    fn get_total_active_waste_weight(env: &Env) -> u64 {
+        let mut total_weight: u64 = 0;
+        let total_wastes = Self::get_waste_count(env);
+
+        for waste_id in 1..=total_wastes {
+            if let Some(waste) = env
+                .storage()
+                .instance()
+                .get::<_, Waste>(&("waste_v2", waste_id as u128))
+            {
+                if waste.is_active {
+                    let weight = u64::try_from(waste.weight)
+                        .expect("Waste weight exceeds u64 range");
+                    total_weight = total_weight
+                        .checked_add(weight)
+                        .expect("Overflow in active waste total weight");
+                }
+            }
+        }
+
+        total_weight
+    }
+
+    /// Get incentive by ID
+    pub fn get_incentive_by_id(env: Env, incentive_id: u64) -> Option<Incentive> {
+        Self::get_incentive(&env, incentive_id)
+    }
+
+    /// Get multiple incentives by IDs (batch retrieval)
+    pub fn get_incentives_batch(env: Env, incentive_ids: Vec<u64>) -> Vec<Option<Incentive>> {
+        let mut results = Vec::new(&env);
+
+        for incentive_id in incentive_ids.iter() {
+            results.push_back(Self::get_incentive_internal(&env, incentive_id));
+        }
+
+        results
+    }
+
+    /// Update incentive active status
+    /// Only the rewarder can update their incentive
+    pub fn update_incentive_status(
+        env: Env,
+        incentive_id: u64,
+        is_active: bool,
+    ) -> Incentive {
+        let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+
+        // Require auth from the rewarder
+        incentive.rewarder.require_auth();
+
+        if is_active {
+            if incentive.remaining_budget == 0 {
+                panic!("Cannot reactivate exhausted incentive");
+            }
+            incentive.reactivate();
+        } else {
+            incentive.deactivate();
+        }
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        incentive
+    }
+
+    /// Update an existing incentive's reward points per kilogram
+    /// Only the rewarder can update their incentive
+    /// Only active incentives can be updated
+    pub fn update_incentive(
+        env: Env,
+        incentive_id: u64,
+        new_reward_points: u64,
+    ) -> Incentive {
+        // Step 1: Retrieve incentive (existence check)
+        let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+
+        // Step 2: Authorization check
+        incentive.rewarder.require_auth();
+
+        // Step 3: Active status check
+        if !incentive.active {
+            panic!("Incentive is not active");
+        }
+
+        // Step 4: Input validation
+        if new_reward_points == 0 {
+            panic!("Reward must be greater than zero");
+        }
+
+        // Step 5: Update fields
+        incentive.reward_points = new_reward_points;
+
+        // Step 6: Persist to storage
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        // Step 7: Emit event
+        events::emit_incentive_updated(&env, incentive_id, &incentive.rewarder, new_reward_points);
+
+        incentive
+    }
+
+
+    /// Calculate reward for a given waste amount based on an incentive
+    /// Returns the reward amount, or 0 if the incentive is inactive
+    /// The reward is capped at the incentive's remaining budget
+    pub fn calculate_incentive_reward(
+        env: Env,
+        incentive_id: u64,
+        waste_amount: u64,
+    ) -> u64 {
+        let incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+
+        // Check if incentive is active
+        if !incentive.active {
+            return 0;
+        }
+
+        Self::calculate_incentive_base_reward(&env, &incentive, waste_amount)
+            .expect("Reward calculation overflow")
+            .min(incentive.remaining_budget)
+    }
+
+    /// Get all incentives for a specific waste type
+    pub fn get_incentives_by_waste_type(
+        env: Env,
+        waste_type: WasteType,
+    ) -> soroban_sdk::Vec<Incentive> {
+        let mut results = soroban_sdk::Vec::new(&env);
+        let count = Self::get_incentive_count(&env);
+
+        for i in 1..=count {
+            if let Some(incentive) = Self::get_incentive(&env, i) {
+                if incentive.accepts(waste_type) {
+                    results.push_back(incentive);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Get all active incentives
+    pub fn get_active_incentives(env: Env) -> soroban_sdk::Vec<Incentive> {
+        let mut results = soroban_sdk::Vec::new(&env);
+        let count = Self::get_incentive_count(&env);
+
+        for i in 1..=count {
+            if let Some(incentive) = Self::get_incentive(&env, i) {
+                if incentive.active {
+                    results.push_back(incentive);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Get up to `limit` active incentives across all waste types, in creation order
+    /// `limit` is capped at MAX_PAGE_SIZE
+    pub fn get_all_active_incentives(env: Env, limit: u32) -> soroban_sdk::Vec<Incentive> {
+        let mut results = soroban_sdk::Vec::new(&env);
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let count = Self::get_incentive_count(&env);
+
+        for i in 1..=count {
+            if results.len() >= limit {
+                break;
+            }
+            if let Some(incentive) = Self::get_incentive(&env, i) {
+                if incentive.active {
+                    results.push_back(incentive);
+                }
+            }
+        }
+
+        results
+    }
+
+
+    /// Get a page of incentives in creation order (ascending ID)
+    /// Skips `offset` incentives and returns at most `limit` (capped at MAX_PAGE_SIZE);
+    /// purged incentives are not counted
+    pub fn get_incentives_chronological(env: Env, offset: u32, limit: u32) -> Vec<Incentive> {
+        let mut page = Vec::new(&env);
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut skipped: u32 = 0;
+
+        for incentive_id in 1..=Self::get_incentive_count(&env) {
+            if page.len() >= limit {
+                break;
+            }
+            let Some(incentive) = Self::get_incentive_internal(&env, incentive_id) else {
+                continue;
+            };
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            page.push_back(incentive);
+        }
+
+        page
+    }
+
+    /// Get participant information
+    pub fn get_participant(env: Env, address: Address) -> Option<Participant> {
+        let key = (address,);
+        env.storage().instance().get(&key)
+    }
+
+    /// Get the top participants ranked by total tokens earned (descending)
+    /// Keeps at most `limit` entries while scanning instead of sorting the whole registry
+    pub fn get_top_participants(env: Env, limit: u32) -> Vec<(Address, u128)> {
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut top: Vec<(Address, u128)> = Vec::new(&env);
+
+        if limit == 0 {
+            return top;
+        }
+
+        for address in Self::get_participant_registry(&env).iter() {
+            let Some(participant) = Self::get_participant(env.clone(), address.clone()) else {
+                continue;
+            };
+            let tokens = participant.total_tokens_earned;
+
+            if top.len() == limit && tokens <= top.get(limit - 1).unwrap().1 {
+                continue;
+            }
+
+            // Find the insertion point that keeps the list sorted descending
+            let mut position = top.len();
+            for (i, (_, existing)) in top.iter().enumerate() {
+                if tokens > existing {
+                    position = i as u32;
+                    break;
+                }
+            }
+
+            top.insert(position, (address, tokens));
+            if top.len() > limit {
+                top.pop_back();
+            }
+        }
+
+        top
+    }
+
+    /// Find the registered collectors closest to a point (coordinates scaled by 1e6)
+    /// Distances are squared so no sqrt is needed; longitude does not wrap at the antimeridian
+    pub fn find_nearest_collectors(
+        env: Env,
+        latitude: i128,
+        longitude: i128,
+        limit: u32,
+    ) -> Vec<(Address, i128)> {
+        Self::validate_coordinates(latitude, longitude);
+
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut nearest: Vec<(Address, i128)> = Vec::new(&env);
+
+        if limit == 0 {
+            return nearest;
+        }
+
+        for address in Self::get_participant_registry(&env).iter() {
+            let Some(participant) = Self::get_participant(env.clone(), address.clone()) else {
+                continue;
+            };
+            if !participant.is_registered || participant.role != ParticipantRole::Collector {
+                continue;
+            }
+
+            let d_lat = participant.latitude - latitude;
+            let d_lon = participant.longitude - longitude;
+            let distance = d_lat
+                .saturating_mul(d_lat)
+                .saturating_add(d_lon.saturating_mul(d_lon));
+
+            if nearest.len() == limit && distance >= nearest.get(limit - 1).unwrap().1 {
+                continue;
+            }
+
+            // Find the insertion point that keeps the list sorted ascending
+            let mut position = nearest.len();
+            for (i, (_, existing)) in nearest.iter().enumerate() {
+                if distance < existing {
+                    position = i as u32;
+                    break;
+                }
+            }
+
+            nearest.insert(position, (address, distance));
+            if nearest.len() > limit {
+                nearest.pop_back();
+            }
+        }
+
+        nearest
+    }
+
+    /// Get participant information with current statistics
+    /// Returns participant details along with their recycling statistics
+    /// Returns None if participant is not registered
+    pub fn get_participant_info(env: Env, address: Address) -> Option<ParticipantInfo> {
+        let participant = Self::get_participant(env.clone(), address.clone())?;
+        let stats = Self::get_stats(env, address.clone())
+            .unwrap_or_else(|| RecyclingStats::new(address));
+        
+        Some(ParticipantInfo {
+            participant,
+            stats,
+        })
+    }
+
+    /// Export a participant's record, stats, owned wastes and incentives in one call
+    /// Returns None if the participant is not registered
+    pub fn export_participant_profile(env: Env, address: Address) -> Option<ParticipantProfile> {
+        let participant = Self::get_participant(env.clone(), address.clone())?;
+        if !participant.is_registered {
+            return None;
+        }
+
+        let stats = Self::get_stats(env.clone(), address.clone())
+            .unwrap_or_else(|| RecyclingStats::new(address.clone()));
+
+        let mut waste_ids = Vec::new(&env);
+        for id in Self::get_material_index(&env, &address).iter() {
+            if waste_ids.len() >= MAX_PAGE_SIZE {
+                break;
+            }
+            waste_ids.push_back(id);
+        }
+
+        let incentive_ids = Self::get_incentives_by_rewarder(env, address);
+
+        Some(ParticipantProfile {
+            participant,
+            stats,
+            waste_ids,
+            incentive_ids,
+        })
+    }
+
+    /// Update participant role
+    /// Preserves registration timestamp and other data
+    pub fn update_role(env: Env, address: Address, new_role: ParticipantRole) -> Participant {
+        address.require_auth();
+
+        let mut participant: Participant =
+            Self::get_participant(env.clone(), address.clone()).expect("Participant not found");
+
+        // Validate participant is registered
+        if !participant.is_registered {
+            panic!("Participant is not registered");
+        }
+
+        participant.role = new_role;
+        Self::set_participant(&env, &address, &participant);
+
+        participant
+    }
+
+    /// Update a participant's role only if it still equals `expected_role`
+    /// Guards against a concurrent role change being silently overwritten
+    pub fn update_role_cas(
+        env: Env,
+        address: Address,
+        expected_role: ParticipantRole,
+        new_role: ParticipantRole,
+    ) -> Participant {
+        let participant =
+            Self::get_participant(env.clone(), address.clone()).expect("Participant not found");
+
+        if participant.role != expected_role {
+            panic!("Role precondition failed");
+        }
+
+        Self::update_role(env, address, new_role)
+    }
+
+    /// Deregister a participant
+    /// With no grace period configured, is_registered is cleared immediately. Otherwise
+    /// deregistration is scheduled for now + grace and the participant stays registered
+    /// until `finalize_deregistration` is called after that time
+    pub fn deregister_participant(env: Env, address: Address) -> Participant {
+        address.require_auth();
+
+        let key = (address.clone(),);
+        let mut participant: Participant = env
+            .storage()
+            .instance()
+            .get(&key)
+            .expect("Participant not found");
+
+        let grace = Self::get_deregistration_grace(env.clone());
+        if grace == 0 {
+            participant.is_registered = false;
+            env.storage().instance().set(&key, &participant);
+            return participant;
+        }
+
+        let pending_key = ("deregister_at", address.clone());
+        if env.storage().instance().has(&pending_key) {
+            panic!("Deregistration already pending");
+        }
+
+        let deregister_at = env.ledger().timestamp().saturating_add(grace);
+        env.storage().instance().set(&pending_key, &deregister_at);
+
+        participant
+    }
+
+    /// Complete a scheduled deregistration once its grace period has elapsed
+    /// Callable by anyone, since the participant already requested it
+    pub fn finalize_deregistration(env: Env, address: Address) -> Participant {
+        let deregister_at = Self::get_deregister_at(env.clone(), address.clone())
+            .expect("No pending deregistration");
+
+        if env.ledger().timestamp() < deregister_at {
+            panic!("Grace period not elapsed");
+        }
+
+        let key = (address.clone(),);
+        let mut participant: Participant = env
+            .storage()
+            .instance()
+            .get(&key)
+            .expect("Participant not found");
+
+        participant.is_registered = false;
+        env.storage().instance().set(&key, &participant);
+        env.storage().instance().remove(&("deregister_at", address));
+
+        participant
+    }
+
+    /// Cancel a scheduled deregistration that has not been finalized yet
+    pub fn cancel_deregistration(env: Env, address: Address) -> Participant {
+        address.require_auth();
+
+        let pending_key = ("deregister_at", address.clone());
+        if !env.storage().instance().has(&pending_key) {
+            panic!("No pending deregistration");
+        }
+        env.storage().instance().remove(&pending_key);
+
+        Self::get_participant(env, address).expect("Participant not found")
+    }
+
+    /// Get the time at which a pending deregistration can be finalized, if any
+    pub fn get_deregister_at(env: Env, address: Address) -> Option<u64> {
+        env.storage().instance().get(&("deregister_at", address))
+    }
+
+    /// Set the delay in seconds between requesting and finalizing deregistration (admin only)
+    pub fn set_deregistration_grace(env: Env, admin: Address, seconds: u64) {
+        Self::require_admin(&env, &admin);
+
+        let old_value = Self::get_deregistration_grace(env.clone());
+        env.storage().instance().set(&("dereg_grace",), &seconds);
+        Self::log_config_change(
+            &env,
+            &admin,
+            "dereg_grace",
+            ConfigValue::Number(old_value),
+            ConfigValue::Number(seconds),
+        );
+    }
+
+    /// Get the deregistration grace period in seconds (0 means immediate)
+    pub fn get_deregistration_grace(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("dereg_grace",))
+            .unwrap_or(0)
+    }
+
+    /// Move a participant's history to a new address and deregister the old one (admin only)
+    /// Stats, lifetime totals, escrowed rewards, owned wastes and created incentives are
+    /// carried over; when `new_addr` is already registered its totals are added to rather
+    /// than replaced
+    pub fn migrate_participant(
+        env: Env,
+        admin: Address,
+        old_addr: Address,
+        new_addr: Address,
+    ) -> Participant {
+        Self::require_admin(&env, &admin);
+
+        if old_addr == new_addr {
+            panic!("Cannot migrate to the same address");
+        }
+
+        let mut old_participant =
+            Self::get_participant(env.clone(), old_addr.clone()).expect("Participant not found");
+        if !old_participant.is_registered {
+            panic!("Participant is not registered");
+        }
+
+        // Merge the participant record and lifetime totals
+        let new_participant = match Self::get_participant(env.clone(), new_addr.clone()) {
+            Some(mut existing) => {
+                if !existing.is_registered {
+                    panic!("Target participant is not registered");
+                }
+                existing.total_waste_processed = existing
+                    .total_waste_processed
+                    .checked_add(old_participant.total_waste_processed)
+                    .expect("Overflow in total waste processed");
+                existing.total_tokens_earned = existing
+                    .total_tokens_earned
+                    .checked_add(old_participant.total_tokens_earned)
+                    .expect("Overflow in total tokens earned");
+                existing
+            }
+            None => Participant {
+                address: new_addr.clone(),
+                ..old_participant.clone()
+            },
+        };
+        Self::set_participant(&env, &new_addr, &new_participant);
+        Self::add_to_participant_registry(&env, &new_addr);
+
+        // Merge recycling stats
+        if let Some(old_stats) = Self::get_stats(env.clone(), old_addr.clone()) {
+            let stats = Self::get_stats(env.clone(), new_addr.clone())
+                .unwrap_or_else(|| RecyclingStats::new(new_addr.clone()))
+                .merged_with(&old_stats)
+                .expect("Overflow in recycling stats");
+            env.storage().instance().set(&("stats", new_addr.clone()), &stats);
+            env.storage().instance().remove(&("stats", old_addr.clone()));
+        }
+
+        // Merge escrowed incentive rewards
+        let old_escrow = Self::get_escrow_balance(env.clone(), old_addr.clone());
+        if old_escrow > 0 {
+            let escrow = Self::get_escrow_balance(env.clone(), new_addr.clone())
+                .checked_add(old_escrow)
+                .expect("Overflow in escrow balance");
+            env.storage()
+                .instance()
+                .set(&("escrow", new_addr.clone()), &escrow);
+            env.storage().instance().remove(&("escrow", old_addr.clone()));
+        }
+
+        // Hand over owned materials
+        for waste_id in Self::get_material_index(&env, &old_addr).iter() {
+            if let Some(mut material) = Self::get_waste_internal(&env, waste_id) {
+                material.submitter = new_addr.clone();
+                Self::set_waste(&env, waste_id, &material);
+            }
+            Self::add_to_material_index(&env, &new_addr, waste_id);
+        }
+        env.storage()
+            .instance()
+            .remove(&("material_index", old_addr.clone()));
+
+        // Hand over owned v2 wastes
+        let old_wastes: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", old_addr.clone()))
+            .unwrap_or(Vec::new(&env));
+        let mut new_wastes: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", new_addr.clone()))
+            .unwrap_or(Vec::new(&env));
+        for waste_id in old_wastes.iter() {
+            if let Some(mut waste) = env
+                .storage()
+                .instance()
+                .get::<_, types::Waste>(&("waste_v2", waste_id))
+            {
+                waste.transfer_to(new_addr.clone());
+                env.storage().instance().set(&("waste_v2", waste_id), &waste);
+            }
+            new_wastes.push_back(waste_id);
+        }
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", new_addr.clone()), &new_wastes);
+        env.storage()
+            .instance()
+            .remove(&("participant_wastes", old_addr.clone()));
+
+        // Hand over created incentives
+        let mut new_incentives = Self::get_incentives_by_rewarder(env.clone(), new_addr.clone());
+        for incentive_id in Self::get_incentives_by_rewarder(env.clone(), old_addr.clone()).iter() {
+            if let Some(mut incentive) = Self::get_incentive_internal(&env, incentive_id) {
+                incentive.rewarder = new_addr.clone();
+                Self::set_incentive(&env, incentive_id, &incentive);
+            }
+            new_incentives.push_back(incentive_id);
+        }
+        env.storage()
+            .instance()
+            .set(&("rewarder_incentives", new_addr.clone()), &new_incentives);
+        env.storage()
+            .instance()
+            .remove(&("rewarder_incentives", old_addr.clone()));
+
+        // Retire the old address with its history moved away
+        old_participant.is_registered = false;
+        old_participant.total_waste_processed = 0;
+        old_participant.total_tokens_earned = 0;
+        Self::set_participant(&env, &old_addr, &old_participant);
+        env.storage().instance().remove(&("deregister_at", old_addr));
+
+        new_participant
+    }
+
+    /// Update participant location
+    pub fn update_location(
+        env: Env,
+        address: Address,
+        latitude: i128,
+        longitude: i128,
+    ) -> Participant {
+        address.require_auth();
+
+        let key = (address.clone(),);
+        let mut participant: Participant = env
+            .storage()
+            .instance()
+            .get(&key)
+            .expect("Participant not found");
+
+        // Validate participant is registered
+        if !participant.is_registered {
+            panic!("Participant is not registered");
+        }
+
+        Self::validate_coordinates(latitude, longitude);
+
+        participant.latitude = latitude;
+        participant.longitude = longitude;
+        env.storage().instance().set(&key, &participant);
+
+        participant
+    }
+
+    // ========== Generic Transfer Record Functions ==========
+
+    /// Record a transfer of any item type between two addresses
+    /// The record starts as Pending and must pass `TransferRecord::validate`;
+    /// returns the new record ID
+    pub fn record_generic_transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        item_type: TransferItemType,
+        item_id: u64,
+        amount: u64,
+        note: String,
+    ) -> u64 {
+        from.require_auth();
+        Self::require_not_paused(&env);
+
+        let record_id = env
+            .storage()
+            .instance()
+            .get::<_, u64>(&("transfer_record_count",))
+            .unwrap_or(0)
+            + 1;
+
+        let record = TransferRecord::new(
+            record_id,
+            from,
+            to,
+            item_type,
+            item_id,
+            amount,
+            env.ledger().timestamp(),
+            note,
+        );
+        if let Err(message) = record.validate() {
+            panic!("{}", message);
+        }
+
+        env.storage()
+            .instance()
+            .set(&("transfer_record", record_id), &record);
+        env.storage()
+            .instance()
+            .set(&("transfer_record_count",), &record_id);
+
+        record_id
+    }
+
+    /// Move a transfer record to a new status (sender only)
+    /// Records in a final status can no longer change
+    pub fn update_transfer_status(
+        env: Env,
+        record_id: u64,
+        new_status: TransferStatus,
+    ) -> TransferRecord {
+        let mut record = Self::get_transfer_record(env.clone(), record_id)
+            .expect("Transfer record not found");
+
+        record.from.require_auth();
+
+        if !record.update_status(new_status) {
+            panic!("Transfer status is final");
+        }
+
+        env.storage()
+            .instance()
+            .set(&("transfer_record", record_id), &record);
+
+        record
+    }
+
+    /// Get a generic transfer record by ID
+    pub fn get_transfer_record(env: Env, record_id: u64) -> Option<TransferRecord> {
+        env.storage().instance().get(&("transfer_record", record_id))
+    }
+
+    // ========== Waste Transfer History Functions ==========
+
+    /// Get transfer history for a specific waste
+    /// Returns chronologically ordered list of transfers
+    pub fn get_transfer_history(env: Env, waste_id: u64) -> Vec<WasteTransfer> {
+        let key = ("transfers", waste_id);
+        env.storage().instance().get(&key).unwrap_or(Vec::new(&env))
+    }
+
+    /// Get a page of the transfer history for a specific waste
+    /// Returns at most `limit` transfers (capped at MAX_PAGE_SIZE) starting at `offset`,
+    /// in chronological order
+    pub fn get_transfer_history_paged(
+        env: Env,
+        waste_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<WasteTransfer> {
+        let history = Self::get_transfer_history(env.clone(), waste_id);
+        let limit = limit.min(MAX_PAGE_SIZE);
+
+        if offset >= history.len() {
+            return Vec::new(&env);
+        }
+
+        let end = offset.saturating_add(limit).min(history.len());
+        history.slice(offset..end)
+    }
+
+    /// Get the number of transfers recorded for a specific waste
+    pub fn get_transfer_count(env: Env, waste_id: u64) -> u32 {
+        Self::get_transfer_history(env, waste_id).len()
+    }
+
+    /// Get complete transfer history for a waste (alias for get_transfer_history)
+    /// Returns chronologically ordered list of all transfers
+    /// Includes all transfer details: from, to, timestamp, and notes
+    pub fn get_waste_transfer_history(env: Env, waste_id: u64) -> Vec<WasteTransfer> {
+        Self::get_transfer_history(env, waste_id)
+    }
+
+    /// Get a v2 waste together with its location-tracked transfer history
+    /// History is returned in the order transfers were recorded
+    /// Returns None if the waste doesn't exist
+    pub fn get_waste_full(env: Env, waste_id: u128) -> Option<(types::Waste, Vec<WasteTransfer>)> {
+        let waste: types::Waste = env.storage().instance().get(&("waste_v2", waste_id))?;
+        let history: Vec<WasteTransfer> = env
+            .storage()
+            .instance()
+            .get(&("transfer_history", waste_id))
+            .unwrap_or(Vec::new(&env));
+
+        Some((waste, history))
+    }
+
+    /// Get only the (latitude, longitude) of a v2 waste
+    /// Returns None if the waste doesn't exist
+    pub fn get_waste_coordinates(env: Env, waste_id: u128) -> Option<(i128, i128)> {
+        let waste: types::Waste = env.storage().instance().get(&("waste_v2", waste_id))?;
+        Some((waste.latitude, waste.longitude))
+    }
+
+    /// Get the coordinates of multiple v2 wastes (batch retrieval)
+    pub fn get_coordinates_batch(env: Env, waste_ids: Vec<u128>) -> Vec<Option<(i128, i128)>> {
+        let mut results = Vec::new(&env);
+
+        for waste_id in waste_ids.iter() {
+            results.push_back(Self::get_waste_coordinates(env.clone(), waste_id));
+        }
+
+        results
+    }
+
+    /// Get the ordered chain of owners of a v2 waste, from the original owner
+    /// to the current owner
+    /// Returns an empty vec if the waste doesn't exist
+    pub fn get_ownership_chain(env: Env, waste_id: u128) -> Vec<Address> {
+        let mut chain = Vec::new(&env);
+        let waste: types::Waste = match env.storage().instance().get(&("waste_v2", waste_id)) {
+            Some(waste) => waste,
+            None => return chain,
+        };
+        let history: Vec<WasteTransfer> = env
+            .storage()
+            .instance()
+            .get(&("transfer_history", waste_id))
+            .unwrap_or(Vec::new(&env));
+
+        match history.first() {
+            Some(first) => {
+                chain.push_back(first.from);
+                for transfer in history.iter() {
+                    chain.push_back(transfer.to);
+                }
+            }
+            None => chain.push_back(waste.current_owner),
+        }
+
+        chain
+    }
+
+    /// Get how often a v2 waste changes hands
+    /// Returns (transfer_count, average_seconds_between_transfers), or (0, 0) when
+    /// fewer than two transfers have been recorded
+    pub fn get_transfer_velocity(env: Env, waste_id: u128) -> (u32, u64) {
+        let history: Vec<WasteTransfer> = env
+            .storage()
+            .instance()
+            .get(&("transfer_history", waste_id))
+            .unwrap_or(Vec::new(&env));
+
+        let count = history.len();
+        if count < 2 {
+            return (0, 0);
+        }
+
+        let first = history.get_unchecked(0);
+        let last = history.get_unchecked(count - 1);
+        let elapsed = last.transferred_at.saturating_sub(first.transferred_at);
+        (count, elapsed / (count as u64 - 1))
+    }
+
+    /// Record a waste transfer
+    /// Appends to immutable history
+    fn record_transfer(env: &Env, waste_id: u64, from: Address, to: Address, note: String) {
+        let key = ("transfers", waste_id);
+        let mut history: Vec<WasteTransfer> =
+            env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+
+        let transfer = WasteTransfer::new(
+            waste_id as u128,
+            from.clone(),
+            to.clone(),
+            env.ledger().timestamp(),
+            0,
+            0,
+            note,
+        );
+
+        history.push_back(transfer);
+        env.storage().instance().set(&key, &history);
+
+        Self::index_transfer(env, waste_id, &from, &to);
+    }
+
+    /// Transfer waste ownership from one participants to another
+    pub fn transfer_waste(
+        env: Env,
+        waste_id: u64,
+        from: Address,
+        to: Address,
+        note: String,
+    ) -> Result<Material, ScavengerError> {
+        from.require_auth();
+        Self::require_not_paused(&env);
+
+        // Verify both participants are registered
+        Self::require_registered(&env, &from)?;
+        Self::require_registered(&env, &to)?;
+
+        // Get and update material
+        let mut material: Material =
+            Self::get_waste_internal(&env, waste_id).ok_or(ScavengerError::WasteNotFound)?;
+
+        // Verify sender owns the waste
+        if material.submitter != from {
+            return Err(ScavengerError::Unauthorized);
+        }
+
+        if material.is_retired {
+            return Err(ScavengerError::MaterialRetired);
+        }
+
+        // Update ownership
+        material.submitter = to.clone();
+        Self::set_waste(&env, waste_id, &material);
+
+        // Move the material between owner indexes
+        Self::remove_from_material_index(&env, &from, waste_id);
+        Self::add_to_material_index(&env, &to, waste_id);
+
+        // Record transfer in history
+        Self::record_transfer(&env, waste_id, from, to, note);
+
+        Ok(material)
+    }
+
+    /// Transfer multiple wastes from one participant to another in a single call
+    /// Every waste is validated before any ownership changes, so a single bad or
+    /// repeated ID aborts the batch
+    pub fn transfer_wastes_batch(
+        env: Env,
+        waste_ids: Vec<u64>,
+        from: Address,
+        to: Address,
+        note: String,
+    ) -> Vec<Material> {
+        from.require_auth();
+        Self::require_not_paused(&env);
+
+        // Verify both participants are registered
+        Self::require_registered(&env, &from)
+            .unwrap_or_else(|error| panic_with_error!(&env, error));
+        Self::require_registered(&env, &to)
+            .unwrap_or_else(|error| panic_with_error!(&env, error));
+
+        // Validate every waste before mutating state
+        let mut materials = Vec::new(&env);
+        for waste_id in waste_ids.iter() {
+            if materials.iter().any(|material: Material| material.id == waste_id) {
+                panic!("Duplicate waste ID in batch");
+            }
+
+            let material: Material =
+                Self::get_waste_internal(&env, waste_id).expect("Waste not found");
+
+            if material.submitter != from {
+                panic!("Only waste owner can transfer");
+            }
+
+            if material.is_retired {
+                panic!("Cannot transfer retired material");
+            }
+
+            materials.push_back(material);
+        }
+
+        let mut results = Vec::new(&env);
+        for mut material in materials.iter() {
+            let waste_id = material.id;
+
+            // Update ownership
+            material.submitter = to.clone();
+            Self::set_waste(&env, waste_id, &material);
+
+            // Move the material between owner indexes
+            Self::remove_from_material_index(&env, &from, waste_id);
+            Self::add_to_material_index(&env, &to, waste_id);
+
+            // Record transfer in history
+            Self::record_transfer(&env, waste_id, from.clone(), to.clone(), note.clone());
+
+            results.push_back(material);
+        }
+
+        results
+    }
+
+    /// Get all transfers for a participant (as sender)
+    /// Returns each waste the participant has sent along with its full transfer history
+    pub fn get_transfers_from(env: Env, address: Address) -> Vec<(u64, Vec<WasteTransfer>)> {
+        let waste_ids = Self::get_transfer_index(&env, "xfer_from", &address);
+        Self::collect_transfer_histories(&env, waste_ids)
+    }
+
+    /// Get all transfers for a participant (as receiver)
+    /// Returns each waste the participant has received along with its full transfer history
+    pub fn get_transfers_to(env: Env, address: Address) -> Vec<(u64, Vec<WasteTransfer>)> {
+        let waste_ids = Self::get_transfer_index(&env, "xfer_to", &address);
+        Self::collect_transfer_histories(&env, waste_ids)
+    }
+
+    /// Get the waste IDs recorded in a sender or receiver transfer index
+    fn get_transfer_index(env: &Env, index: &str, address: &Address) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&(index, address.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Record a waste ID in a sender or receiver transfer index (once per waste)
+    fn add_to_transfer_index(env: &Env, index: &str, address: &Address, waste_id: u64) {
+        let mut waste_ids = Self::get_transfer_index(env, index, address);
+        if !waste_ids.contains(waste_id) {
+            waste_ids.push_back(waste_id);
+            env.storage()
+                .instance()
+                .set(&(index, address.clone()), &waste_ids);
+        }
+    }
+
+    /// Index a transfer under both its sender and receiver
+    fn index_transfer(env: &Env, waste_id: u64, from: &Address, to: &Address) {
+        Self::add_to_transfer_index(env, "xfer_from", from, waste_id);
+        Self::add_to_transfer_index(env, "xfer_to", to, waste_id);
+    }
+
+    /// Load the transfer history for each waste ID
+    /// Material and v2 waste IDs share one counter, so each ID has at most one history
+    fn collect_transfer_histories(
+        env: &Env,
+        waste_ids: Vec<u64>,
+    ) -> Vec<(u64, Vec<WasteTransfer>)> {
+        let mut results = Vec::new(env);
+
+        for waste_id in waste_ids.iter() {
+            let history: Vec<WasteTransfer> = env
+                .storage()
+                .instance()
+                .get(&("transfers", waste_id))
+                .or_else(|| {
+                    env.storage()
+                        .instance()
+                        .get(&("transfer_history", waste_id as u128))
+                })
+                .unwrap_or(Vec::new(env));
+            results.push_back((waste_id, history));
+        }
+
+        results
+    }
+
+    /// Validate if a participant can perform a specific action
+    pub fn can_collect(env: Env, address: Address) -> bool {
+        let key = (address,);
+        if let Some(participant) = env.storage().instance().get::<_, Participant>(&key) {
+            participant.is_registered && participant.role.can_collect_materials()
+        } else {
+            false
+        }
+    }
+
+    /// Validate if a participants can manufacture
+    pub fn can_manufacture(env: Env, address: Address) -> bool {
+        let key = (address,);
+        if let Some(participant) = env.storage().instance().get::<_, Participant>(&key) {
+            participant.is_registered && participant.role.can_manufacture()
+        } else {
+            false
+        }
+    }
+
+    /// Check whether an address may perform an action without sending a transaction
+    /// `action` is one of `submit`, `verify`, `collect`, `manufacture` or
+    /// `create_incentive`; unknown actions and unregistered addresses return false
+    pub fn can_perform(env: Env, address: Address, action: Symbol) -> bool {
+        let Some(participant) = Self::get_participant(env.clone(), address.clone()) else {
+            return false;
+        };
+        if !participant.is_registered {
+            return false;
+        }
+
+        let role = participant.role;
+        if action == symbol_short!("submit") {
+            true
+        } else if action == symbol_short!("verify") {
+            role.can_process_recyclables()
+        } else if action == symbol_short!("collect") {
+            role.can_collect_materials()
+        } else if action == Symbol::new(&env, "manufacture") {
+            role.can_manufacture()
+        } else if action == Symbol::new(&env, "create_incentive") {
+            role.can_manufacture() || Self::is_incentive_sponsor(env, address)
+        } else {
+            false
+        }
+    }
+
+    /// Submit a new material for recycling
+    pub fn submit_material(
+        env: Env,
+        waste_type: WasteType,
+        weight: u64,
+        submitter: Address,
+        description: String,
+    ) -> Result<Material, ScavengerError> {
+        submitter.require_auth();
+        Self::require_not_paused(&env);
+
+        let auto_verify = Self::qualifies_for_auto_verify(&env, &submitter, weight);
+        let material =
+            Self::submit_material_internal(&env, waste_type, weight, submitter, description)?;
+
+        if auto_verify {
+            let verifier = env.current_contract_address();
+            return Self::complete_verification(&env, material, &verifier);
+        }
+
+        Ok(material)
+    }
+
+    /// Submission logic shared by the authorized entrypoints
+    fn submit_material_internal(
+        env: &Env,
+        waste_type: WasteType,
+        weight: u64,
+        submitter: Address,
+        description: String,
+    ) -> Result<Material, ScavengerError> {
+        // Validate submitter is registered
+        Self::require_registered(env, &submitter)?;
+
+        // Reject dust submissions
+        Self::require_min_weight(env, weight);
+        Self::apply_submission_cap(env, &submitter, weight);
+
+        // Get next waste ID using the new storage system
+        let waste_id = Self::next_waste_id(env);
+
+        // Create material
+        let material = Material::new(
+            waste_id,
+            waste_type,
+            weight,
+            submitter.clone(),
+            env.ledger().timestamp(),
+            description,
+        );
+
+        // Store waste using the new storage systems
+        Self::set_waste(env, waste_id, &material);
+        Self::add_to_material_index(env, &submitter, waste_id);
+        Self::add_to_type_index(env, waste_type, waste_id);
+
+        // Update stats
+        let mut stats: RecyclingStats = env
+            .storage()
+            .instance()
+            .get(&("stats", submitter.clone()))
+            .unwrap_or_else(|| RecyclingStats::new(submitter.clone()));
+
+        stats.record_submission(&material);
+        env.storage().instance().set(&("stats", submitter.clone()), &stats);
+
+        // Update global metrics
+        let mut metrics = Self::load_global_metrics(env);
+        metrics.record_submission(&material);
+        Self::save_global_metrics(env, &metrics);
+
+        // Update participant stats
+        Self::update_participant_stats(env, &submitter, weight, 0);
+
+        // Update global total weight
+        Self::add_to_total_weight(env, weight);
+
+        events::emit_submitted(env, waste_id, waste_type, weight, &submitter);
+
+        Ok(material)
+    }
+
+    /// Submit a material with its weight given in an explicit unit
+    /// The amount is converted to grams before storing
+    pub fn submit_material_with_unit(
+        env: Env,
+        waste_type: WasteType,
+        amount: u64,
+        unit: WeightUnit,
+        submitter: Address,
+        description: String,
+    ) -> Result<Material, ScavengerError> {
+        let weight = unit
+            .to_grams(amount)
+            .expect("Weight overflow converting to grams");
+
+        Self::submit_material(env, waste_type, weight, submitter, description)
+    }
+
+    /// Submit a material and verify it in the same transaction (recyclers only)
+    /// Records both the submission and the verification and awards the tokens
+    pub fn submit_and_verify(
+        env: Env,
+        waste_type: WasteType,
+        weight: u64,
+        recycler: Address,
+        description: String,
+    ) -> Material {
+        recycler.require_auth();
+        Self::require_not_paused(&env);
+
+        let participant =
+            Self::get_participant(env.clone(), recycler.clone()).expect("Recycler not registered");
+
+        if !participant.is_registered || !participant.role.can_process_recyclables() {
+            panic!("Only recyclers can self-verify");
+        }
+
+        let material =
+            Self::submit_material_internal(&env, waste_type, weight, recycler.clone(), description)
+                .unwrap_or_else(|error| panic_with_error!(&env, error));
+
+        Self::verify_material_internal(&env, material.id, recycler)
+            .unwrap_or_else(|error| panic_with_error!(&env, error))
+    }
+
+    /// Register new waste with location data
+    pub fn recycle_waste(
+        env: Env,
+        waste_type: WasteType,
+        weight: u128,
+        recycler: Address,
+        latitude: i128,
+        longitude: i128,
+    ) -> u128 {
+        recycler.require_auth();
+        Self::require_not_paused(&env);
+
+        Self::require_registered(&env, &recycler)
+            .unwrap_or_else(|error| panic_with_error!(&env, error));
+
+        Self::validate_coordinates(latitude, longitude);
+
+        let waste_id = Self::next_waste_id(&env) as u128;
+        let timestamp = env.ledger().timestamp();
+
+        let waste = types::Waste::new(
+            waste_id,
+            waste_type,
+            weight,
+            recycler.clone(),
+            latitude,
+            longitude,
+            timestamp,
+            true,
+            false,
+            recycler.clone(),
+        );
+
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+
+        let mut waste_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", recycler.clone()))
+            .unwrap_or(Vec::new(&env));
+        waste_list.push_back(waste_id);
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", recycler.clone()), &waste_list);
+
+        // Emit waste registered event
+        events::emit_waste_registered(
+            &env,
+            waste_id,
+            &recycler,
+            waste_type,
+            weight,
+            latitude,
+            longitude,
+        );
+
+        waste_id
+    }
+
+    /// Register a v2 waste record for an existing material, returning the new waste ID
+    /// The waste keeps the material's type, weight and owner and is linked back to it
+    pub fn promote_material_to_waste(
+        env: Env,
+        material_id: u64,
+        latitude: i128,
+        longitude: i128,
+    ) -> u128 {
+        let material = Self::get_waste_internal(&env, material_id).expect("Material not found");
+        material.submitter.require_auth();
+        Self::require_not_paused(&env);
+
+        if env.storage().instance().has(&("promoted", material_id)) {
+            panic!("Material already promoted");
+        }
+        if material.is_retired {
+            panic!("Material is retired");
+        }
+
+        Self::validate_coordinates(latitude, longitude);
+
+        let owner = material.submitter.clone();
+        let weight = material.weight as u128;
+        let waste_id = Self::next_waste_id(&env) as u128;
+
+        let waste = types::Waste::new(
+            waste_id,
+            material.waste_type,
+            weight,
+            owner.clone(),
+            latitude,
+            longitude,
+            material.submitted_at,
+            true,
+            false,
+            owner.clone(),
+        );
+
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        env.storage()
+            .instance()
+            .set(&("promoted", material_id), &waste_id);
+
+        let mut waste_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", owner.clone()))
+            .unwrap_or(Vec::new(&env));
+        waste_list.push_back(waste_id);
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", owner.clone()), &waste_list);
+
+        events::emit_waste_registered(
+            &env,
+            waste_id,
+            &owner,
+            material.waste_type,
+            weight,
+            latitude,
+            longitude,
+        );
+
+        waste_id
+    }
+
+    /// Get the v2 waste ID a material was promoted to, if any
+    pub fn get_promoted_waste(env: Env, material_id: u64) -> Option<u128> {
+        env.storage().instance().get(&("promoted", material_id))
+    }
+
+    /// Register waste using the fluent WasteBuilder
+    /// Allows a custom recycled timestamp and an optional pre-confirmation
+    pub fn recycle_waste_builder(
+        env: Env,
+        waste_type: WasteType,
+        weight: u128,
+        recycler: Address,
+        latitude: i128,
+        longitude: i128,
+        recycled_at: u64,
+        confirmer: Option<Address>,
+    ) -> types::Waste {
+        recycler.require_auth();
+        Self::require_not_paused(&env);
+
+        Self::require_registered(&env, &recycler)
+            .unwrap_or_else(|error| panic_with_error!(&env, error));
+
+        Self::validate_coordinates(latitude, longitude);
+
+        let waste_id = Self::next_waste_id(&env) as u128;
+
+        let mut builder = types::WasteBuilder::new(waste_id, waste_type, weight, recycler.clone())
+            .location(latitude, longitude)
+            .recycled_at(recycled_at);
+        if let Some(confirmer) = confirmer {
+            builder = builder.confirmed(confirmer);
+        }
+        let waste = builder.build();
+
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+
+        let mut waste_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", recycler.clone()))
+            .unwrap_or(Vec::new(&env));
+        waste_list.push_back(waste_id);
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", recycler.clone()), &waste_list);
+
+        events::emit_waste_registered(
+            &env,
+            waste_id,
+            &recycler,
+            waste_type,
+            weight,
+            latitude,
+            longitude,
+        );
+
+        waste
+    }
+
+    /// Transfer waste between participants with location tracking
+    pub fn transfer_waste_v2(
+        env: Env,
+        waste_id: u128,
         from: Address,
         to: Address,
-        latitude: i128,
-        longitude: i128,
+        latitude: i128,
+        longitude: i128,
+    ) -> WasteTransfer {
+        from.require_auth();
+        Self::require_not_paused(&env);
+
+        Self::validate_coordinates(latitude, longitude);
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        if waste.current_owner != from {
+            panic!("Caller does not own waste");
+        }
+
+        if !waste.is_active {
+            panic!("Cannot transfer deactivated waste");
+        }
+
+        if !Self::is_valid_transfer(env.clone(), from.clone(), to.clone()) {
+            panic!("Invalid transfer");
+        }
+
+        waste.transfer_to(to.clone());
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+
+        let from_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", from.clone()))
+            .unwrap_or(Vec::new(&env));
+        let mut new_from_list = Vec::new(&env);
+        for id in from_list.iter() {
+            if id != waste_id {
+                new_from_list.push_back(id);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", from.clone()), &new_from_list);
+
+        let mut to_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", to.clone()))
+            .unwrap_or(Vec::new(&env));
+        to_list.push_back(waste_id);
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", to.clone()), &to_list);
+
+        let timestamp = env.ledger().timestamp();
+        let transfer = WasteTransfer::new(
+            waste_id,
+            from.clone(),
+            to.clone(),
+            timestamp,
+            latitude,
+            longitude,
+            String::from_str(&env, "transfer"),
+        );
+
+        let mut history: Vec<WasteTransfer> = env
+            .storage()
+            .instance()
+            .get(&("transfer_history", waste_id))
+            .unwrap_or(Vec::new(&env));
+        history.push_back(transfer.clone());
+        env.storage()
+            .instance()
+            .set(&("transfer_history", waste_id), &history);
+
+        Self::index_transfer(&env, waste_id as u64, &from, &to);
+
+        events::emit_transferred(&env, waste_id, &from, &to, timestamp);
+
+        transfer
+    }
+
+    /// Donate a confirmed waste to the configured charity address
+    /// Ownership moves to the charity, the transfer is recorded with a `donated` note
+    /// and the owner is awarded the donation bonus scaled by weight. The bonus is paid
+    /// at most once per waste, so re-donating a waste only moves ownership
+    pub fn donate_waste(env: Env, waste_id: u128, owner: Address) -> WasteTransfer {
+        owner.require_auth();
+        Self::require_not_paused(&env);
+
+        let charity = Self::get_charity_contract(env.clone()).expect("Charity address not set");
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        if waste.current_owner != owner {
+            panic!("Caller does not own waste");
+        }
+
+        if !waste.is_active {
+            panic!("Cannot transfer deactivated waste");
+        }
+
+        if !waste.is_confirmed {
+            panic!("Waste not confirmed");
+        }
+
+        if owner == charity {
+            panic!("Waste already owned by recipient");
+        }
+
+        waste.transfer_to(charity.clone());
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+
+        let owner_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", owner.clone()))
+            .unwrap_or(Vec::new(&env));
+        let mut new_owner_list = Vec::new(&env);
+        for id in owner_list.iter() {
+            if id != waste_id {
+                new_owner_list.push_back(id);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", owner.clone()), &new_owner_list);
+
+        let mut charity_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", charity.clone()))
+            .unwrap_or(Vec::new(&env));
+        charity_list.push_back(waste_id);
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", charity.clone()), &charity_list);
+
+        let timestamp = env.ledger().timestamp();
+        let transfer = WasteTransfer::new(
+            waste_id,
+            owner.clone(),
+            charity.clone(),
+            timestamp,
+            waste.latitude,
+            waste.longitude,
+            String::from_str(&env, "donated"),
+        );
+
+        let mut history: Vec<WasteTransfer> = env
+            .storage()
+            .instance()
+            .get(&("transfer_history", waste_id))
+            .unwrap_or(Vec::new(&env));
+        history.push_back(transfer.clone());
+        env.storage()
+            .instance()
+            .set(&("transfer_history", waste_id), &history);
+
+        Self::index_transfer(&env, waste_id as u64, &owner, &charity);
+
+        // Award the donation bonus once per waste, remembering the total so recompute_stats keeps it
+        let bonus = if env.storage().instance().has(&("donated", waste_id)) {
+            0
+        } else {
+            env.storage().instance().set(&("donated", waste_id), &true);
+            u64::try_from(
+                (waste.weight / 1000).saturating_mul(Self::get_donation_bonus(env.clone()) as u128),
+            )
+            .unwrap_or(u64::MAX)
+        };
+        if bonus > 0 {
+
+            let mut stats: RecyclingStats = env
+                .storage()
+                .instance()
+                .get(&("stats", owner.clone()))
+                .unwrap_or_else(|| RecyclingStats::new(owner.clone()));
+            stats.total_points = stats.total_points.saturating_add(bonus);
+            env.storage().instance().set(&("stats", owner.clone()), &stats);
+
+            let earned: u64 = env
+                .storage()
+                .instance()
+                .get(&("donation_bonus_earned", owner.clone()))
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&("donation_bonus_earned", owner.clone()), &earned.saturating_add(bonus));
+        }
+
+        events::emit_transferred(&env, waste_id, &owner, &charity, timestamp);
+
+        transfer
+    }
+
+    /// Transfer aggregated waste from collector to manufacturer
+    /// The source wastes are summed into one new waste and deactivated; `amount`
+    /// must equal their combined weight
+    pub fn transfer_collected_waste(
+        env: Env,
+        waste_type: WasteType,
+        source_ids: Vec<u128>,
+        amount: u128,
+        collector: Address,
+        manufacturer: Address,
+        latitude: i128,
+        longitude: i128,
+        notes: String,
+    ) -> u128 {
+        collector.require_auth();
+        Self::require_not_paused(&env);
+
+        Self::require_registered(&env, &collector)
+            .unwrap_or_else(|error| panic_with_error!(&env, error));
+        Self::require_registered(&env, &manufacturer)
+            .unwrap_or_else(|error| panic_with_error!(&env, error));
+
+        let collector_key = (collector.clone(),);
+        let collector_participant: Participant = env
+            .storage()
+            .instance()
+            .get(&collector_key)
+            .expect("Collector not registered");
+
+        if collector_participant.role != ParticipantRole::Collector {
+            panic!("Only collectors can use this");
+        }
+
+        let manufacturer_key = (manufacturer.clone(),);
+        let manufacturer_participant: Participant = env
+            .storage()
+            .instance()
+            .get(&manufacturer_key)
+            .expect("Manufacturer not registered");
+
+        if manufacturer_participant.role != ParticipantRole::Manufacturer {
+            panic!("Recipient must be manufacturer");
+        }
+
+        if source_ids.is_empty() {
+            panic!("No source wastes provided");
+        }
+
+        // Validate every source before deactivating any of them
+        let mut sources: Vec<types::Waste> = Vec::new(&env);
+        let mut total_weight: u128 = 0;
+        for (i, source_id) in source_ids.iter().enumerate() {
+            if source_ids.first_index_of(source_id) != Some(i as u32) {
+                panic!("Duplicate source waste");
+            }
+
+            let source: types::Waste = env
+                .storage()
+                .instance()
+                .get(&("waste_v2", source_id))
+                .expect("Source waste not found");
+
+            if source.current_owner != collector {
+                panic!("Collector does not own source waste");
+            }
+            if !source.is_active {
+                panic!("Source waste is deactivated");
+            }
+            if source.waste_type != waste_type {
+                panic!("Source waste type mismatch");
+            }
+
+            total_weight = total_weight
+                .checked_add(source.weight)
+                .expect("Overflow in aggregate weight");
+            sources.push_back(source);
+        }
+
+        if total_weight != amount {
+            panic!("Amount does not match source weight");
+        }
+
+        for mut source in sources.iter() {
+            source.deactivate();
+            env.storage()
+                .instance()
+                .set(&("waste_v2", source.waste_id), &source);
+        }
+
+        let waste_id = Self::next_waste_id(&env) as u128;
+        let timestamp = env.ledger().timestamp();
+
+        let waste = types::Waste::new(
+            waste_id,
+            waste_type,
+            total_weight,
+            manufacturer.clone(),
+            latitude,
+            longitude,
+            timestamp,
+            true,
+            false,
+            manufacturer.clone(),
+        );
+
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+
+        let mut manufacturer_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", manufacturer.clone()))
+            .unwrap_or(Vec::new(&env));
+        manufacturer_list.push_back(waste_id);
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", manufacturer.clone()), &manufacturer_list);
+
+        let transfer = WasteTransfer::new(
+            waste_id,
+            collector.clone(),
+            manufacturer.clone(),
+            timestamp,
+            latitude,
+            longitude,
+            notes,
+        );
+
+        let mut history: Vec<WasteTransfer> = env
+            .storage()
+            .instance()
+            .get(&("transfer_history", waste_id))
+            .unwrap_or(Vec::new(&env));
+        history.push_back(transfer);
+        env.storage()
+            .instance()
+            .set(&("transfer_history", waste_id), &history);
+
+        Self::index_transfer(&env, waste_id as u64, &collector, &manufacturer);
+
+        events::emit_bulk_transferred(
+            &env,
+            waste_id,
+            &collector,
+            &manufacturer,
+            waste_type,
+            timestamp,
+        );
+
+        waste_id
+    }
+
+    // ========== Dispute Functions ==========
+
+    /// Dispute ownership of a v2 waste held by another participant
+    /// Only one dispute per waste can be open at a time
+    pub fn open_dispute(env: Env, waste_id: u128, claimant: Address, reason: String) -> Dispute {
+        claimant.require_auth();
+        Self::require_registered(&env, &claimant)
+            .unwrap_or_else(|error| panic_with_error!(&env, error));
+
+        let waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        if waste.current_owner == claimant {
+            panic!("Owner cannot dispute own waste");
+        }
+
+        if Self::get_dispute(env.clone(), waste_id)
+            .is_some_and(|dispute| dispute.status == DisputeStatus::Open)
+        {
+            panic!("Dispute already open");
+        }
+
+        let dispute = Dispute {
+            waste_id,
+            claimant,
+            reason,
+            status: DisputeStatus::Open,
+            opened_at: env.ledger().timestamp(),
+        };
+        env.storage().instance().set(&("dispute", waste_id), &dispute);
+
+        dispute
+    }
+
+    /// Rule on an open dispute, upholding or dismissing it (admin only)
+    pub fn resolve_dispute(env: Env, admin: Address, waste_id: u128, upheld: bool) -> Dispute {
+        Self::require_admin(&env, &admin);
+
+        let mut dispute = Self::get_dispute(env.clone(), waste_id).expect("Dispute not found");
+        if dispute.status != DisputeStatus::Open {
+            panic!("Dispute already resolved");
+        }
+
+        dispute.status = if upheld {
+            DisputeStatus::Upheld
+        } else {
+            DisputeStatus::Dismissed
+        };
+        env.storage().instance().set(&("dispute", waste_id), &dispute);
+
+        dispute
+    }
+
+    /// Get the latest dispute raised over a waste, if any
+    pub fn get_dispute(env: Env, waste_id: u128) -> Option<Dispute> {
+        env.storage().instance().get(&("dispute", waste_id))
+    }
+
+    /// Move a disputed v2 waste to `to` regardless of its current holder (admin only)
+    /// Only wastes with an open or upheld dispute can be moved; the transfer is recorded
+    /// in the waste's history with `reason` as its note and the dispute is marked resolved
+    pub fn admin_force_transfer(
+        env: Env,
+        admin: Address,
+        waste_id: u128,
+        to: Address,
+        reason: String,
     ) -> WasteTransfer {
-        from.require_auth();
+        Self::require_admin(&env, &admin);
+
+        let mut dispute = Self::get_dispute(env.clone(), waste_id).expect("No dispute for waste");
+        if dispute.status == DisputeStatus::Resolved {
+            panic!("Dispute already resolved");
+        }
+        if !dispute.allows_force_transfer() {
+            panic!("Dispute was dismissed");
+        }
+
+        Self::require_registered(&env, &to)
+            .unwrap_or_else(|error| panic_with_error!(&env, error));
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        let from = waste.current_owner.clone();
+        if from == to {
+            panic!("Waste already owned by recipient");
+        }
+
+        waste.transfer_to(to.clone());
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+
+        dispute.status = DisputeStatus::Resolved;
+        env.storage().instance().set(&("dispute", waste_id), &dispute);
+
+        let from_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", from.clone()))
+            .unwrap_or(Vec::new(&env));
+        let mut new_from_list = Vec::new(&env);
+        for id in from_list.iter() {
+            if id != waste_id {
+                new_from_list.push_back(id);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", from.clone()), &new_from_list);
+
+        let mut to_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", to.clone()))
+            .unwrap_or(Vec::new(&env));
+        to_list.push_back(waste_id);
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", to.clone()), &to_list);
+
+        let transfer = WasteTransfer::new(
+            waste_id,
+            from.clone(),
+            to.clone(),
+            env.ledger().timestamp(),
+            waste.latitude,
+            waste.longitude,
+            reason,
+        );
+
+        let mut history: Vec<WasteTransfer> = env
+            .storage()
+            .instance()
+            .get(&("transfer_history", waste_id))
+            .unwrap_or(Vec::new(&env));
+        history.push_back(transfer.clone());
+        env.storage()
+            .instance()
+            .set(&("transfer_history", waste_id), &history);
+
+        Self::index_transfer(&env, waste_id as u64, &from, &to);
+
+        events::emit_admin_transferred(&env, waste_id, &admin, &from, &to);
+
+        transfer
+    }
+
+    /// Get active v2 wastes that have not been confirmed yet, in registration order
+    /// Returns at most `limit` IDs (capped at MAX_PAGE_SIZE)
+    pub fn get_unconfirmed_wastes(env: Env, limit: u32) -> Vec<u128> {
+        let mut waste_ids = Vec::new(&env);
+        let limit = limit.min(MAX_PAGE_SIZE);
+
+        for waste_id in 1..=Self::get_waste_count(&env) {
+            if waste_ids.len() >= limit {
+                break;
+            }
+            if let Some(waste) = env
+                .storage()
+                .instance()
+                .get::<_, Waste>(&("waste_v2", waste_id as u128))
+            {
+                if waste.is_active && !waste.is_confirmed {
+                    waste_ids.push_back(waste_id as u128);
+                }
+            }
+        }
+
+        waste_ids
+    }
+
+    /// Get active, unconfirmed v2 wastes currently owned by `owner`
+    /// Returns at most `limit` IDs (capped at MAX_PAGE_SIZE)
+    pub fn get_unconfirmed_wastes_for(env: Env, owner: Address, limit: u32) -> Vec<u128> {
+        let mut waste_ids = Vec::new(&env);
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let owned: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", owner))
+            .unwrap_or(Vec::new(&env));
+
+        for waste_id in owned.iter() {
+            if waste_ids.len() >= limit {
+                break;
+            }
+            if let Some(waste) = env
+                .storage()
+                .instance()
+                .get::<_, Waste>(&("waste_v2", waste_id))
+            {
+                if waste.is_active && !waste.is_confirmed {
+                    waste_ids.push_back(waste_id);
+                }
+            }
+        }
+
+        waste_ids
+    }
+
+    /// Confirm waste details
+    pub fn confirm_waste_details(
+        env: Env,
+        waste_id: u128,
+        confirmer: Address,
+    ) -> types::Waste {
+        confirmer.require_auth();
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        if !waste.is_active {
+            panic!("Cannot confirm deactivated waste");
+        }
+
+        if waste.current_owner == confirmer {
+            panic!("Owner cannot confirm own waste");
+        }
+
+        if waste.is_confirmed {
+            panic!("Waste already confirmed");
+        }
+
+        waste.confirm(confirmer.clone());
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+
+        events::emit_confirmed(&env, waste_id, &confirmer);
+
+        waste
+    }
+
+    /// Reject waste details with a reason code
+    /// Rejected waste is deactivated and cannot later be confirmed
+    pub fn reject_waste_details(
+        env: Env,
+        waste_id: u128,
+        confirmer: Address,
+        reason: Symbol,
+    ) -> types::Waste {
+        confirmer.require_auth();
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        if !waste.is_active {
+            panic!("Cannot reject deactivated waste");
+        }
+
+        if waste.current_owner == confirmer {
+            panic!("Owner cannot reject own waste");
+        }
+
+        if waste.is_confirmed {
+            panic!("Confirmed waste cannot be rejected");
+        }
+
+        waste.reject(confirmer.clone(), reason.clone());
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+
+        events::emit_rejected(&env, waste_id, &confirmer, reason);
+
+        waste
+    }
+
+    /// Reset waste confirmation status so a different party can confirm it
+    /// Only the current confirmer or the admin can reset the confirmation
+    pub fn reset_waste_confirmation(
+        env: Env,
+        waste_id: u128,
+        caller: Address,
+    ) -> types::Waste {
+        caller.require_auth();
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        let admin: Option<Address> = env.storage().instance().get(&ADMIN);
+        if waste.confirmer != caller && admin.as_ref() != Some(&caller) {
+            panic!("Only confirmer or admin can reset confirmation");
+        }
+
+        if !waste.is_confirmed {
+            panic!("Waste is not confirmed");
+        }
+
+        waste.reset_confirmation();
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+
+        events::emit_unconfirmed(&env, waste_id, &caller);
+
+        waste
+    }
+
+    /// Deactivate a waste record (admin only)
+    /// Deactivated waste cannot be queried or reactivated
+    pub fn deactivate_waste(
+        env: Env,
+        waste_id: u128,
+        admin: Address,
+    ) -> types::Waste {
+        Self::require_admin(&env, &admin);
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        if !waste.is_active {
+            panic!("Waste already deactivated");
+        }
+
+        waste.deactivate();
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+
+        events::emit_deactivated(&env, waste_id, &admin);
+
+        waste
+    }
+
+    /// Retire a material that has been burned, destroyed, or otherwise consumed
+    /// Only the current owner can retire a material, and retirement is permanent
+    pub fn retire_material(env: Env, material_id: u64, caller: Address) -> Material {
+        caller.require_auth();
+
+        let mut material: Material =
+            Self::get_waste_internal(&env, material_id).expect("Material not found");
+
+        if material.submitter != caller {
+            panic!("Only material owner can retire");
+        }
+
+        if material.is_retired {
+            panic!("Material already retired");
+        }
+
+        material.retire();
+        Self::set_waste(&env, material_id, &material);
+
+        let retired_count: u64 = env
+            .storage()
+            .instance()
+            .get(&("retired_count",))
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&("retired_count",), &(retired_count + 1));
+
+        events::emit_retired(&env, material_id, &caller);
+
+        material
+    }
+
+    /// Advance a material to the next lifecycle status
+    /// The admin may advance any material. Otherwise collectors and recyclers may mark it
+    /// collected and only recyclers may process or recycle it; non-recyclers may only
+    /// advance materials they own. Rejection leaves the lifecycle through `reject_material`
+    pub fn advance_waste_status(env: Env, material_id: u64, caller: Address) -> Material {
+        caller.require_auth();
+
+        let mut material: Material =
+            Self::get_waste_internal(&env, material_id).expect("Material not found");
+
+        if material.is_retired {
+            panic!("Cannot advance retired material");
+        }
+
+        let next = material.status.next().expect("Material status is final");
+
+        let admin: Option<Address> = env.storage().instance().get(&ADMIN);
+        if admin.as_ref() != Some(&caller) {
+            let participant: Participant = env
+                .storage()
+                .instance()
+                .get(&(caller.clone(),))
+                .expect("Caller not registered");
+
+            if !participant.is_registered {
+                panic!("Caller is not registered");
+            }
+
+            let allowed = match next {
+                WasteStatus::Collected => participant.role.can_collect_materials(),
+                _ => participant.role.can_process_recyclables(),
+            };
+            if !allowed {
+                panic!("Caller cannot advance material to next status");
+            }
+
+            if !participant.role.can_process_recyclables() && material.submitter != caller {
+                panic!("Only the owner or a recycler can advance material");
+            }
+        }
+
+        material.transition_status(next);
+        Self::set_waste(&env, material_id, &material);
+
+        material
+    }
+
+    /// Reject a submitted material (recyclers only)
+    /// Rejected materials are final and count against the submitter's acceptance rate
+    pub fn reject_material(env: Env, material_id: u64, verifier: Address) -> Material {
+        verifier.require_auth();
+        Self::require_not_paused(&env);
+
+        let participant: Participant = env
+            .storage()
+            .instance()
+            .get(&(verifier.clone(),))
+            .expect("Verifier not registered");
+
+        if !participant.is_registered {
+            panic!("Verifier is not registered");
+        }
+
+        if !participant.role.can_process_recyclables() {
+            panic!("Only recyclers can reject materials");
+        }
+
+        let mut material: Material =
+            Self::get_waste_internal(&env, material_id).expect("Material not found");
+
+        if material.verified {
+            panic!("Cannot reject verified material");
+        }
+
+        if !material.transition_status(WasteStatus::Rejected) {
+            panic!("Material status is final");
+        }
+        Self::set_waste(&env, material_id, &material);
+
+        let mut stats: RecyclingStats = env
+            .storage()
+            .instance()
+            .get(&("stats", material.submitter.clone()))
+            .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
+        stats.record_rejection(&material);
+
+        let penalty = Self::get_rejection_penalty(env.clone());
+        if penalty > 0 {
+            stats.total_points = stats.total_points.saturating_sub(penalty);
+            env.storage()
+                .instance()
+                .set(&("reject_penalty", material_id), &penalty);
+            events::emit_penalized(
+                &env,
+                &material.submitter,
+                material_id,
+                penalty,
+                stats.total_points,
+            );
+        }
+
+        env.storage()
+            .instance()
+            .set(&("stats", material.submitter.clone()), &stats);
+
+        material
+    }
+
+    /// Get the number of submitted materials that have not been retired
+    pub fn get_active_materials_count(env: Env) -> u64 {
+        let retired_count: u64 = env
+            .storage()
+            .instance()
+            .get(&("retired_count",))
+            .unwrap_or(0);
+
+        Self::load_global_metrics(&env)
+            .total_materials
+            .saturating_sub(retired_count)
+    }
+
+    /// Batch submit multiple materials for recycling
+    /// More efficient than individual submissions
+    pub fn submit_materials_batch(
+        env: Env,
+        materials: soroban_sdk::Vec<(WasteType, u64, String)>,
+        submitter: Address,
+    ) -> soroban_sdk::Vec<Material> {
+        submitter.require_auth();
+        Self::require_not_paused(&env);
+
+        // Validate submitter is registered
+        Self::require_registered(&env, &submitter)
+            .unwrap_or_else(|error| panic_with_error!(&env, error));
+
+        // Validate every item up front so a bad entry aborts before any state is written
+        let mut batch_weight: u64 = 0;
+        for (_, weight, _) in materials.iter() {
+            Self::require_min_weight(&env, weight);
+            batch_weight = batch_weight
+                .checked_add(weight)
+                .expect("Overflow in batch weight");
+        }
+        Self::apply_submission_cap(&env, &submitter, batch_weight);
+
+        let mut results = soroban_sdk::Vec::new(&env);
+        let timestamp = env.ledger().timestamp();
+
+        // Get or create stats once
+        let mut stats: RecyclingStats = env
+            .storage()
+            .instance()
+            .get(&("stats", submitter.clone()))
+            .unwrap_or_else(|| RecyclingStats::new(submitter.clone()));
+
+        let mut metrics = Self::load_global_metrics(&env);
+        let mut total_weight: u64 = 0;
+
+        // Process each material
+        for item in materials.iter() {
+            let (waste_type, weight, description) = item;
+            let waste_id = Self::next_waste_id(&env);
+
+            let material = Material::new(
+                waste_id,
+                waste_type,
+                weight,
+                submitter.clone(),
+                timestamp,
+                description,
+            );
+
+            Self::set_waste(&env, waste_id, &material);
+            Self::add_to_material_index(&env, &submitter, waste_id);
+            Self::add_to_type_index(&env, waste_type, waste_id);
+            stats.record_submission(&material);
+            metrics.record_submission(&material);
+            events::emit_submitted(&env, waste_id, waste_type, weight, &submitter);
+            results.push_back(material);
+            
+            // Accumulate weight with overflow check
+            total_weight = total_weight.checked_add(weight).expect("Overflow in batch weight");
+        }
+
+        // Update stats once at the end
+        env.storage().instance().set(&("stats", submitter.clone()), &stats);
+        Self::save_global_metrics(&env, &metrics);
+
+        // Update participant stats
+        Self::update_participant_stats(&env, &submitter, total_weight, 0);
+
+        results
+    }
+
+    /// Get material by ID (alias for backward compatibility)
+    pub fn get_material(env: Env, material_id: u64) -> Option<Material> {
+        Self::get_waste(env, material_id)
+    }
+
+    /// Get waste by ID (alias for backward compatibility)
+    pub fn get_waste_by_id(env: Env, waste_id: u64) -> Option<Material> {
+        Self::get_waste(env, waste_id)
+    }
+
+    /// Get waste by ID (primary public interface)
+    /// Returns the waste/material record if it exists, None otherwise
+    pub fn get_waste(env: Env, waste_id: u64) -> Option<Material> {
+        let key = ("waste", waste_id);
+        env.storage().instance().get(&key)
+    }
+
+    /// Get all waste IDs owned by a participant
+    /// Returns a vector of waste IDs where the participant is the current submitter/owner
+    pub fn get_participant_wastes(env: Env, participant: Address) -> Vec<u64> {
+        Self::get_material_index(&env, &participant)
+    }
+
+    /// Get waste IDs owned by a participant, one page at a time
+    /// Scans at most `limit` waste records starting at `start_id` (capped at MAX_PAGE_SIZE)
+    /// Returns the matching IDs and the waste ID to resume from, or None when exhausted
+    pub fn get_participant_wastes_paged(
+        env: Env,
+        participant: Address,
+        start_id: u64,
+        limit: u32,
+    ) -> (Vec<u64>, Option<u64>) {
+        let mut waste_ids = Vec::new(&env);
+        let waste_count = Self::get_waste_count(&env);
+        let limit = limit.min(MAX_PAGE_SIZE) as u64;
+        let start_id = start_id.max(1);
+
+        if limit == 0 || start_id > waste_count {
+            return (waste_ids, None);
+        }
+
+        let end_id = start_id.saturating_add(limit - 1).min(waste_count);
+
+        for waste_id in start_id..=end_id {
+            let key = ("waste", waste_id);
+            if let Some(material) = env.storage().instance().get::<_, Material>(&key) {
+                if material.submitter == participant {
+                    waste_ids.push_back(waste_id);
+                }
+            }
+        }
+
+        let next_cursor = if end_id < waste_count {
+            Some(end_id + 1)
+        } else {
+            None
+        };
+
+        (waste_ids, next_cursor)
+    }
+
+    /// Get the IDs of all materials of a given waste type, in submission order
+    pub fn get_wastes_by_type(env: Env, waste_type: WasteType) -> Vec<u64> {
+        Self::get_type_index(&env, waste_type)
+    }
+
+    /// Get a page of material IDs for a given waste type
+    /// Returns at most `limit` IDs (capped at MAX_PAGE_SIZE) starting at `offset`
+    pub fn get_wastes_by_type_paged(
+        env: Env,
+        waste_type: WasteType,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let index = Self::get_type_index(&env, waste_type);
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut page = Vec::new(&env);
+
+        if offset >= index.len() {
+            return page;
+        }
+
+        let end = offset.saturating_add(limit).min(index.len());
+        for i in offset..end {
+            page.push_back(index.get(i).unwrap());
+        }
+
+        page
+    }
+
+    /// Get the ID of the longest-waiting material that still needs verification
+    /// Rejected and retired materials are skipped; ties go to the lower ID.
+    /// Returns None when every matching material is verified
+    pub fn get_oldest_unverified(env: Env, waste_type: Option<WasteType>) -> Option<u64> {
+        let candidates = match waste_type {
+            Some(waste_type) => Self::get_type_index(&env, waste_type),
+            None => {
+                let mut ids = Vec::new(&env);
+                for id in 1..=Self::get_waste_count(&env) {
+                    ids.push_back(id);
+                }
+                ids
+            }
+        };
+
+        let mut oldest: Option<(u64, u64)> = None;
+        for material_id in candidates.iter() {
+            let Some(material) = Self::get_waste_internal(&env, material_id) else {
+                continue;
+            };
+            if material.verified || material.is_retired || material.status == WasteStatus::Rejected {
+                continue;
+            }
+            if oldest.is_none_or(|(_, submitted_at)| material.submitted_at < submitted_at) {
+                oldest = Some((material_id, material.submitted_at));
+            }
+        }
+
+        oldest.map(|(material_id, _)| material_id)
+    }
+
+    /// Get multiple wastes by IDs (batch retrieval)
+    pub fn get_wastes_batch(
+        env: Env,
+        waste_ids: soroban_sdk::Vec<u64>,
+    ) -> soroban_sdk::Vec<Option<Material>> {
+        let mut results = soroban_sdk::Vec::new(&env);
+
+        for waste_id in waste_ids.iter() {
+            results.push_back(Self::get_waste_internal(&env, waste_id));
+        }
+
+        results
+    }
+
+    /// Get multiple wastes by IDs, keeping only those that exist and match the filters
+    /// `only_verified` drops unverified materials; `waste_type` restricts to one type
+    pub fn get_wastes_batch_filtered(
+        env: Env,
+        waste_ids: soroban_sdk::Vec<u64>,
+        only_verified: bool,
+        waste_type: Option<WasteType>,
+    ) -> soroban_sdk::Vec<Material> {
+        let mut results = soroban_sdk::Vec::new(&env);
+
+        for waste_id in waste_ids.iter() {
+            if let Some(material) = Self::get_waste_internal(&env, waste_id) {
+                if only_verified && !material.verified {
+                    continue;
+                }
+                if waste_type.is_some_and(|wt| wt != material.waste_type) {
+                    continue;
+                }
+                results.push_back(material);
+            }
+        }
+
+        results
+    }
+
+    /// Verify a material submission (only recyclers can verify)
+    pub fn verify_material(
+        env: Env,
+        material_id: u64,
+        verifier: Address,
+    ) -> Result<Material, ScavengerError> {
+        verifier.require_auth();
+        Self::require_not_paused(&env);
+
+        Self::verify_material_internal(&env, material_id, verifier)
+    }
 
-        let mut waste: types::Waste = env
+    /// Verification logic shared by the authorized entrypoints
+    fn verify_material_internal(
+        env: &Env,
+        material_id: u64,
+        verifier: Address,
+    ) -> Result<Material, ScavengerError> {
+        // Check if verifier is a recycler and is registered
+        let verifier_key = (verifier.clone(),);
+        let participant: Participant = env
             .storage()
             .instance()
-            .get(&("waste_v2", waste_id))
-            .expect("Waste not found");
+            .get(&verifier_key)
+            .ok_or(ScavengerError::NotRegistered)?;
+
+        if !participant.is_registered {
+            return Err(ScavengerError::NotRegistered);
+        }
+
+        if !participant.role.can_process_recyclables() {
+            return Err(ScavengerError::Unauthorized);
+        }
+
+        // Get and verify material using new storage system
+        let material: Material =
+            Self::get_waste_internal(env, material_id).ok_or(ScavengerError::WasteNotFound)?;
+
+        if material.is_retired {
+            return Err(ScavengerError::MaterialRetired);
+        }
+
+        if material.status == WasteStatus::Rejected {
+            return Err(ScavengerError::MaterialRejected);
+        }
+
+        if material.verified {
+            return Err(ScavengerError::AlreadyVerified);
+        }
+
+        Self::complete_verification(env, material, &verifier)
+    }
+
+    /// Mark a material verified and award the submitter's tokens and stats
+    fn complete_verification(
+        env: &Env,
+        mut material: Material,
+        verifier: &Address,
+    ) -> Result<Material, ScavengerError> {
+        // Calculate tokens earned, remembering them so a revocation reverses the same amount
+        let tokens_earned = Self::calculate_verification_reward(env, &material)?;
+
+        let material_id = material.id;
+        let was_verified = material.verified;
+        material.verify();
+        Self::set_waste(env, material_id, &material);
+        env.storage()
+            .instance()
+            .set(&("verify_reward", material_id), &tokens_earned);
+
+        // Update submitter stats
+        let mut stats: RecyclingStats = env
+            .storage()
+            .instance()
+            .get(&("stats", material.submitter.clone()))
+            .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
+
+        stats.record_verification_with_points(&material, tokens_earned);
+        env.storage()
+            .instance()
+            .set(&("stats", material.submitter.clone()), &stats);
+
+        // Update global metrics, counting each material once
+        if !was_verified {
+            let mut metrics = Self::load_global_metrics(env);
+            metrics.record_verification(&material);
+            Self::save_global_metrics(env, &metrics);
+        }
+
+        // Update submitter's participant stats with tokens earned
+        Self::update_participant_stats(env, &material.submitter, 0, tokens_earned);
+
+        events::emit_verified(
+            env,
+            material_id,
+            verifier,
+            &material.submitter,
+            tokens_earned,
+        );
+
+        Ok(material)
+    }
+
+    /// Revoke a verification made in error (recyclers only)
+    /// Reverses the reward and verification counts awarded to the submitter. The reward
+    /// clawed back is the one stored at verification; materials verified before rewards
+    /// were stored claw back nothing rather than a recomputed amount
+    pub fn revoke_verification(env: Env, material_id: u64, verifier: Address) -> Material {
+        verifier.require_auth();
+        Self::require_not_paused(&env);
+
+        let participant: Participant = env
+            .storage()
+            .instance()
+            .get(&(verifier.clone(),))
+            .expect("Verifier not registered");
+
+        if !participant.is_registered {
+            panic!("Verifier is not registered");
+        }
+
+        if !participant.role.can_process_recyclables() {
+            panic!("Only recyclers can revoke verification");
+        }
+
+        let mut material: Material =
+            Self::get_waste_internal(&env, material_id).expect("Material not found");
+
+        if !material.verified {
+            panic!("Material not verified");
+        }
+
+        material.unverify();
+        Self::set_waste(&env, material_id, &material);
+
+        let tokens_revoked: u64 = env
+            .storage()
+            .instance()
+            .get(&("verify_reward", material_id))
+            .unwrap_or(0);
+        env.storage().instance().remove(&("verify_reward", material_id));
+
+        // Reverse submitter stats
+        let mut stats: RecyclingStats = env
+            .storage()
+            .instance()
+            .get(&("stats", material.submitter.clone()))
+            .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
+        stats.record_revocation(tokens_revoked);
+        env.storage()
+            .instance()
+            .set(&("stats", material.submitter.clone()), &stats);
+
+        // Update global metrics
+        let mut metrics = Self::load_global_metrics(&env);
+        metrics.record_revocation();
+        Self::save_global_metrics(&env, &metrics);
+
+        Self::revoke_participant_tokens(&env, &material.submitter, tokens_revoked);
+
+        events::emit_revoked(&env, material_id, &verifier, &material.submitter, tokens_revoked);
+
+        material
+    }
+
+    /// Batch verify multiple materials
+    pub fn verify_materials_batch(
+        env: Env,
+        material_ids: soroban_sdk::Vec<u64>,
+        verifier: Address,
+    ) -> soroban_sdk::Vec<Material> {
+        verifier.require_auth();
+        Self::require_not_paused(&env);
+
+        Self::verify_materials_batch_internal(&env, material_ids, &verifier)
+    }
+
+    /// Verify a large batch a chunk at a time
+    /// Processes at most `max_count` IDs starting at `start_index` and returns the
+    /// verified materials plus the index to resume from (the batch length when done)
+    pub fn verify_materials_batch_resumable(
+        env: Env,
+        material_ids: soroban_sdk::Vec<u64>,
+        verifier: Address,
+        start_index: u32,
+        max_count: u32,
+    ) -> (soroban_sdk::Vec<Material>, u32) {
+        verifier.require_auth();
+        Self::require_not_paused(&env);
+
+        let start = start_index.min(material_ids.len());
+        let end = start.saturating_add(max_count).min(material_ids.len());
+        let results =
+            Self::verify_materials_batch_internal(&env, material_ids.slice(start..end), &verifier);
+
+        (results, end)
+    }
+
+    /// Verify the given materials without checking auth
+    fn verify_materials_batch_internal(
+        env: &Env,
+        material_ids: soroban_sdk::Vec<u64>,
+        verifier: &Address,
+    ) -> soroban_sdk::Vec<Material> {
+        // Check if verifier is a recycler and is registered
+        let verifier_key = (verifier.clone(),);
+        let participant: Participant = env
+            .storage()
+            .instance()
+            .get(&verifier_key)
+            .expect("Verifier not registered");
+
+        if !participant.is_registered {
+            panic!("Verifier is not registered");
+        }
+
+        if !participant.role.can_process_recyclables() {
+            panic!("Only recyclers can verify materials");
+        }
+
+        let mut results = soroban_sdk::Vec::new(env);
+        let mut metrics = Self::load_global_metrics(env);
+        let mut seen = soroban_sdk::Vec::new(env);
+
+        for material_id in material_ids.iter() {
+            // Repeated IDs are only verified once
+            if seen.contains(material_id) {
+                continue;
+            }
+            seen.push_back(material_id);
+
+            if let Some(mut material) = Self::get_waste_internal(env, material_id) {
+                // Retired, rejected and already verified materials are skipped like missing ones
+                if material.is_retired
+                    || material.status == WasteStatus::Rejected
+                    || material.verified
+                {
+                    continue;
+                }
+
+                // Materials whose reward overflows are skipped like missing ones
+                let Ok(tokens_earned) = Self::calculate_verification_reward(env, &material) else {
+                    continue;
+                };
+
+                let was_verified = material.verified;
+                material.verify();
+                Self::set_waste(env, material_id, &material);
+                env.storage()
+                    .instance()
+                    .set(&("verify_reward", material_id), &tokens_earned);
+
+                // Update submitter stats
+                let mut stats: RecyclingStats = env
+                    .storage()
+                    .instance()
+                    .get(&("stats", material.submitter.clone()))
+                    .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
+
+                stats.record_verification_with_points(&material, tokens_earned);
+                env.storage()
+                    .instance()
+                    .set(&("stats", material.submitter.clone()), &stats);
+
+                if !was_verified {
+                    metrics.record_verification(&material);
+                }
+
+                // Update submitter's participant stats with tokens earned
+                Self::update_participant_stats(env, &material.submitter, 0, tokens_earned);
+
+                events::emit_verified(
+                    env,
+                    material_id,
+                    verifier,
+                    &material.submitter,
+                    tokens_earned,
+                );
+
+                results.push_back(material);
+            }
+        }
+
+        Self::save_global_metrics(env, &metrics);
+
+        results
+    }
+
+    /// Get recycling statistics for a participant
+    pub fn get_stats(env: Env, participant: Address) -> Option<RecyclingStats> {
+        env.storage().instance().get(&("stats", participant))
+    }
+
+    /// Rebuild a participant's recycling stats from their material records (admin only)
+    /// Walks the participant's material index and overwrites the stored stats; points are
+    /// the verification rewards recorded for each verified material less rejection penalties
+    pub fn recompute_stats(env: Env, admin: Address, address: Address) -> RecyclingStats {
+        Self::require_admin(&env, &admin);
+
+        let mut stats = RecyclingStats::new(address.clone());
+        for material_id in Self::get_material_index(&env, &address).iter() {
+            let Some(material) = Self::get_waste_internal(&env, material_id) else {
+                continue;
+            };
+
+            stats.record_submission(&material);
+            if material.verified {
+                let points: u64 = env
+                    .storage()
+                    .instance()
+                    .get(&("verify_reward", material_id))
+                    .unwrap_or_else(|| {
+                        Self::calculate_material_reward(&env, &material)
+                            .unwrap_or_else(|error| panic_with_error!(&env, error))
+                    });
+                stats.record_verification_with_points(&material, points);
+            }
+            stats.record_rejection(&material);
+
+            let penalty: u64 = env
+                .storage()
+                .instance()
+                .get(&("reject_penalty", material_id))
+                .unwrap_or(0);
+            stats.total_points = stats.total_points.saturating_sub(penalty);
+        }
+
+        let donation_bonus: u64 = env
+            .storage()
+            .instance()
+            .get(&("donation_bonus_earned", address.clone()))
+            .unwrap_or(0);
+        stats.total_points = stats.total_points.saturating_add(donation_bonus);
+
+        env.storage().instance().set(&("stats", address), &stats);
+        stats
+    }
+
+    /// Get a participant's submission count and total weight for one waste type
+    /// Returns (count, weight in grams), zeros if the participant has no stats
+    pub fn get_stats_by_type(env: Env, participant: Address, waste_type: WasteType) -> (u64, u64) {
+        match Self::get_stats(env, participant) {
+            Some(stats) => (stats.count_by_type(waste_type), stats.weight_by_type(waste_type)),
+            None => (0, 0),
+        }
+    }
+
+    /// Get a participant's environmental impact score
+    /// Each submission scores by waste type: 3 for infinitely recyclable types
+    /// (metal, glass), 1 for biodegradable types (paper) and 2 for the rest.
+    /// Returns 0 if the participant has no stats
+    pub fn get_impact_score(env: Env, address: Address) -> u64 {
+        Self::get_stats(env, address)
+            .map(|stats| stats.impact_score())
+            .unwrap_or(0)
+    }
+
+    /// Get a participant's verification rate and recycler badges
+    /// Returns (verification rate %, active recycler, verified contributor)
+    pub fn get_participant_badges(env: Env, address: Address) -> (u64, bool, bool) {
+        let stats = Self::get_stats(env, address.clone())
+            .unwrap_or_else(|| RecyclingStats::new(address));
+
+        (
+            stats.verification_rate(),
+            stats.is_active_recycler(),
+            stats.is_verified_contributor(),
+        )
+    }
+
+    /// Get ecosystem-wide recycling metrics across all participants
+    pub fn get_global_metrics(env: Env) -> GlobalMetrics {
+        Self::load_global_metrics(&env)
+    }
+
+    /// Get the waste type with the most submissions across all participants
+    /// Ties resolve to the lowest enum discriminant; None if nothing was submitted
+    pub fn get_global_most_submitted_type(env: Env) -> Option<WasteType> {
+        Self::load_global_metrics(&env).most_submitted_type()
+    }
+
+    /// Get supply chain statistics (total wastes, total weight, total tokens earned)
+    /// Returns a tuple of (total_wastes_count, total_weight_grams, total_tokens_earned)
+    pub fn get_supply_chain_stats(env: Env) -> (u64, u64, u128) {
+        let total_wastes = Self::get_waste_count(&env);
+        let total_weight = Self::get_total_active_waste_weight(&env);
+        let total_tokens = Self::get_total_tokens(&env);
+        
+        (total_wastes, total_weight, total_tokens)
+    }
+
+    /// Get the active incentive with the highest reward for a specific manufacturer and waste type
+    /// Returns None if no active incentive is found
+    pub fn get_active_mfr_incentive(
+        env: Env,
+        manufacturer: Address,
+        waste_type: WasteType,
+    ) -> Option<Incentive> {
+        // Get all incentives for this manufacturer
+        let incentive_ids = Self::get_incentives_by_rewarder(env.clone(), manufacturer.clone());
+        
+        let mut best_incentive: Option<Incentive> = None;
+        let mut highest_reward: u64 = 0;
+        
+        // Iterate through all incentives and find the best active one
+        for incentive_id in incentive_ids.iter() {
+            if let Some(incentive) = Self::get_incentive_internal(&env, incentive_id) {
+                // Check if incentive matches criteria: active and correct waste type
+                if incentive.active && incentive.accepts(waste_type) {
+                    // Keep track of the incentive with highest reward
+                    if incentive.reward_points > highest_reward {
+                        highest_reward = incentive.reward_points;
+                        best_incentive = Some(incentive);
+                    }
+                }
+            }
+        }
+        
+        best_incentive
+    }
+
+    /// Get the active incentives for a waste type, sorted by reward_points (highest first)
+    /// Returns at most MAX_PAGE_SIZE entries; use `get_incentives_limited` for smaller pages
+    pub fn get_incentives(env: Env, waste_type: WasteType) -> Vec<Incentive> {
+        Self::top_incentives(&env, waste_type, MAX_PAGE_SIZE)
+    }
+
+    /// Get the top `limit` active incentives for a waste type by reward_points (highest first)
+    /// Only `limit` entries are kept while scanning, so cost stays bounded for large lists
+    pub fn get_incentives_limited(env: Env, waste_type: WasteType, limit: u32) -> Vec<Incentive> {
+        Self::top_incentives(&env, waste_type, limit.min(MAX_PAGE_SIZE))
+    }
+
+    /// Get the top `limit` active incentives for a waste type by remaining_budget (highest first)
+    pub fn get_incentives_by_budget(env: Env, waste_type: WasteType, limit: u32) -> Vec<Incentive> {
+        Self::top_incentives_by(&env, waste_type, limit.min(MAX_PAGE_SIZE), |incentive| {
+            incentive.remaining_budget
+        })
+    }
+
+    /// Select the active incentives with the highest reward_points using bounded insertion
+    fn top_incentives(env: &Env, waste_type: WasteType, limit: u32) -> Vec<Incentive> {
+        Self::top_incentives_by(env, waste_type, limit, |incentive| incentive.reward_points)
+    }
+
+    /// Select the active incentives with the highest `rank` using bounded insertion
+    fn top_incentives_by(
+        env: &Env,
+        waste_type: WasteType,
+        limit: u32,
+        rank: fn(&Incentive) -> u64,
+    ) -> Vec<Incentive> {
+        let mut top: Vec<Incentive> = Vec::new(env);
+
+        if limit == 0 {
+            return top;
+        }
+
+        let incentive_ids: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&("general_incentives", waste_type))
+            .unwrap_or(Vec::new(env));
+
+        for incentive_id in incentive_ids.iter() {
+            let Some(incentive) = Self::get_incentive_internal(env, incentive_id) else {
+                continue;
+            };
+            if !incentive.active {
+                continue;
+            }
 
-        if waste.current_owner != from {
-            panic!("Caller does not own waste");
-        }
+            if top.len() == limit && rank(&incentive) <= rank(&top.get(limit - 1).unwrap()) {
+                continue;
+            }
 
-        if !waste.is_active {
-            panic!("Cannot transfer deactivated waste");
-        }
+            // Find the insertion point that keeps the list sorted descending
+            let mut position = top.len();
+            for (i, existing) in top.iter().enumerate() {
+                if rank(&incentive) > rank(&existing) {
+                    position = i as u32;
+                    break;
+                }
+            }
 
-        if !Self::is_valid_transfer(env.clone(), from.clone(), to.clone()) {
-            panic!("Invalid transfer");
+            top.insert(position, incentive);
+            if top.len() > limit {
+                top.pop_back();
+            }
         }
 
-        waste.transfer_to(to.clone());
-        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        top
+    }
 
-        let from_list: Vec<u128> = env
-            .storage()
+    /// Get the IDs of all incentives owned by a rewarder
+    pub fn get_incentives_by_rewarder(env: Env, rewarder: Address) -> Vec<u64> {
+        env.storage()
             .instance()
-            .get(&("participant_wastes", from.clone()))
-            .unwrap_or(Vec::new(&env));
-        let mut new_from_list = Vec::new(&env);
-        for id in from_list.iter() {
-            if id != waste_id {
-                new_from_list.push_back(id);
+            .get(&("rewarder_incentives", rewarder))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the remaining budget a manufacturer has committed across its active incentives
+    pub fn get_committed_budget(env: Env, manufacturer: Address) -> u64 {
+        let mut total: u64 = 0;
+        for incentive_id in Self::get_incentives_by_rewarder(env.clone(), manufacturer).iter() {
+            if let Some(incentive) = Self::get_incentive_internal(&env, incentive_id) {
+                if incentive.active {
+                    total = total
+                        .checked_add(incentive.remaining_budget)
+                        .expect("Overflow in committed budget");
+                }
+            }
+        }
+        total
+    }
+
+    /// Get the remaining budget committed across every active incentive
+    pub fn get_total_committed_budget(env: Env) -> u64 {
+        let mut total: u64 = 0;
+        for incentive_id in 1..=Self::get_incentive_count(&env) {
+            if let Some(incentive) = Self::get_incentive_internal(&env, incentive_id) {
+                if incentive.active {
+                    total = total
+                        .checked_add(incentive.remaining_budget)
+                        .expect("Overflow in committed budget");
+                }
             }
         }
+        total
+    }
+
+    /// Allow a non-manufacturer, such as a municipality or NGO, to sponsor incentives (admin only)
+    pub fn add_incentive_sponsor(env: Env, admin: Address, sponsor: Address) {
+        Self::require_admin(&env, &admin);
+        let old_value = Self::sponsor_config_value(&env, &sponsor);
         env.storage()
             .instance()
-            .set(&("participant_wastes", from.clone()), &new_from_list);
+            .set(&("incentive_sponsor", sponsor.clone()), &true);
+        Self::log_config_change(
+            &env,
+            &admin,
+            "inc_sponsor",
+            old_value,
+            ConfigValue::Address(sponsor),
+        );
+    }
 
-        let mut to_list: Vec<u128> = env
-            .storage()
-            .instance()
-            .get(&("participant_wastes", to.clone()))
-            .unwrap_or(Vec::new(&env));
-        to_list.push_back(waste_id);
+    /// Remove an address from the incentive sponsor allowlist (admin only)
+    /// Incentives it already created are unaffected
+    pub fn remove_incentive_sponsor(env: Env, admin: Address, sponsor: Address) {
+        Self::require_admin(&env, &admin);
+        let old_value = Self::sponsor_config_value(&env, &sponsor);
         env.storage()
             .instance()
-            .set(&("participant_wastes", to.clone()), &to_list);
+            .remove(&("incentive_sponsor", sponsor));
+        Self::log_config_change(&env, &admin, "inc_sponsor", old_value, ConfigValue::Unset);
+    }
 
-        let timestamp = env.ledger().timestamp();
-        let transfer = WasteTransfer::new(
-            waste_id,
-            from.clone(),
-            to.clone(),
-            timestamp,
-            latitude,
-            longitude,
-            soroban_sdk::symbol_short!("transfer"),
-        );
+    /// Config log value for a sponsor's allowlist entry: its address if listed
+    fn sponsor_config_value(env: &Env, sponsor: &Address) -> ConfigValue {
+        if Self::is_incentive_sponsor(env.clone(), sponsor.clone()) {
+            ConfigValue::Address(sponsor.clone())
+        } else {
+            ConfigValue::Unset
+        }
+    }
 
-        let mut history: Vec<WasteTransfer> = env
-            .storage()
-            .instance()
-            .get(&("transfer_history", waste_id))
-            .unwrap_or(Vec::new(&env));
-        history.push_back(transfer.clone());
+    /// Check whether an address is on the incentive sponsor allowlist
+    pub fn is_incentive_sponsor(env: Env, address: Address) -> bool {
         env.storage()
             .instance()
-            .set(&("transfer_history", waste_id), &history);
+            .get(&("incentive_sponsor", address))
+            .unwrap_or(false)
+    }
+
+    /// Create a new incentive
+    pub fn create_incentive(
+        env: Env,
+        rewarder: Address,
+        waste_type: WasteType,
+        reward_points: u64,
+        total_budget: u64,
+    ) -> Incentive {
+        Self::create_incentive_internal(
+            &env,
+            rewarder,
+            waste_type,
+            reward_points,
+            total_budget,
+            0,
+            u64::MAX,
+        )
+    }
+
+    /// Create a new incentive that only rewards materials weighing between
+    /// `min_weight` and `max_weight` grams (inclusive)
+    pub fn create_incentive_with_band(
+        env: Env,
+        rewarder: Address,
+        waste_type: WasteType,
+        reward_points: u64,
+        total_budget: u64,
+        min_weight: u64,
+        max_weight: u64,
+    ) -> Incentive {
+        Self::create_incentive_internal(
+            &env,
+            rewarder,
+            waste_type,
+            reward_points,
+            total_budget,
+            min_weight,
+            max_weight,
+        )
+    }
+
+    /// Create a new incentive that only rewards materials located within a bounding box
+    /// Coordinates are scaled by 1e6; a material's location is that of the v2 waste it
+    /// was promoted to, falling back to the claimer's registered location
+    pub fn create_geofenced_incentive(
+        env: Env,
+        rewarder: Address,
+        waste_type: WasteType,
+        reward_points: u64,
+        total_budget: u64,
+        min_lat: i128,
+        max_lat: i128,
+        min_lon: i128,
+        max_lon: i128,
+    ) -> Incentive {
+        Self::validate_coordinates(min_lat, min_lon);
+        Self::validate_coordinates(max_lat, max_lon);
+        if min_lat > max_lat || min_lon > max_lon {
+            panic!("Invalid incentive region");
+        }
 
-        env.events().publish(
-            (soroban_sdk::symbol_short!("transfer"), waste_id),
-            (from, to, timestamp),
+        let mut incentive = Self::create_incentive_internal(
+            &env,
+            rewarder,
+            waste_type,
+            reward_points,
+            total_budget,
+            0,
+            u64::MAX,
         );
+        incentive.min_lat = min_lat;
+        incentive.max_lat = max_lat;
+        incentive.min_lon = min_lon;
+        incentive.max_lon = max_lon;
+        Self::set_incentive(&env, incentive.id, &incentive);
 
-        transfer
+        incentive
     }
 
-    /// Transfer aggregated waste from collector to manufacturer
-    pub fn transfer_collected_waste(
+    /// Check whether a claim falls inside a geofenced incentive's region
+    /// Uses the promoted v2 waste location, else the claimer's registered location
+    fn is_claim_in_region(
+        env: &Env,
+        incentive: &Incentive,
+        material_id: u64,
+        claimer: &Address,
+    ) -> bool {
+        if !incentive.is_geofenced() {
+            return true;
+        }
+
+        let waste_location = Self::get_promoted_waste(env.clone(), material_id)
+            .and_then(|waste_id| Self::get_waste_coordinates(env.clone(), waste_id));
+        let location = waste_location.or_else(|| {
+            Self::get_participant(env.clone(), claimer.clone())
+                .map(|participant| (participant.latitude, participant.longitude))
+        });
+
+        match location {
+            Some((latitude, longitude)) => incentive.contains_location(latitude, longitude),
+            None => false,
+        }
+    }
+
+    /// Create a new incentive whose reward grows with the material weight
+    /// `tiers` holds `(weight_threshold_grams, bonus_percent)` pairs in strictly
+    /// ascending threshold order; the highest threshold at or below the material
+    /// weight adds its bonus to the base reward
+    pub fn create_tiered_incentive(
         env: Env,
+        rewarder: Address,
         waste_type: WasteType,
-        collector: Address,
-        manufacturer: Address,
-        latitude: i128,
-        longitude: i128,
-        notes: soroban_sdk::Symbol,
-    ) -> u128 {
-        collector.require_auth();
+        reward_points: u64,
+        total_budget: u64,
+        tiers: Vec<(u64, u32)>,
+    ) -> Incentive {
+        let mut previous: Option<u64> = None;
+        for (threshold, _) in tiers.iter() {
+            if previous.is_some_and(|prev| threshold <= prev) {
+                panic!("Tiers must be sorted ascending");
+            }
+            previous = Some(threshold);
+        }
 
-        let collector_key = (collector.clone(),);
-        let collector_participant: Participant = env
-            .storage()
+        let incentive = Self::create_incentive_internal(
+            &env,
+            rewarder,
+            waste_type,
+            reward_points,
+            total_budget,
+            0,
+            u64::MAX,
+        );
+        env.storage()
             .instance()
-            .get(&collector_key)
-            .expect("Collector not registered");
+            .set(&("incentive_tiers", incentive.id), &tiers);
 
-        if collector_participant.role != ParticipantRole::Collector {
-            panic!("Only collectors can use this");
+        incentive
+    }
+
+    /// Create one incentive covering several waste types, each with its own sub-budget
+    /// `type_budgets` holds `(waste_type, budget)` pairs; claims debit the sub-budget of
+    /// the material's type and the total budget is the sum of all sub-budgets
+    pub fn create_multi_type_incentive(
+        env: Env,
+        rewarder: Address,
+        reward_points: u64,
+        type_budgets: Vec<(WasteType, u64)>,
+    ) -> Incentive {
+        let mut accepted_types = Vec::new(&env);
+        let mut budgets = Vec::new(&env);
+        let mut total_budget: u64 = 0;
+        for (waste_type, budget) in type_budgets.iter() {
+            if accepted_types.contains(waste_type) {
+                panic!("Duplicate waste type");
+            }
+            total_budget = total_budget
+                .checked_add(budget)
+                .expect("Overflow in total_budget");
+            accepted_types.push_back(waste_type);
+            budgets.push_back(budget);
         }
 
-        let manufacturer_key = (manufacturer.clone(),);
-        let manufacturer_participant: Participant = env
+        let primary_type = accepted_types.first().expect("No waste types given");
+
+        let mut incentive = Self::create_incentive_internal(
+            &env,
+            rewarder,
+            primary_type,
+            reward_points,
+            total_budget,
+            0,
+            u64::MAX,
+        );
+        incentive.accepted_types = accepted_types.clone();
+        incentive.type_budgets = budgets;
+        Self::set_incentive(&env, incentive.id, &incentive);
+
+        // The primary type is indexed on creation; add the rest
+        for waste_type in accepted_types.iter().skip(1) {
+            let key = ("general_incentives", waste_type);
+            let mut general_incentives: Vec<u64> =
+                env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+            general_incentives.push_back(incentive.id);
+            env.storage().instance().set(&key, &general_incentives);
+        }
+
+        incentive
+    }
+
+    /// Get the weight tiers of an incentive (empty if it has none)
+    pub fn get_incentive_tiers(env: Env, incentive_id: u64) -> Vec<(u64, u32)> {
+        env.storage()
+            .instance()
+            .get(&("incentive_tiers", incentive_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the bonus percentage of the highest tier reached by `weight` (0 without tiers)
+    fn tier_bonus_percent(env: &Env, incentive_id: u64, weight: u64) -> u32 {
+        let tiers: Vec<(u64, u32)> = env
             .storage()
             .instance()
-            .get(&manufacturer_key)
-            .expect("Manufacturer not registered");
+            .get(&("incentive_tiers", incentive_id))
+            .unwrap_or(Vec::new(env));
+
+        let mut bonus_percent = 0;
+        for (threshold, percent) in tiers.iter() {
+            if threshold > weight {
+                break;
+            }
+            bonus_percent = percent;
+        }
+        bonus_percent
+    }
+
+    /// Calculate an incentive's reward for a weight, with any tier bonus, at the configured
+    /// scale factor
+    /// Returns None if the calculation overflows
+    fn calculate_incentive_tiered_reward(
+        env: &Env,
+        incentive_id: u64,
+        incentive: &Incentive,
+        weight: u64,
+    ) -> Option<u64> {
+        incentive.calculate_reward_with_bonus(
+            weight,
+            Self::get_scale_factor(env.clone()),
+            Self::tier_bonus_percent(env, incentive_id, weight),
+        )
+    }
+
+    /// What a claim of `material` against an incentive pays now: the reward with any
+    /// tier bonus, capped at the budget left for the material's waste type
+    fn claimable_amount(
+        env: &Env,
+        incentive_id: u64,
+        incentive: &Incentive,
+        material: &Material,
+    ) -> u64 {
+        incentive.best_claimable_amount(
+            material.waste_type,
+            material.weight,
+            Self::get_scale_factor(env.clone()),
+            Self::tier_bonus_percent(env, incentive_id, material.weight),
+        )
+    }
+
+    fn create_incentive_internal(
+        env: &Env,
+        rewarder: Address,
+        waste_type: WasteType,
+        reward_points: u64,
+        total_budget: u64,
+        min_weight: u64,
+        max_weight: u64,
+    ) -> Incentive {
+        rewarder.require_auth();
+        Self::require_not_paused(env);
+
+        if min_weight > max_weight {
+            panic!("Invalid weight band");
+        }
+
+        // Verify rewarder is a manufacturer or an allowlisted sponsor
+        if !Self::is_incentive_sponsor(env.clone(), rewarder.clone()) {
+            if !Self::is_participant_registered(env.clone(), rewarder.clone()) {
+                panic!("Rewarder not registered");
+            }
+
+            let participant =
+                Self::get_participant(env.clone(), rewarder.clone()).expect("Rewarder not found");
+
+            if !participant.role.can_manufacture() {
+                panic!("Only manufacturers can create incentives");
+            }
+        }
+
+        // Get next incentive ID
+        let incentive_id = Self::next_incentive_id(env);
+
+        // Create incentive
+        let mut incentive = Incentive::new(
+            env,
+            incentive_id,
+            rewarder.clone(),
+            waste_type,
+            reward_points,
+            total_budget,
+            env.ledger().timestamp(),
+        );
+        incentive.min_weight = min_weight;
+        incentive.max_weight = max_weight;
+
+        // Store incentive
+        Self::set_incentive(env, incentive_id, &incentive);
+
+        // Add to rewarder's incentive list
+        let key = ("rewarder_incentives", rewarder.clone());
+        let mut rewarder_incentives: Vec<u64> =
+            env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        rewarder_incentives.push_back(incentive_id);
+        env.storage().instance().set(&key, &rewarder_incentives);
+
+        // Add to general incentives list for this waste type
+        let key = ("general_incentives", waste_type);
+        let mut general_incentives: Vec<u64> =
+            env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        general_incentives.push_back(incentive_id);
+        env.storage().instance().set(&key, &general_incentives);
+
+        incentive
+    }
+
+    /// Hand an incentive over to another registered manufacturer
+    /// The new rewarder takes over all creator-only operations on the incentive
+    pub fn transfer_incentive_ownership(
+        env: Env,
+        incentive_id: u64,
+        current_rewarder: Address,
+        new_rewarder: Address,
+    ) -> Incentive {
+        current_rewarder.require_auth();
+
+        let mut incentive =
+            Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
+
+        if incentive.rewarder != current_rewarder {
+            panic!("Only incentive creator can transfer ownership");
+        }
+
+        let participant =
+            Self::get_participant(env.clone(), new_rewarder.clone()).expect("New rewarder not found");
+        if !participant.is_registered || !participant.role.can_manufacture() {
+            panic!("New rewarder must be a registered manufacturer");
+        }
+
+        incentive.rewarder = new_rewarder.clone();
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        // Move the incentive between the rewarders' lists
+        let mut old_list = Vec::new(&env);
+        for id in Self::get_incentives_by_rewarder(env.clone(), current_rewarder.clone()).iter() {
+            if id != incentive_id {
+                old_list.push_back(id);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&("rewarder_incentives", current_rewarder), &old_list);
+
+        let mut new_list = Self::get_incentives_by_rewarder(env.clone(), new_rewarder.clone());
+        new_list.push_back(incentive_id);
+        env.storage()
+            .instance()
+            .set(&("rewarder_incentives", new_rewarder), &new_list);
 
-        if manufacturer_participant.role != ParticipantRole::Manufacturer {
-            panic!("Recipient must be manufacturer");
+        incentive
+    }
+
+    /// Deactivate an incentive (only by creator)
+    pub fn deactivate_incentive(env: Env, incentive_id: u64, rewarder: Address) -> Incentive {
+        rewarder.require_auth();
+
+        let mut incentive = Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
+
+        // Verify caller is the creator
+        if incentive.rewarder != rewarder {
+            panic!("Only incentive creator can deactivate");
         }
 
-        let waste_id = Self::next_waste_id(&env) as u128;
-        let timestamp = env.ledger().timestamp();
+        incentive.deactivate();
+        Self::set_incentive(&env, incentive_id, &incentive);
 
-        let waste = types::Waste::new(
-            waste_id,
-            waste_type,
-            0,
-            manufacturer.clone(),
-            latitude,
-            longitude,
-            timestamp,
-            true,
-            false,
-            manufacturer.clone(),
-        );
+        incentive
+    }
 
-        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+    /// Reactivate a deactivated incentive (only by creator)
+    /// Incentives with no remaining budget cannot be reactivated
+    pub fn reactivate_incentive(env: Env, incentive_id: u64, rewarder: Address) -> Incentive {
+        rewarder.require_auth();
 
-        let mut manufacturer_list: Vec<u128> = env
-            .storage()
-            .instance()
-            .get(&("participant_wastes", manufacturer.clone()))
-            .unwrap_or(Vec::new(&env));
-        manufacturer_list.push_back(waste_id);
-        env.storage()
-            .instance()
-            .set(&("participant_wastes", manufacturer.clone()), &manufacturer_list);
+        let mut incentive = Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
 
-        let transfer = WasteTransfer::new(
-            waste_id,
-            collector.clone(),
-            manufacturer.clone(),
-            timestamp,
-            latitude,
-            longitude,
-            notes,
-        );
+        // Verify caller is the creator
+        if incentive.rewarder != rewarder {
+            panic!("Only incentive creator can reactivate");
+        }
 
-        let mut history: Vec<WasteTransfer> = env
-            .storage()
-            .instance()
-            .get(&("transfer_history", waste_id))
-            .unwrap_or(Vec::new(&env));
-        history.push_back(transfer);
-        env.storage()
-            .instance()
-            .set(&("transfer_history", waste_id), &history);
+        if incentive.remaining_budget == 0 {
+            panic!("Cannot reactivate exhausted incentive");
+        }
 
-        env.events().publish(
-            (soroban_sdk::symbol_short!("bulk_xfr"), waste_id),
-            (collector, manufacturer, waste_type, timestamp),
-        );
+        incentive.reactivate();
+        Self::set_incentive(&env, incentive_id, &incentive);
 
-        waste_id
+        incentive
     }
 
-    /// Confirm waste details
-    pub fn confirm_waste_details(
+    /// Add budget to an existing incentive (only by creator)
+    /// Incentives that were auto-deactivated by exhausting their budget are reactivated
+    pub fn top_up_incentive(
         env: Env,
-        waste_id: u128,
-        confirmer: Address,
-    ) -> types::Waste {
-        confirmer.require_auth();
-
-        let mut waste: types::Waste = env
-            .storage()
-            .instance()
-            .get(&("waste_v2", waste_id))
-            .expect("Waste not found");
+        incentive_id: u64,
+        rewarder: Address,
+        additional_budget: u64,
+    ) -> Incentive {
+        rewarder.require_auth();
 
-        if !waste.is_active {
-            panic!("Cannot confirm deactivated waste");
+        if additional_budget == 0 {
+            panic!("Additional budget must be greater than zero");
         }
 
-        if waste.current_owner == confirmer {
-            panic!("Owner cannot confirm own waste");
+        let mut incentive = Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
+
+        // Verify caller is the creator
+        if incentive.rewarder != rewarder {
+            panic!("Only incentive creator can top up");
         }
 
-        if waste.is_confirmed {
-            panic!("Waste already confirmed");
+        if !incentive.accepted_types.is_empty() {
+            panic!("Cannot top up multi-type incentive");
         }
 
-        waste.confirm(confirmer.clone());
-        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        incentive.total_budget = incentive
+            .total_budget
+            .checked_add(additional_budget)
+            .expect("Overflow in total_budget");
+        incentive.remaining_budget = incentive
+            .remaining_budget
+            .checked_add(additional_budget)
+            .expect("Overflow in remaining_budget");
+
+        if incentive.exhausted {
+            incentive.reactivate();
+        }
 
-        env.events().publish(
-            (soroban_sdk::symbol_short!("confirmed"), waste_id),
-            (confirmer, env.ledger().timestamp()),
-        );
+        Self::set_incentive(&env, incentive_id, &incentive);
 
-        waste
+        incentive
     }
 
-    /// Reset waste confirmation status
-    /// Only the waste owner can reset the confirmation
-    pub fn reset_waste_confirmation(
-        env: Env,
-        waste_id: u128,
-        owner: Address,
-    ) -> types::Waste {
-        owner.require_auth();
+    /// Withdraw the unused budget of a deactivated incentive (only by creator)
+    /// Returns the withdrawn points; the total budget is left as created and the
+    /// withdrawn points are recorded so utilization still reflects what was paid out
+    pub fn withdraw_incentive_budget(env: Env, incentive_id: u64, rewarder: Address) -> u64 {
+        rewarder.require_auth();
 
-        let mut waste: types::Waste = env
-            .storage()
-            .instance()
-            .get(&("waste_v2", waste_id))
-            .expect("Waste not found");
+        let mut incentive = Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
 
-        if waste.current_owner != owner {
-            panic!("Only owner can reset confirmation");
+        // Verify caller is the creator
+        if incentive.rewarder != rewarder {
+            panic!("Only incentive creator can withdraw");
         }
 
-        if !waste.is_confirmed {
-            panic!("Waste is not confirmed");
+        if incentive.active {
+            panic!("Deactivate before withdrawing");
         }
 
-        waste.reset_confirmation();
-        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        // Expired reservations hand their budget back so it can be withdrawn too
+        Self::release_expired_reservations(&env, &mut incentive);
 
-        env.events().publish(
-            (soroban_sdk::symbol_short!("reset"), waste_id),
-            (owner, env.ledger().timestamp()),
-        );
+        let withdrawn = incentive.remaining_budget;
+        incentive.remaining_budget = 0;
+        for index in 0..incentive.type_budgets.len() {
+            incentive.type_budgets.set(index, 0);
+        }
+        Self::set_incentive(&env, incentive_id, &incentive);
 
-        waste
+        let key = ("incentive_withdrawn", incentive_id);
+        let total_withdrawn: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&key, &total_withdrawn.saturating_add(withdrawn));
+
+        withdrawn
     }
 
-    /// Deactivate a waste record (admin only)
-    /// Deactivated waste cannot be queried or reactivated
-    pub fn deactivate_waste(
-        env: Env,
-        waste_id: u128,
-        admin: Address,
-    ) -> types::Waste {
-        Self::require_admin(&env, &admin);
+    /// Delete a spent incentive and drop it from the rewarder and waste type indexes
+    /// Only inactive incentives with no remaining budget and no outstanding reservations
+    /// can be purged (only by creator)
+    pub fn purge_incentive(env: Env, incentive_id: u64, rewarder: Address) {
+        rewarder.require_auth();
 
-        let mut waste: types::Waste = env
-            .storage()
-            .instance()
-            .get(&("waste_v2", waste_id))
-            .expect("Waste not found");
+        let incentive = Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
 
-        if !waste.is_active {
-            panic!("Waste already deactivated");
+        // Verify caller is the creator
+        if incentive.rewarder != rewarder {
+            panic!("Only incentive creator can purge");
         }
 
-        waste.deactivate();
-        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        if incentive.active {
+            panic!("Cannot purge active incentive");
+        }
 
-        env.events().publish(
-            (soroban_sdk::symbol_short!("deactive"), waste_id),
-            (admin, env.ledger().timestamp()),
-        );
+        if incentive.remaining_budget > 0 {
+            panic!("Cannot purge incentive with remaining budget");
+        }
 
-        waste
-    }
+        if !Self::get_reserved_materials(&env, incentive_id).is_empty() {
+            panic!("Cannot purge incentive with outstanding reservations");
+        }
 
-    /// Batch submit multiple materials for recycling
-    /// More efficient than individual submissions
-    pub fn submit_materials_batch(
-        env: Env,
-        materials: soroban_sdk::Vec<(WasteType, u64, String)>,
-        submitter: Address,
-    ) -> soroban_sdk::Vec<Material> {
-        submitter.require_auth();
+        env.storage().instance().remove(&("incentive", incentive_id));
+        env.storage().instance().remove(&("incentive_tiers", incentive_id));
+        env.storage().instance().remove(&("incentive_withdrawn", incentive_id));
 
-        // Validate submitter is registered
-        Self::require_registered(&env, &submitter);
+        let key = ("rewarder_incentives", rewarder);
+        let mut rewarder_incentives: Vec<u64> =
+            env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+        if let Some(index) = rewarder_incentives.first_index_of(incentive_id) {
+            rewarder_incentives.remove(index);
+            env.storage().instance().set(&key, &rewarder_incentives);
+        }
 
-        let mut results = soroban_sdk::Vec::new(&env);
-        let timestamp = env.ledger().timestamp();
+        let mut waste_types = incentive.accepted_types.clone();
+        if waste_types.is_empty() {
+            waste_types.push_back(incentive.waste_type);
+        }
+        for waste_type in waste_types.iter() {
+            let key = ("general_incentives", waste_type);
+            let mut general_incentives: Vec<u64> =
+                env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+            if let Some(index) = general_incentives.first_index_of(incentive_id) {
+                general_incentives.remove(index);
+                env.storage().instance().set(&key, &general_incentives);
+            }
+        }
+    }
 
-        // Get or create stats once
-        let mut stats: RecyclingStats = env
+    /// Report how much of an incentive's budget has been consumed
+    /// Returns (spent, total_budget, percent_spent); a zero budget reports 0%
+    pub fn get_incentive_utilization(env: Env, incentive_id: u64) -> (u64, u64, u32) {
+        let incentive = Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
+
+        let withdrawn: u64 = env
             .storage()
             .instance()
-            .get(&("stats", submitter.clone()))
-            .unwrap_or_else(|| RecyclingStats::new(submitter.clone()));
-
-        let mut total_weight: u64 = 0;
+            .get(&("incentive_withdrawn", incentive_id))
+            .unwrap_or(0);
+        let spent = incentive.total_budget - incentive.remaining_budget - withdrawn;
+        let percent_spent = if incentive.total_budget == 0 {
+            0
+        } else {
+            (spent as u128 * 100 / incentive.total_budget as u128) as u32
+        };
 
-        // Process each material
-        for item in materials.iter() {
-            let (waste_type, weight, description) = item;
-            let waste_id = Self::next_waste_id(&env);
+        (spent, incentive.total_budget, percent_spent)
+    }
 
-            let material = Material::new(
-                waste_id,
-                waste_type,
-                weight,
-                submitter.clone(),
-                timestamp,
-                description,
-            );
+    /// Get the incentives a verified material could currently be claimed against
+    /// Returns active incentives of the material's waste type with budget left whose weight
+    /// band and region admit the material, excluding incentives this material has already
+    /// claimed or another claimer holds a live reservation on.
+    /// As on claim, a tiered reward larger than the budget left pays what remains
+    pub fn get_claimable_incentives(env: Env, material_id: u64) -> Vec<Incentive> {
+        let mut claimable = Vec::new(&env);
 
-            Self::set_waste(&env, waste_id, &material);
-            stats.record_submission(&material);
-            results.push_back(material);
-            
-            // Accumulate weight with overflow check
-            total_weight = total_weight.checked_add(weight).expect("Overflow in batch weight");
+        let Some(material) = Self::get_waste_internal(&env, material_id) else {
+            return claimable;
+        };
+        if !material.verified {
+            return claimable;
         }
 
-        // Update stats once at the end
-        env.storage().instance().set(&("stats", submitter.clone()), &stats);
+        let incentive_ids: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&("general_incentives", material.waste_type))
+            .unwrap_or(Vec::new(&env));
 
-        // Update participant stats
-        Self::update_participant_stats(&env, &submitter, total_weight, 0);
+        for incentive_id in incentive_ids.iter() {
+            let Some(incentive) = Self::get_incentive_internal(&env, incentive_id) else {
+                continue;
+            };
 
-        results
-    }
+            if !incentive.is_weight_in_band(material.weight)
+                || !Self::is_claim_in_region(&env, &incentive, material_id, &material.submitter)
+                || env
+                    .storage()
+                    .instance()
+                    .has(&("claimed", incentive_id, material_id))
+            {
+                continue;
+            }
 
-    /// Get material by ID (alias for backward compatibility)
-    pub fn get_material(env: Env, material_id: u64) -> Option<Material> {
-        Self::get_waste(env, material_id)
-    }
+            // Budget held by expired reservations is free again, as it is on claim
+            let mut available = incentive.clone();
+            Self::return_expired_reservations(&env, &mut available);
+            if let Some((holder, _, expires_at)) =
+                Self::get_reservation(env.clone(), incentive_id, material_id)
+            {
+                if env.ledger().timestamp() < expires_at {
+                    if holder == material.submitter {
+                        claimable.push_back(incentive);
+                    }
+                    continue;
+                }
+            }
 
-    /// Get waste by ID (alias for backward compatibility)
-    pub fn get_waste_by_id(env: Env, waste_id: u64) -> Option<Material> {
-        Self::get_waste(env, waste_id)
-    }
+            let rewardable = available.active
+                && available.remaining_for(material.waste_type) > 0
+                && Self::calculate_incentive_tiered_reward(
+                    &env,
+                    incentive_id,
+                    &incentive,
+                    material.weight,
+                )
+                .is_some();
+            if rewardable {
+                claimable.push_back(incentive);
+            }
+        }
 
-    /// Get waste by ID (primary public interface)
-    /// Returns the waste/material record if it exists, None otherwise
-    pub fn get_waste(env: Env, waste_id: u64) -> Option<Material> {
-        let key = ("waste", waste_id);
-        env.storage().instance().get(&key)
+        claimable
     }
 
-    /// Get all waste IDs owned by a participant
-    /// Returns a vector of waste IDs where the participant is the current submitter/owner
-    pub fn get_participant_wastes(env: Env, participant: Address) -> Vec<u64> {
-        let mut waste_ids = Vec::new(&env);
-        let waste_count = env.storage()
-            .instance()
-            .get::<_, u64>(&("waste_count",))
-            .unwrap_or(0);
+    /// Match a participant's owned materials to the incentives they could claim
+    /// Returns (waste_id, incentive_id) pairs for every verified material in the
+    /// participant's index and each active, funded incentive of its waste type
+    pub fn get_matching_incentives(env: Env, address: Address) -> Vec<(u64, u64)> {
+        let mut matches = Vec::new(&env);
 
-        // Iterate through all wastes and collect IDs owned by participant
-        for waste_id in 1..=waste_count {
-            let key = ("waste", waste_id);
-            if let Some(material) = env.storage().instance().get::<_, Material>(&key) {
-                if material.submitter == participant {
-                    waste_ids.push_back(waste_id);
-                }
+        for waste_id in Self::get_material_index(&env, &address).iter() {
+            for incentive in Self::get_claimable_incentives(env.clone(), waste_id).iter() {
+                matches.push_back((waste_id, incentive.id));
             }
         }
 
-        waste_ids
+        matches
+    }
+
+    /// Preview what claiming an incentive for a material would pay
+    /// A reward larger than the remaining budget shows the capped amount the claim pays.
+    /// Returns 0 if the pair is not claimable; never mutates state
+    pub fn preview_claim(env: Env, incentive_id: u64, material_id: u64) -> u64 {
+        let incentive = match Self::get_incentive_internal(&env, incentive_id) {
+            Some(incentive) => incentive,
+            None => return 0,
+        };
+        let material = match Self::get_waste_internal(&env, material_id) {
+            Some(material) => material,
+            None => return 0,
+        };
+
+        if !incentive.active
+            || !material.verified
+            || !incentive.accepts(material.waste_type)
+            || !incentive.is_weight_in_band(material.weight)
+            || !Self::is_claim_in_region(&env, &incentive, material_id, &material.submitter)
+            || env.storage().instance().has(&("claimed", incentive_id, material_id))
+        {
+            return 0;
+        }
+
+        Self::claimable_amount(&env, incentive_id, &incentive, &material)
     }
 
-    /// Get multiple wastes by IDs (batch retrieval)
-    pub fn get_wastes_batch(
-        env: Env,
-        waste_ids: soroban_sdk::Vec<u64>,
-    ) -> soroban_sdk::Vec<Option<Material>> {
-        let mut results = soroban_sdk::Vec::new(&env);
+    // ========== Incentive Reservation Functions ==========
 
-        for waste_id in waste_ids.iter() {
-            results.push_back(Self::get_waste_internal(&env, waste_id));
+    /// Set how long an incentive reservation holds its budget, in seconds (admin only)
+    pub fn set_reservation_ttl(env: Env, admin: Address, seconds: u64) {
+        Self::require_admin(&env, &admin);
+
+        if seconds == 0 {
+            panic!("TTL must be greater than zero");
         }
 
-        results
+        let old_value = Self::get_reservation_ttl(env.clone());
+        env.storage().instance().set(&("reservation_ttl",), &seconds);
+        Self::log_config_change(
+            &env,
+            &admin,
+            "reserve_ttl",
+            ConfigValue::Number(old_value),
+            ConfigValue::Number(seconds),
+        );
     }
 
-    /// Verify a material submission (only recyclers can verify)
-    pub fn verify_material(env: Env, material_id: u64, verifier: Address) -> Material {
-        verifier.require_auth();
+    /// Get the reservation lifetime in seconds (defaults to 300)
+    pub fn get_reservation_ttl(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("reservation_ttl",))
+            .unwrap_or(DEFAULT_RESERVATION_TTL)
+    }
 
-        // Check if verifier is a recycler and is registered
-        let verifier_key = (verifier.clone(),);
-        let participant: Participant = env
+    /// Lock the reward for a verified material against an incentive's budget
+    /// A later `claim_incentive_reward` by the same claimer pays the reserved amount even if
+    /// the budget has since run out; unclaimed reservations free their budget after the TTL.
+    /// Returns the reserved reward
+    pub fn reserve_incentive(
+        env: Env,
+        incentive_id: u64,
+        material_id: u64,
+        claimer: Address,
+    ) -> u64 {
+        claimer.require_auth();
+        Self::require_not_paused(&env);
+
+        let mut incentive =
+            Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
+        let material = Self::get_waste_internal(&env, material_id).expect("Material not found");
+
+        if !material.verified {
+            panic!("Material not verified");
+        }
+        if material.submitter != claimer {
+            panic!("Only material owner can reserve");
+        }
+        if !incentive.accepts(material.waste_type) {
+            panic!("Waste type mismatch");
+        }
+        if !incentive.is_weight_in_band(material.weight) {
+            panic!("Weight outside incentive band");
+        }
+        if !Self::is_claim_in_region(&env, &incentive, material_id, &claimer) {
+            panic!("Outside incentive region");
+        }
+        if env
             .storage()
             .instance()
-            .get(&verifier_key)
-            .expect("Verifier not registered");
-
-        if !participant.is_registered {
-            panic!("Verifier is not registered");
+            .has(&("claimed", incentive_id, material_id))
+        {
+            panic!("Reward already claimed");
         }
 
-        if !participant.role.can_process_recyclables() {
-            panic!("Only recyclers can verify materials");
-        }
+        Self::release_expired_reservations(&env, &mut incentive);
 
-        // Get and verify material using new storage system
-        let mut material: Material =
-            Self::get_waste_internal(&env, material_id).expect("Material not found");
+        let key = ("reservation", incentive_id, material_id);
+        if env.storage().instance().has(&key) {
+            panic!("Reservation already exists");
+        }
+        if !incentive.active {
+            panic!("Incentive is not active");
+        }
 
-        material.verify();
-        Self::set_waste(&env, material_id, &material);
+        if incentive.remaining_for(material.waste_type) == 0 {
+            panic!("Insufficient incentive budget");
+        }
 
-        // Calculate tokens earned
-        let tokens_earned = material.calculate_reward_points();
+        let reward =
+            Self::calculate_incentive_tiered_reward(&env, incentive_id, &incentive, material.weight)
+                .expect("Reward calculation overflow")
+                .min(incentive.remaining_for(material.waste_type));
+        let reward = incentive
+            .claim_amount(material.waste_type, reward)
+            .expect("Insufficient incentive budget");
+        Self::set_incentive(&env, incentive_id, &incentive);
 
-        // Update submitter stats
-        let mut stats: RecyclingStats = env
-            .storage()
+        let expires_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(Self::get_reservation_ttl(env.clone()));
+        env.storage()
             .instance()
-            .get(&("stats", material.submitter.clone()))
-            .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
+            .set(&key, &(claimer, reward, expires_at));
 
-        stats.record_verification(&material);
+        let mut reserved = Self::get_reserved_materials(&env, incentive_id);
+        reserved.push_back(material_id);
         env.storage()
             .instance()
-            .set(&("stats", material.submitter.clone()), &stats);
+            .set(&("reservations", incentive_id), &reserved);
 
-        // Update submitter's participant stats with tokens earned
-        Self::update_participant_stats(&env, &material.submitter, 0, tokens_earned);
+        if !incentive.active {
+            events::emit_incentive_exhausted(&env, incentive_id);
+        }
 
-        material
+        reward
     }
 
-    /// Batch verify multiple materials
-    pub fn verify_materials_batch(
+    /// Get a reservation as (claimer, reward, expires_at), if one is held
+    /// Expired reservations are reported until a claim or reservation releases them
+    pub fn get_reservation(
         env: Env,
-        material_ids: soroban_sdk::Vec<u64>,
-        verifier: Address,
-    ) -> soroban_sdk::Vec<Material> {
-        verifier.require_auth();
+        incentive_id: u64,
+        material_id: u64,
+    ) -> Option<(Address, u64, u64)> {
+        env.storage()
+            .instance()
+            .get(&("reservation", incentive_id, material_id))
+    }
 
-        // Check if verifier is a recycler and is registered
-        let verifier_key = (verifier.clone(),);
-        let participant: Participant = env
-            .storage()
+    /// Get the material IDs holding a reservation on an incentive
+    fn get_reserved_materials(env: &Env, incentive_id: u64) -> Vec<u64> {
+        env.storage()
             .instance()
-            .get(&verifier_key)
-            .expect("Verifier not registered");
+            .get(&("reservations", incentive_id))
+            .unwrap_or(Vec::new(env))
+    }
 
-        if !participant.is_registered {
-            panic!("Verifier is not registered");
+    /// Drop a reservation and remove it from the incentive's reservation list
+    fn remove_reservation(env: &Env, incentive_id: u64, material_id: u64) {
+        env.storage()
+            .instance()
+            .remove(&("reservation", incentive_id, material_id));
+
+        let mut reserved = Self::get_reserved_materials(env, incentive_id);
+        if let Some(index) = reserved.first_index_of(material_id) {
+            reserved.remove(index);
+            env.storage()
+                .instance()
+                .set(&("reservations", incentive_id), &reserved);
         }
+    }
 
-        if !participant.role.can_process_recyclables() {
-            panic!("Only recyclers can verify materials");
+    /// Return the budget of every expired reservation to the incentive
+    /// The caller is responsible for persisting the incentive
+    fn release_expired_reservations(env: &Env, incentive: &mut Incentive) {
+        for material_id in Self::return_expired_reservations(env, incentive).iter() {
+            Self::remove_reservation(env, incentive.id, material_id);
         }
+    }
 
-        let mut results = soroban_sdk::Vec::new(&env);
+    /// Return the budget held by expired reservations to the in-memory incentive
+    /// Returns the expired material IDs without touching storage
+    fn return_expired_reservations(env: &Env, incentive: &mut Incentive) -> Vec<u64> {
+        let now = env.ledger().timestamp();
+        let mut expired = Vec::new(env);
+
+        for material_id in Self::get_reserved_materials(env, incentive.id).iter() {
+            let Some((_, reward, expires_at)) =
+                Self::get_reservation(env.clone(), incentive.id, material_id)
+            else {
+                continue;
+            };
+            if now < expires_at {
+                continue;
+            }
 
-        for material_id in material_ids.iter() {
-            if let Some(mut material) = Self::get_waste_internal(&env, material_id) {
-                material.verify();
-                Self::set_waste(&env, material_id, &material);
+            let waste_type = Self::get_waste_internal(env, material_id)
+                .map(|material| material.waste_type)
+                .unwrap_or(incentive.waste_type);
+            incentive.release_amount(waste_type, reward);
+            expired.push_back(material_id);
+        }
 
-                // Calculate tokens earned
-                let tokens_earned = material.calculate_reward_points();
+        expired
+    }
 
-                // Update submitter stats
-                let mut stats: RecyclingStats = env
-                    .storage()
-                    .instance()
-                    .get(&("stats", material.submitter.clone()))
-                    .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
+    /// Explain whether `claim_incentive_reward` would succeed, without changing state
+    /// Returns `ok` or the first failing reason: `paused`, `not_found`, `unverified`,
+    /// `not_owner`, `type_mismatch`, `out_of_band`, `outside_region`, `already_claimed`,
+    /// `reserved`, `inactive` or `insufficient_budget`
+    pub fn check_claim_eligibility(
+        env: Env,
+        incentive_id: u64,
+        material_id: u64,
+        claimer: Address,
+    ) -> Symbol {
+        if Self::is_paused(env.clone()) {
+            return symbol_short!("paused");
+        }
 
-                stats.record_verification(&material);
-                env.storage()
-                    .instance()
-                    .set(&("stats", material.submitter.clone()), &stats);
+        let (Some(mut incentive), Some(material)) = (
+            Self::get_incentive_internal(&env, incentive_id),
+            Self::get_waste_internal(&env, material_id),
+        ) else {
+            return symbol_short!("not_found");
+        };
 
-                // Update submitter's participant stats with tokens earned
-                Self::update_participant_stats(&env, &material.submitter, 0, tokens_earned);
+        if !material.verified {
+            return Symbol::new(&env, "unverified");
+        }
+        if material.submitter != claimer {
+            return symbol_short!("not_owner");
+        }
+        if !incentive.accepts(material.waste_type) {
+            return Symbol::new(&env, "type_mismatch");
+        }
+        if !incentive.is_weight_in_band(material.weight) {
+            return Symbol::new(&env, "out_of_band");
+        }
+        if !Self::is_claim_in_region(&env, &incentive, material_id, &claimer) {
+            return Symbol::new(&env, "outside_region");
+        }
+        if env
+            .storage()
+            .instance()
+            .has(&("claimed", incentive_id, material_id))
+        {
+            return Symbol::new(&env, "already_claimed");
+        }
 
-                results.push_back(material);
+        Self::return_expired_reservations(&env, &mut incentive);
+        if let Some((holder, _, expires_at)) =
+            Self::get_reservation(env.clone(), incentive_id, material_id)
+        {
+            if env.ledger().timestamp() < expires_at {
+                if holder != claimer {
+                    return symbol_short!("reserved");
+                }
+                return symbol_short!("ok");
             }
         }
 
-        results
-    }
+        if !incentive.active {
+            return symbol_short!("inactive");
+        }
 
-    /// Get recycling statistics for a participant
-    pub fn get_stats(env: Env, participant: Address) -> Option<RecyclingStats> {
-        env.storage().instance().get(&("stats", participant))
-    }
+        if incentive.remaining_for(material.waste_type) == 0 {
+            return Symbol::new(&env, "insufficient_budget");
+        }
 
-    /// Get supply chain statistics (total wastes, total weight, total tokens earned)
-    /// Returns a tuple of (total_wastes_count, total_weight_grams, total_tokens_earned)
-    pub fn get_supply_chain_stats(env: Env) -> (u64, u64, u128) {
-        let total_wastes = Self::get_waste_count(&env);
-        let total_weight = Self::get_total_active_waste_weight(&env);
-        let total_tokens = Self::get_total_tokens(&env);
-        
-        (total_wastes, total_weight, total_tokens)
+        symbol_short!("ok")
     }
 
-    /// Get the active incentive with the highest reward for a specific manufacturer and waste type
-    /// Returns None if no active incentive is found
-    pub fn get_active_incentive_for_manufacturer(
+    /// Claim an incentive reward for a verified material
+    /// Only the material submitter can claim, and the waste types must match
+    /// A reward larger than the budget left for the waste type pays what remains
+    pub fn claim_incentive_reward(
         env: Env,
-        manufacturer: Address,
-        waste_type: WasteType,
-    ) -> Option<Incentive> {
-        // Get all incentives for this manufacturer
-        let incentive_ids = Self::get_incentives_by_rewarder(env.clone(), manufacturer.clone());
-        
-        let mut best_incentive: Option<Incentive> = None;
-        let mut highest_reward: u64 = 0;
-        
-        // Iterate through all incentives and find the best active one
-        for incentive_id in incentive_ids.iter() {
-            if let Some(incentive) = Self::get_incentive_internal(&env, incentive_id) {
-                // Check if incentive matches criteria: active and correct waste type
-                if incentive.active && incentive.waste_type == waste_type {
-                    // Keep track of the incentive with highest reward
-                    if incentive.reward_points > highest_reward {
-                        highest_reward = incentive.reward_points;
-                        best_incentive = Some(incentive);
-                    }
-                }
-            }
+        incentive_id: u64,
+        material_id: u64,
+        claimer: Address,
+    ) -> Result<u64, ScavengerError> {
+        claimer.require_auth();
+        Self::require_not_paused(&env);
+
+        let mut incentive = Self::get_incentive_internal(&env, incentive_id)
+            .ok_or(ScavengerError::IncentiveNotFound)?;
+        let material =
+            Self::get_waste_internal(&env, material_id).ok_or(ScavengerError::WasteNotFound)?;
+
+        if !material.verified {
+            return Err(ScavengerError::NotVerified);
         }
-        
-        best_incentive
-    }
 
-    /// Create a new incentive
-    pub fn create_incentive(
-        env: Env,
-        rewarder: Address,
-        waste_type: WasteType,
-        reward_points: u64,
-        total_budget: u64,
-    ) -> Incentive {
-        rewarder.require_auth();
+        if material.submitter != claimer {
+            return Err(ScavengerError::Unauthorized);
+        }
 
-        // Verify rewarder is a manufacturer
-        if !Self::is_participant_registered(env.clone(), rewarder.clone()) {
-            panic!("Rewarder not registered");
+        if !incentive.accepts(material.waste_type) {
+            return Err(ScavengerError::WasteTypeMismatch);
         }
 
-        let participant =
-            Self::get_participant(env.clone(), rewarder.clone()).expect("Rewarder not found");
+        if !incentive.is_weight_in_band(material.weight) {
+            panic!("Weight outside incentive band");
+        }
 
-        if !participant.role.can_manufacture() {
-            panic!("Only manufacturers can create incentives");
+        if !Self::is_claim_in_region(&env, &incentive, material_id, &claimer) {
+            panic!("Outside incentive region");
         }
 
-        // Get next incentive ID
-        let incentive_id = Self::next_incentive_id(&env);
+        let claimed_key = ("claimed", incentive_id, material_id);
+        if env.storage().instance().has(&claimed_key) {
+            return Err(ScavengerError::AlreadyClaimed);
+        }
 
-        // Create incentive
-        let incentive = Incentive::new(
+        Self::release_expired_reservations(&env, &mut incentive);
+        let was_active = incentive.active;
+
+        // A live reservation already holds its reward out of the budget
+        let reward = match Self::get_reservation(env.clone(), incentive_id, material_id) {
+            Some((holder, reserved, _)) => {
+                if holder != claimer {
+                    return Err(ScavengerError::Unauthorized);
+                }
+                Self::remove_reservation(&env, incentive_id, material_id);
+                reserved
+            }
+            None => {
+                if !incentive.active {
+                    return Err(ScavengerError::IncentiveInactive);
+                }
+
+                if incentive.remaining_for(material.waste_type) == 0 {
+                    return Err(ScavengerError::InsufficientBudget);
+                }
+
+                // A reward larger than the budget left pays what remains
+                let reward = Self::calculate_incentive_tiered_reward(
+                    &env,
+                    incentive_id,
+                    &incentive,
+                    material.weight,
+                )
+                .expect("Reward calculation overflow")
+                .min(incentive.remaining_for(material.waste_type));
+                incentive
+                    .claim_amount(material.waste_type, reward)
+                    .ok_or(ScavengerError::InsufficientBudget)?
+            }
+        };
+        Self::set_incentive(&env, incentive_id, &incentive);
+        env.storage().instance().set(&claimed_key, &true);
+
+        let mut material_claims = Self::get_claims_for_material(env.clone(), material_id);
+        material_claims.push_back(incentive_id);
+        env.storage()
+            .instance()
+            .set(&("material_claims", material_id), &material_claims);
+
+        // Hold the reward until the claimer withdraws it; stats are credited on withdrawal
+        let escrow_balance = Self::get_escrow_balance(env.clone(), claimer.clone())
+            .checked_add(reward)
+            .ok_or(ScavengerError::Overflow)?;
+        env.storage()
+            .instance()
+            .set(&("escrow", claimer.clone()), &escrow_balance);
+
+        let mut history = Self::get_incentive_claim_history(env.clone(), incentive_id);
+        history.push_back((claimer.clone(), material_id, reward, env.ledger().timestamp()));
+        env.storage()
+            .instance()
+            .set(&("incentive_claims", incentive_id), &history);
+
+        events::emit_claimed(
+            &env,
             incentive_id,
-            rewarder.clone(),
-            waste_type,
-            reward_points,
-            total_budget,
-            env.ledger().timestamp(),
+            &claimer,
+            material_id,
+            reward,
+            incentive.remaining_budget,
         );
+        if was_active && !incentive.active {
+            events::emit_incentive_exhausted(&env, incentive_id);
+        }
 
-        // Store incentive
-        Self::set_incentive(&env, incentive_id, &incentive);
+        Ok(reward)
+    }
 
-        // Add to rewarder's incentive list
-        let key = ("rewarder_incentives", rewarder.clone());
-        let mut rewarder_incentives: Vec<u64> =
-            env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
-        rewarder_incentives.push_back(incentive_id);
-        env.storage().instance().set(&key, &rewarder_incentives);
+    /// Get every successful claim against an incentive, oldest first
+    /// Each entry is (claimer, material_id, reward, timestamp)
+    pub fn get_incentive_claim_history(
+        env: Env,
+        incentive_id: u64,
+    ) -> Vec<(Address, u64, u64, u64)> {
+        env.storage()
+            .instance()
+            .get(&("incentive_claims", incentive_id))
+            .unwrap_or(Vec::new(&env))
+    }
 
-        // Add to general incentives list for this waste type
-        let key = ("general_incentives", waste_type);
-        let mut general_incentives: Vec<u64> =
-            env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
-        general_incentives.push_back(incentive_id);
-        env.storage().instance().set(&key, &general_incentives);
+    /// Get the IDs of incentives a material has been claimed against, oldest first
+    pub fn get_claims_for_material(env: Env, material_id: u64) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&("material_claims", material_id))
+            .unwrap_or(Vec::new(&env))
+    }
 
-        incentive
+    /// Get a page of an incentive's claim history
+    /// Returns at most `limit` entries (capped at MAX_PAGE_SIZE) starting at `offset`
+    pub fn get_incentive_claims_paged(
+        env: Env,
+        incentive_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(Address, u64, u64, u64)> {
+        let history = Self::get_incentive_claim_history(env.clone(), incentive_id);
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut page = Vec::new(&env);
+
+        if offset >= history.len() {
+            return page;
+        }
+
+        let end = offset.saturating_add(limit).min(history.len());
+        for i in offset..end {
+            page.push_back(history.get(i).unwrap());
+        }
+
+        page
     }
 
-    /// Deactivate an incentive (only by creator)
-    pub fn deactivate_incentive(env: Env, incentive_id: u64, rewarder: Address) -> Incentive {
-        rewarder.require_auth();
+    /// Get the claimed rewards a participant has not withdrawn yet
+    pub fn get_escrow_balance(env: Env, address: Address) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("escrow", address))
+            .unwrap_or(0)
+    }
 
-        let mut incentive = Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
+    /// Get a participant's token balances in one call
+    /// Returns (total_tokens_earned, stats total_points, escrow_balance), zero when missing
+    pub fn get_token_summary(env: Env, address: Address) -> (u128, u64, u64) {
+        let tokens_earned = Self::get_participant(env.clone(), address.clone())
+            .map(|participant| participant.total_tokens_earned)
+            .unwrap_or(0);
+        let points = Self::get_stats(env.clone(), address.clone())
+            .map(|stats| stats.total_points)
+            .unwrap_or(0);
+        let escrow = Self::get_escrow_balance(env, address);
 
-        // Verify caller is the creator
-        if incentive.rewarder != rewarder {
-            panic!("Only incentive creator can deactivate");
+        (tokens_earned, points, escrow)
+    }
+
+    /// Withdraw all escrowed rewards, returning the amount released
+    /// The released amount is credited to the participant's points and tokens earned
+    pub fn withdraw_escrow(env: Env, address: Address) -> u64 {
+        address.require_auth();
+        Self::require_not_paused(&env);
+
+        let amount = Self::get_escrow_balance(env.clone(), address.clone());
+        if amount == 0 {
+            panic!("No escrowed rewards");
         }
 
-        incentive.deactivate();
-        Self::set_incentive(&env, incentive_id, &incentive);
+        let mut stats: RecyclingStats = env
+            .storage()
+            .instance()
+            .get(&("stats", address.clone()))
+            .unwrap_or_else(|| RecyclingStats::new(address.clone()));
+        stats.total_points = stats
+            .total_points
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, ScavengerError::Overflow));
+        env.storage().instance().set(&("stats", address.clone()), &stats);
 
-        incentive
+        Self::update_participant_stats(&env, &address, 0, amount);
+
+        env.storage().instance().remove(&("escrow", address.clone()));
+        events::emit_escrow_withdrawn(&env, &address, amount);
+
+        amount
     }
 
 }
@@ -1,5 +1,8 @@
 
-use soroban_sdk::{contracttype, Address, String, Symbol};
+use soroban_sdk::{contracttype, Address, String, Symbol, Vec};
+
+/// Grams per reward unit used when no scale factor has been configured (one kilogram)
+pub const DEFAULT_SCALE_FACTOR: u64 = 1000;
 
 /// Represents a transfer record in the recycling system
 /// This struct is fully compatible with Soroban storage and implements
@@ -60,7 +63,7 @@ pub enum TransferStatus {
 impl TransferItemType {
     /// Validates if the value is a valid TransferItemType variant
     pub fn is_valid(value: u32) -> bool {
-        matches!(value, 0 | 1 | 2 | 3)
+        matches!(value, 0..=3)
     }
 
     /// Converts a u32 to a TransferItemType
@@ -93,7 +96,7 @@ impl TransferItemType {
 impl TransferStatus {
     /// Validates if the value is a valid TransferStatus variant
     pub fn is_valid(value: u32) -> bool {
-        matches!(value, 0 | 1 | 2 | 3 | 4)
+        matches!(value, 0..=4)
     }
 
     /// Converts a u32 to a TransferStatus
@@ -140,6 +143,7 @@ impl TransferStatus {
 
 impl TransferRecord {
     /// Creates a new TransferRecord with Pending status
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: u64,
         from: Address,
@@ -213,13 +217,29 @@ pub struct Incentive {
     pub remaining_budget: u64,
     /// Whether the incentive is currently active
     pub active: bool,
+    /// Whether the incentive was deactivated by exhausting its budget rather than by its rewarder
+    pub exhausted: bool,
     /// Timestamp when the incentive was created
     pub created_at: u64,
+    /// Smallest material weight (grams) the incentive rewards
+    pub min_weight: u64,
+    /// Largest material weight (grams) the incentive rewards
+    pub max_weight: u64,
+    /// Waste types of a multi-type incentive; empty for single-type incentives
+    pub accepted_types: Vec<WasteType>,
+    /// Remaining budget of each entry in `accepted_types`, in the same order
+    pub type_budgets: Vec<u64>,
+    /// Bounding box (scaled by 1e6) claims must fall within; all zero means unbounded
+    pub min_lat: i128,
+    pub max_lat: i128,
+    pub min_lon: i128,
+    pub max_lon: i128,
 }
 
 impl Incentive {
-    /// Creates a new Incentive instance
+    /// Creates a new single-type Incentive instance
     pub fn new(
+        env: &soroban_sdk::Env,
         id: u64,
         rewarder: Address,
         waste_type: WasteType,
@@ -235,51 +255,201 @@ impl Incentive {
             total_budget,
             remaining_budget: total_budget,
             active: true,
+            exhausted: false,
             created_at,
+            min_weight: 0,
+            max_weight: u64::MAX,
+            accepted_types: Vec::new(env),
+            type_budgets: Vec::new(env),
+            min_lat: 0,
+            max_lat: 0,
+            min_lon: 0,
+            max_lon: 0,
         }
     }
 
+    /// Checks if the incentive is limited to a region
+    pub fn is_geofenced(&self) -> bool {
+        self.min_lat != 0 || self.max_lat != 0 || self.min_lon != 0 || self.max_lon != 0
+    }
+
+    /// Checks if a location falls within the incentive's region (always true when unbounded)
+    pub fn contains_location(&self, latitude: i128, longitude: i128) -> bool {
+        !self.is_geofenced()
+            || ((self.min_lat..=self.max_lat).contains(&latitude)
+                && (self.min_lon..=self.max_lon).contains(&longitude))
+    }
+
+    /// Checks if the incentive rewards materials of the given waste type
+    pub fn accepts(&self, waste_type: WasteType) -> bool {
+        if self.accepted_types.is_empty() {
+            self.waste_type == waste_type
+        } else {
+            self.accepted_types.contains(waste_type)
+        }
+    }
+
+    /// Gets the budget left for the given waste type
+    /// Single-type incentives draw on their whole remaining budget
+    pub fn remaining_for(&self, waste_type: WasteType) -> u64 {
+        if !self.accepts(waste_type) {
+            return 0;
+        }
+        match self.accepted_types.first_index_of(waste_type) {
+            Some(index) => self.type_budgets.get_unchecked(index),
+            None => self.remaining_budget,
+        }
+    }
+
+    /// Checks if a material weight in grams falls within the incentive's weight band
+    pub fn is_weight_in_band(&self, weight_grams: u64) -> bool {
+        weight_grams >= self.min_weight && weight_grams <= self.max_weight
+    }
+
     /// Deactivates the incentive
+    /// A deliberate deactivation is never undone by budget being returned
     pub fn deactivate(&mut self) {
         self.active = false;
+        self.exhausted = false;
     }
 
-    /// Calculates reward for a given weight in grams
-    pub fn calculate_reward(&self, weight_grams: u64) -> u64 {
+    /// Reactivates the incentive
+    pub fn reactivate(&mut self) {
+        self.active = true;
+        self.exhausted = false;
+    }
+
+    /// Calculates reward for a given weight in grams at the default scale
+    /// Returns None if the multiplication overflows
+    pub fn calculate_reward(&self, weight_grams: u64) -> Option<u64> {
         // Convert grams to kg and multiply by reward points
-        (weight_grams / 1000) * self.reward_points
+        (weight_grams / DEFAULT_SCALE_FACTOR).checked_mul(self.reward_points)
+    }
+
+    /// Calculates reward for a given weight in grams at the default scale without
+    /// dropping partial kilograms
+    /// Multiplies before dividing so sub-kilogram weights earn proportional rewards
+    /// Returns None if the multiplication overflows
+    pub fn calculate_reward_precise(&self, weight_grams: u64) -> Option<u64> {
+        self.calculate_reward_scaled(weight_grams, DEFAULT_SCALE_FACTOR)
+    }
+
+    /// Calculates reward for a given weight in grams, paying `reward_points` for every
+    /// `scale_factor` grams (1000 rewards per kilogram, 100 per 100 grams)
+    /// Returns None if the multiplication overflows or the scale factor is zero
+    pub fn calculate_reward_scaled(&self, weight_grams: u64, scale_factor: u64) -> Option<u64> {
+        weight_grams
+            .checked_mul(self.reward_points)?
+            .checked_div(scale_factor)
     }
 
-    /// Attempts to claim a reward, returns the amount claimed
-    /// Returns None if insufficient budget
-    pub fn claim_reward(&mut self, weight_grams: u64) -> Option<u64> {
+    /// Attempts to claim a reward for a material of the given type at `scale_factor`,
+    /// returns the amount claimed
+    /// Returns None if insufficient budget or the reward calculation overflows
+    pub fn claim_reward(
+        &mut self,
+        waste_type: WasteType,
+        weight_grams: u64,
+        scale_factor: u64,
+    ) -> Option<u64> {
         if !self.active {
             return None;
         }
 
-        let reward = self.calculate_reward(weight_grams);
-        if reward > self.remaining_budget {
+        let reward = self.calculate_reward_scaled(weight_grams, scale_factor)?;
+        self.claim_amount(waste_type, reward)
+    }
+
+    /// Deducts an already calculated reward from the remaining budget, and from the
+    /// waste type's sub-budget for multi-type incentives
+    /// Returns None if the incentive is inactive or the budget can't cover it
+    pub fn claim_amount(&mut self, waste_type: WasteType, reward: u64) -> Option<u64> {
+        if !self.active || !self.accepts(waste_type) {
             return None;
         }
 
-        self.remaining_budget -= reward;
+        let remaining = self.remaining_budget.checked_sub(reward)?;
+        if let Some(index) = self.accepted_types.first_index_of(waste_type) {
+            let type_remaining = self.type_budgets.get_unchecked(index).checked_sub(reward)?;
+            self.type_budgets.set(index, type_remaining);
+        }
+        self.remaining_budget = remaining;
 
         // Auto-deactivate if budget exhausted
         if self.remaining_budget == 0 {
             self.active = false;
+            self.exhausted = true;
         }
 
         Some(reward)
     }
 
-    /// Checks if the incentive has sufficient budget for a reward
-    pub fn has_sufficient_budget(&self, weight_grams: u64) -> bool {
+    /// Returns a previously deducted reward to the remaining budget, and to the waste
+    /// type's sub-budget for multi-type incentives
+    /// An incentive auto-deactivated by exhausting its budget is reactivated; one its
+    /// rewarder deactivated stays inactive
+    pub fn release_amount(&mut self, waste_type: WasteType, amount: u64) {
+        self.remaining_budget = self.remaining_budget.saturating_add(amount);
+        if let Some(index) = self.accepted_types.first_index_of(waste_type) {
+            let type_remaining = self.type_budgets.get_unchecked(index).saturating_add(amount);
+            self.type_budgets.set(index, type_remaining);
+        }
+
+        if self.exhausted && amount > 0 {
+            self.reactivate();
+        }
+    }
+
+    /// Calculates the reward for a given weight in grams at `scale_factor` plus a
+    /// `bonus_percent` share of it
+    /// Returns None if the calculation overflows or the scale factor is zero
+    pub fn calculate_reward_with_bonus(
+        &self,
+        weight_grams: u64,
+        scale_factor: u64,
+        bonus_percent: u32,
+    ) -> Option<u64> {
+        let base = self.calculate_reward_scaled(weight_grams, scale_factor)?;
+        let bonus = base.checked_mul(bonus_percent as u64)? / 100;
+        base.checked_add(bonus)
+    }
+
+    /// Calculates the reward (with `bonus_percent` added) for a given weight in grams at
+    /// `scale_factor`, capped at the budget left for the waste type
+    /// A reward too large to represent is capped like any other
+    pub fn best_claimable_amount(
+        &self,
+        waste_type: WasteType,
+        weight_grams: u64,
+        scale_factor: u64,
+        bonus_percent: u32,
+    ) -> u64 {
+        self.calculate_reward_with_bonus(weight_grams, scale_factor, bonus_percent)
+            .unwrap_or(u64::MAX)
+            .min(self.remaining_for(waste_type))
+    }
+
+    /// Checks if the incentive has sufficient budget for a reward on the waste type
+    /// at `scale_factor`
+    pub fn has_sufficient_budget(
+        &self,
+        waste_type: WasteType,
+        weight_grams: u64,
+        scale_factor: u64,
+    ) -> bool {
         if !self.active {
             return false;
         }
-        let reward = self.calculate_reward(weight_grams);
-        reward <= self.remaining_budget
+        match self.calculate_reward_scaled(weight_grams, scale_factor) {
+            Some(reward) => self.can_cover(waste_type, reward),
+            None => false,
+        }
+    }
 
+    /// Checks if the incentive is active and can pay an already calculated reward
+    /// for the waste type
+    pub fn can_cover(&self, waste_type: WasteType, reward: u64) -> bool {
+        self.active && reward <= self.remaining_for(waste_type)
     }
 }
 
@@ -407,6 +577,29 @@ impl WasteType {
     pub fn is_infinitely_recyclable(&self) -> bool {
         matches!(self, WasteType::Metal | WasteType::Glass)
     }
+
+    /// Returns the environmental impact weight of one submission
+    /// Infinitely recyclable types score 3, biodegradable types 1, all others 2
+    pub fn impact_weight(&self) -> u64 {
+        if self.is_infinitely_recyclable() {
+            3
+        } else if self.is_biodegradable() {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Returns the default reward multiplier applied per kilogram
+    pub fn default_reward_multiplier(&self) -> u32 {
+        match self {
+            WasteType::Paper => 1,
+            WasteType::PetPlastic => 3,
+            WasteType::Plastic => 2,
+            WasteType::Metal => 5,
+            WasteType::Glass => 2,
+        }
+    }
 }
 
 impl core::fmt::Display for WasteType {
@@ -415,6 +608,69 @@ impl core::fmt::Display for WasteType {
     }
 }
 
+/// Unit a caller used when reporting material weight
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WeightUnit {
+    /// Weight given in grams (the storage unit)
+    Grams = 0,
+    /// Weight given in kilograms
+    Kilograms = 1,
+}
+
+impl WeightUnit {
+    /// Converts an amount in this unit to grams
+    /// Returns None if the conversion overflows
+    pub fn to_grams(&self, amount: u64) -> Option<u64> {
+        match self {
+            WeightUnit::Grams => Some(amount),
+            WeightUnit::Kilograms => amount.checked_mul(1000),
+        }
+    }
+}
+
+/// Represents where a material is in its recycling lifecycle
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WasteStatus {
+    /// Material has been submitted but not yet picked up
+    Submitted = 0,
+    /// Material has been collected
+    Collected = 1,
+    /// Material has been processed by a recycler
+    Processed = 2,
+    /// Material has been fully recycled
+    Recycled = 3,
+    /// Material was rejected and leaves the lifecycle
+    Rejected = 4,
+}
+
+impl WasteStatus {
+    /// Returns the next status in the forward lifecycle, if any
+    pub fn next(&self) -> Option<Self> {
+        match self {
+            WasteStatus::Submitted => Some(WasteStatus::Collected),
+            WasteStatus::Collected => Some(WasteStatus::Processed),
+            WasteStatus::Processed => Some(WasteStatus::Recycled),
+            WasteStatus::Recycled | WasteStatus::Rejected => None,
+        }
+    }
+
+    /// Checks if the status is final (cannot be changed)
+    pub fn is_final(&self) -> bool {
+        matches!(self, WasteStatus::Recycled | WasteStatus::Rejected)
+    }
+
+    /// Checks if moving to `new_status` is a legal forward transition
+    /// Any non-final status may move to its successor or be rejected
+    pub fn can_transition_to(&self, new_status: WasteStatus) -> bool {
+        if self.is_final() {
+            return false;
+        }
+        new_status == WasteStatus::Rejected || self.next() == Some(new_status)
+    }
+}
+
 /// Represents a recyclable material submission in the system
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -433,6 +689,10 @@ pub struct Material {
     pub verified: bool,
     /// Optional description of the material
     pub description: String,
+    /// Whether the material has been retired (burned, destroyed, or otherwise consumed)
+    pub is_retired: bool,
+    /// Current lifecycle status of the material
+    pub status: WasteStatus,
 }
 
 impl Material {
@@ -453,6 +713,8 @@ impl Material {
             submitted_at,
             verified: false,
             description,
+            is_retired: false,
+            status: WasteStatus::Submitted,
         }
     }
 
@@ -461,6 +723,26 @@ impl Material {
         self.verified = true;
     }
 
+    /// Revokes a previous verification
+    pub fn unverify(&mut self) {
+        self.verified = false;
+    }
+
+    /// Retires the material so it can no longer be transferred or verified
+    pub fn retire(&mut self) {
+        self.is_retired = true;
+    }
+
+    /// Moves the material to a new lifecycle status
+    /// Returns true if updated, false if the transition is not allowed
+    pub fn transition_status(&mut self, new_status: WasteStatus) -> bool {
+        if !self.status.can_transition_to(new_status) {
+            return false;
+        }
+        self.status = new_status;
+        true
+    }
+
     /// Checks if the material meets minimum weight requirement (100g)
     pub fn meets_minimum_weight(&self) -> bool {
         self.weight >= 100
@@ -468,17 +750,18 @@ impl Material {
 
     /// Calculates reward points based on waste type and weight
     /// Different waste types have different point multipliers
-    pub fn calculate_reward_points(&self) -> u64 {
-        let multiplier = match self.waste_type {
-            WasteType::Paper => 1,
-            WasteType::PetPlastic => 3,
-            WasteType::Plastic => 2,
-            WasteType::Metal => 5,
-            WasteType::Glass => 2,
-        };
+    /// Returns None if the multiplication overflows
+    pub fn calculate_reward_points(&self) -> Option<u64> {
+        self.calculate_reward_points_with(self.waste_type.default_reward_multiplier())
+    }
 
+    /// Calculates reward points using the given per-kilogram multiplier
+    /// Returns None if the multiplication overflows
+    pub fn calculate_reward_points_with(&self, multiplier: u32) -> Option<u64> {
         // Points = (weight in kg) * multiplier * 10
-        (self.weight / 1000) * multiplier * 10
+        (self.weight / 1000)
+            .checked_mul(multiplier as u64)?
+            .checked_mul(10)
     }
 }
 
@@ -507,10 +790,13 @@ pub struct Waste {
     pub is_confirmed: bool,
     /// Address of the confirmer/verifier
     pub confirmer: Address,
+    /// Reason code given when the waste was rejected by a verifier
+    pub rejection_reason: Option<Symbol>,
 }
 
 impl Waste {
     /// Creates a new Waste instance with all fields
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         waste_id: u128,
         waste_type: WasteType,
@@ -534,6 +820,7 @@ impl Waste {
             is_active,
             is_confirmed,
             confirmer,
+            rejection_reason: None,
         }
     }
 
@@ -580,6 +867,18 @@ impl Waste {
         self.is_active = false;
     }
 
+    /// Rejects the waste with the given reason code and deactivates it
+    pub fn reject(&mut self, confirmer: Address, reason: Symbol) {
+        self.confirmer = confirmer;
+        self.rejection_reason = Some(reason);
+        self.is_active = false;
+    }
+
+    /// Checks if the waste has been rejected
+    pub fn is_rejected(&self) -> bool {
+        self.rejection_reason.is_some()
+    }
+
     /// Transfers ownership to a new owner
     pub fn transfer_to(&mut self, new_owner: Address) {
         self.current_owner = new_owner;
@@ -602,7 +901,7 @@ pub struct WasteTransfer {
     pub transferred_at: u64,
     pub latitude: i128,
     pub longitude: i128,
-    pub note: Symbol,
+    pub note: String,
 }
 
 impl WasteTransfer {
@@ -613,7 +912,7 @@ impl WasteTransfer {
         transferred_at: u64,
         latitude: i128,
         longitude: i128,
-        note: Symbol,
+        note: String,
     ) -> Self {
         Self {
             waste_id,
@@ -710,6 +1009,7 @@ impl WasteBuilder {
             is_active: self.is_active,
             is_confirmed: self.is_confirmed,
             confirmer,
+            rejection_reason: None,
         }
     }
 }
@@ -735,6 +1035,14 @@ pub struct RecyclingStats {
     pub plastic_count: u64,
     pub metal_count: u64,
     pub glass_count: u64,
+    /// Weight of materials in grams by waste type
+    pub paper_weight: u64,
+    pub pet_plastic_weight: u64,
+    pub plastic_weight: u64,
+    pub metal_weight: u64,
+    pub glass_weight: u64,
+    /// Total number of materials rejected by a verifier
+    pub rejected_submissions: u64,
 }
 
 impl RecyclingStats {
@@ -751,6 +1059,12 @@ impl RecyclingStats {
             plastic_count: 0,
             metal_count: 0,
             glass_count: 0,
+            paper_weight: 0,
+            pet_plastic_weight: 0,
+            plastic_weight: 0,
+            metal_weight: 0,
+            glass_weight: 0,
+            rejected_submissions: 0,
         }
     }
 
@@ -759,31 +1073,140 @@ impl RecyclingStats {
         self.total_submissions += 1;
         self.total_weight += material.weight;
 
-        // Update waste type count
+        // Update waste type count and weight
         match material.waste_type {
-            WasteType::Paper => self.paper_count += 1,
-            WasteType::PetPlastic => self.pet_plastic_count += 1,
-            WasteType::Plastic => self.plastic_count += 1,
-            WasteType::Metal => self.metal_count += 1,
-            WasteType::Glass => self.glass_count += 1,
+            WasteType::Paper => {
+                self.paper_count += 1;
+                self.paper_weight += material.weight;
+            }
+            WasteType::PetPlastic => {
+                self.pet_plastic_count += 1;
+                self.pet_plastic_weight += material.weight;
+            }
+            WasteType::Plastic => {
+                self.plastic_count += 1;
+                self.plastic_weight += material.weight;
+            }
+            WasteType::Metal => {
+                self.metal_count += 1;
+                self.metal_weight += material.weight;
+            }
+            WasteType::Glass => {
+                self.glass_count += 1;
+                self.glass_weight += material.weight;
+            }
+        }
+    }
+
+    /// Gets the number of materials submitted for a waste type
+    pub fn count_by_type(&self, waste_type: WasteType) -> u64 {
+        match waste_type {
+            WasteType::Paper => self.paper_count,
+            WasteType::PetPlastic => self.pet_plastic_count,
+            WasteType::Plastic => self.plastic_count,
+            WasteType::Metal => self.metal_count,
+            WasteType::Glass => self.glass_count,
+        }
+    }
+
+    /// Gets the total weight in grams submitted for a waste type
+    pub fn weight_by_type(&self, waste_type: WasteType) -> u64 {
+        match waste_type {
+            WasteType::Paper => self.paper_weight,
+            WasteType::PetPlastic => self.pet_plastic_weight,
+            WasteType::Plastic => self.plastic_weight,
+            WasteType::Metal => self.metal_weight,
+            WasteType::Glass => self.glass_weight,
         }
     }
 
     /// Records a material verification
     pub fn record_verification(&mut self, material: &Material) {
+        let points = material
+            .calculate_reward_points()
+            .expect("Reward calculation overflow");
+        self.record_verification_with_points(material, points);
+    }
+
+    /// Records a material verification that awarded the given points
+    pub fn record_verification_with_points(&mut self, material: &Material, points: u64) {
         if material.verified {
             self.verified_submissions += 1;
-            self.total_points += material.calculate_reward_points();
+            self.total_points += points;
+        }
+    }
+
+    /// Combines two participants' stats into one, keeping this participant's address
+    /// Returns None if any counter overflows
+    pub fn merged_with(&self, other: &RecyclingStats) -> Option<Self> {
+        Some(Self {
+            participant: self.participant.clone(),
+            total_submissions: self.total_submissions.checked_add(other.total_submissions)?,
+            verified_submissions: self
+                .verified_submissions
+                .checked_add(other.verified_submissions)?,
+            total_weight: self.total_weight.checked_add(other.total_weight)?,
+            total_points: self.total_points.checked_add(other.total_points)?,
+            paper_count: self.paper_count.checked_add(other.paper_count)?,
+            pet_plastic_count: self.pet_plastic_count.checked_add(other.pet_plastic_count)?,
+            plastic_count: self.plastic_count.checked_add(other.plastic_count)?,
+            metal_count: self.metal_count.checked_add(other.metal_count)?,
+            glass_count: self.glass_count.checked_add(other.glass_count)?,
+            paper_weight: self.paper_weight.checked_add(other.paper_weight)?,
+            pet_plastic_weight: self.pet_plastic_weight.checked_add(other.pet_plastic_weight)?,
+            plastic_weight: self.plastic_weight.checked_add(other.plastic_weight)?,
+            metal_weight: self.metal_weight.checked_add(other.metal_weight)?,
+            glass_weight: self.glass_weight.checked_add(other.glass_weight)?,
+            rejected_submissions: self
+                .rejected_submissions
+                .checked_add(other.rejected_submissions)?,
+        })
+    }
+
+    /// Reverses a verification that awarded the given points
+    pub fn record_revocation(&mut self, points: u64) {
+        self.verified_submissions = self.verified_submissions.saturating_sub(1);
+        self.total_points = self.total_points.saturating_sub(points);
+    }
+
+    /// Records a material rejection
+    pub fn record_rejection(&mut self, material: &Material) {
+        if material.status == WasteStatus::Rejected {
+            self.rejected_submissions += 1;
         }
     }
 
     /// Calculates the verification rate (percentage)
     pub fn verification_rate(&self) -> u64 {
-        if self.total_submissions == 0 {
-            0
-        } else {
-            (self.verified_submissions * 100) / self.total_submissions
-        }
+        (self.verified_submissions * 100)
+            .checked_div(self.total_submissions)
+            .unwrap_or(0)
+    }
+
+    /// Calculates the acceptance rate (percentage of submissions not rejected)
+    pub fn acceptance_rate(&self) -> u64 {
+        (self.total_submissions.saturating_sub(self.rejected_submissions) * 100)
+            .checked_div(self.total_submissions)
+            .unwrap_or(0)
+    }
+
+    /// Calculates the environmental impact score
+    /// Sum over waste types of submission count * `WasteType::impact_weight`
+    pub fn impact_score(&self) -> u64 {
+        [
+            WasteType::Paper,
+            WasteType::PetPlastic,
+            WasteType::Plastic,
+            WasteType::Metal,
+            WasteType::Glass,
+        ]
+        .iter()
+        .fold(0u64, |score, waste_type| {
+            score.saturating_add(
+                self.count_by_type(*waste_type)
+                    .saturating_mul(waste_type.impact_weight()),
+            )
+        })
     }
 
     /// Gets the most submitted waste type
@@ -805,11 +1228,9 @@ impl RecyclingStats {
 
     /// Calculates average weight per submission
     pub fn average_weight(&self) -> u64 {
-        if self.total_submissions == 0 {
-            0
-        } else {
-            self.total_weight / self.total_submissions
-        }
+        self.total_weight
+            .checked_div(self.total_submissions)
+            .unwrap_or(0)
     }
 
     /// Checks if participant is an active recycler (10+ submissions)
@@ -823,6 +1244,235 @@ impl RecyclingStats {
     }
 }
 
+/// Tracks ecosystem-wide recycling totals across all participants
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobalMetrics {
+    /// Total number of materials submitted
+    pub total_materials: u64,
+    /// Total number of verified materials
+    pub total_verified: u64,
+    /// Total weight of all materials in grams
+    pub total_weight: u64,
+    /// Number of materials by waste type
+    pub paper_count: u64,
+    pub pet_plastic_count: u64,
+    pub plastic_count: u64,
+    pub metal_count: u64,
+    pub glass_count: u64,
+}
+
+impl GlobalMetrics {
+    /// Creates an empty GlobalMetrics instance
+    pub fn new() -> Self {
+        Self {
+            total_materials: 0,
+            total_verified: 0,
+            total_weight: 0,
+            paper_count: 0,
+            pet_plastic_count: 0,
+            plastic_count: 0,
+            metal_count: 0,
+            glass_count: 0,
+        }
+    }
+
+    /// Records a new material submission
+    pub fn record_submission(&mut self, material: &Material) {
+        self.total_materials = self
+            .total_materials
+            .checked_add(1)
+            .expect("Overflow in global material count");
+        self.total_weight = self
+            .total_weight
+            .checked_add(material.weight)
+            .expect("Overflow in global weight");
+
+        let count = match material.waste_type {
+            WasteType::Paper => &mut self.paper_count,
+            WasteType::PetPlastic => &mut self.pet_plastic_count,
+            WasteType::Plastic => &mut self.plastic_count,
+            WasteType::Metal => &mut self.metal_count,
+            WasteType::Glass => &mut self.glass_count,
+        };
+        *count = count.checked_add(1).expect("Overflow in global type count");
+    }
+
+    /// Records a material verification
+    pub fn record_verification(&mut self, material: &Material) {
+        if material.verified {
+            self.total_verified = self
+                .total_verified
+                .checked_add(1)
+                .expect("Overflow in global verified count");
+        }
+    }
+
+    /// Reverses a material verification
+    pub fn record_revocation(&mut self) {
+        self.total_verified = self.total_verified.saturating_sub(1);
+    }
+
+    /// Gets the most submitted waste type across all participants
+    /// Ties resolve to the type with the lowest discriminant
+    pub fn most_submitted_type(&self) -> Option<WasteType> {
+        let counts = [
+            (WasteType::Paper, self.paper_count),
+            (WasteType::PetPlastic, self.pet_plastic_count),
+            (WasteType::Plastic, self.plastic_count),
+            (WasteType::Metal, self.metal_count),
+            (WasteType::Glass, self.glass_count),
+        ];
+
+        counts
+            .iter()
+            .fold(None, |best: Option<&(WasteType, u64)>, entry| match best {
+                Some(leader) if leader.1 >= entry.1 => Some(leader),
+                _ if entry.1 > 0 => Some(entry),
+                _ => best,
+            })
+            .map(|(waste_type, _)| *waste_type)
+    }
+}
+
+impl Default for GlobalMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A sensitive configuration change that requires multi-admin approval
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigChange {
+    /// Set the collector and owner reward percentages
+    Percentages(u32, u32),
+    /// Set the charity contract address
+    Charity(Address),
+    /// Replace the admin set and its approval threshold
+    Admins(Vec<Address>, u32),
+}
+
+/// A pending configuration change and the admins who have approved it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigProposal {
+    /// The change to apply once enough admins approve
+    pub change: ConfigChange,
+    /// Distinct admins that have approved the change
+    pub approvals: Vec<Address>,
+}
+
+impl ConfigProposal {
+    /// Creates a proposal approved by its proposer
+    pub fn new(env: &soroban_sdk::Env, change: ConfigChange, proposer: Address) -> Self {
+        let mut approvals = Vec::new(env);
+        approvals.push_back(proposer);
+        Self { change, approvals }
+    }
+
+    /// Checks if the given admin has already approved
+    pub fn has_approved(&self, admin: &Address) -> bool {
+        self.approvals.contains(admin)
+    }
+
+    /// Records an approval from the given admin
+    pub fn approve(&mut self, admin: Address) {
+        self.approvals.push_back(admin);
+    }
+
+    /// Counts approvals from admins still in the given admin set
+    /// Approvals from removed admins no longer count towards the threshold
+    pub fn approval_count(&self, admins: &Vec<Address>) -> u32 {
+        self.approvals
+            .iter()
+            .filter(|approver| admins.contains(approver))
+            .count() as u32
+    }
+
+    /// Checks if the proposal has reached the approval threshold within the given admin set
+    pub fn is_approved(&self, admins: &Vec<Address>, threshold: u32) -> bool {
+        self.approval_count(admins) >= threshold
+    }
+}
+
+/// A configuration value as captured in the admin audit log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigValue {
+    /// The setting had no stored value
+    Unset,
+    /// A numeric setting (percentages, weights, durations)
+    Number(u64),
+    /// An address setting
+    Address(Address),
+    /// A boolean setting
+    Flag(bool),
+}
+
+impl ConfigValue {
+    /// Wraps an optional numeric setting
+    pub fn from_number(value: Option<u64>) -> Self {
+        match value {
+            Some(number) => ConfigValue::Number(number),
+            None => ConfigValue::Unset,
+        }
+    }
+}
+
+/// An applied admin configuration change
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigLogEntry {
+    /// Admin that applied the change
+    pub admin: Address,
+    /// Name of the changed setting
+    pub field: Symbol,
+    /// Value before the change
+    pub old_value: ConfigValue,
+    /// Value after the change
+    pub new_value: ConfigValue,
+    /// Ledger timestamp of the change
+    pub timestamp: u64,
+}
+
+/// Progress of an ownership dispute raised over a waste
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    /// Waiting for an admin ruling
+    Open = 0,
+    /// Admin ruled in favour of the claimant
+    Upheld = 1,
+    /// Admin rejected the claim
+    Dismissed = 2,
+    /// Admin force-transferred the waste, closing the dispute
+    Resolved = 3,
+}
+
+/// An ownership dispute raised by a participant against the current holder of a waste
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    /// The disputed v2 waste
+    pub waste_id: u128,
+    /// Participant claiming to be the rightful owner
+    pub claimant: Address,
+    /// Claimant's explanation
+    pub reason: String,
+    /// Current status of the dispute
+    pub status: DisputeStatus,
+    /// Ledger timestamp when the dispute was opened
+    pub opened_at: u64,
+}
+
+impl Dispute {
+    /// Checks if the dispute still permits an admin force-transfer
+    pub fn allows_force_transfer(&self) -> bool {
+        matches!(self.status, DisputeStatus::Open | DisputeStatus::Upheld)
+    }
+}
+
 #[cfg(test)]
 mod recycling_stats_tests {
     use super::*;
@@ -856,6 +1506,24 @@ mod recycling_stats_tests {
         assert_eq!(stats.total_submissions, 1);
         assert_eq!(stats.total_weight, 5000);
         assert_eq!(stats.paper_count, 1);
+        assert_eq!(stats.paper_weight, 5000);
+    }
+
+    #[test]
+    fn test_weight_by_type() {
+        let env = soroban_sdk::Env::default();
+        let participant = Address::generate(&env);
+        let description = String::from_str(&env, "Test");
+
+        let mut stats = RecyclingStats::new(participant.clone());
+        stats.record_submission(&Material::new(1, WasteType::Metal, 2000, participant.clone(), 0, description.clone()));
+        stats.record_submission(&Material::new(2, WasteType::Metal, 3000, participant.clone(), 0, description.clone()));
+        stats.record_submission(&Material::new(3, WasteType::Glass, 1500, participant, 0, description));
+
+        assert_eq!(stats.weight_by_type(WasteType::Metal), 5000);
+        assert_eq!(stats.count_by_type(WasteType::Metal), 2);
+        assert_eq!(stats.weight_by_type(WasteType::Glass), 1500);
+        assert_eq!(stats.weight_by_type(WasteType::Paper), 0);
     }
 
     #[test]
@@ -886,6 +1554,31 @@ mod recycling_stats_tests {
         assert_eq!(stats.verification_rate(), 80);
     }
 
+    #[test]
+    fn test_record_rejection_and_acceptance_rate() {
+        let env = soroban_sdk::Env::default();
+        let participant = Address::generate(&env);
+        let description = String::from_str(&env, "Test");
+
+        let mut stats = RecyclingStats::new(participant.clone());
+        assert_eq!(stats.acceptance_rate(), 0);
+
+        let mut material = Material::new(1, WasteType::Paper, 1000, participant, 0, description);
+        stats.record_submission(&material);
+        stats.record_submission(&material);
+        stats.record_submission(&material);
+        stats.record_submission(&material);
+
+        // Only materials actually marked rejected are counted
+        stats.record_rejection(&material);
+        assert_eq!(stats.rejected_submissions, 0);
+
+        material.transition_status(WasteStatus::Rejected);
+        stats.record_rejection(&material);
+        assert_eq!(stats.rejected_submissions, 1);
+        assert_eq!(stats.acceptance_rate(), 75);
+    }
+
     #[test]
     fn test_most_submitted_type() {
         let env = soroban_sdk::Env::default();
@@ -938,7 +1631,7 @@ mod recycling_stats_tests {
     #[test]
     fn test_stats_storage() {
         let env = soroban_sdk::Env::default();
-        let contract_id = env.register_contract(None, crate::ScavengerContract);
+        let _contract_id = env.register_contract(None, crate::ScavengerContract);
         let participant = Address::generate(&env);
 
         let stats = RecyclingStats::new(participant.clone());
@@ -948,6 +1641,246 @@ mod recycling_stats_tests {
     }
 }
 
+#[cfg(test)]
+mod global_metrics_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_new_global_metrics() {
+        let metrics = GlobalMetrics::new();
+
+        assert_eq!(metrics.total_materials, 0);
+        assert_eq!(metrics.total_verified, 0);
+        assert_eq!(metrics.total_weight, 0);
+        assert_eq!(metrics, GlobalMetrics::default());
+    }
+
+    #[test]
+    fn test_global_record_submission_and_verification() {
+        let env = soroban_sdk::Env::default();
+        let participant = Address::generate(&env);
+        let description = String::from_str(&env, "Test");
+
+        let mut metrics = GlobalMetrics::new();
+        let mut material = Material::new(1, WasteType::Metal, 3000, participant, 0, description);
+
+        metrics.record_submission(&material);
+        metrics.record_verification(&material);
+        assert_eq!(metrics.total_verified, 0);
+
+        material.verify();
+        metrics.record_verification(&material);
+
+        assert_eq!(metrics.total_materials, 1);
+        assert_eq!(metrics.total_verified, 1);
+        assert_eq!(metrics.total_weight, 3000);
+        assert_eq!(metrics.metal_count, 1);
+        assert_eq!(metrics.paper_count, 0);
+    }
+
+    #[test]
+    fn test_global_most_submitted_type_prefers_lowest_on_tie() {
+        let mut metrics = GlobalMetrics::new();
+        assert_eq!(metrics.most_submitted_type(), None);
+
+        metrics.glass_count = 2;
+        metrics.plastic_count = 2;
+        assert_eq!(metrics.most_submitted_type(), Some(WasteType::Plastic));
+
+        metrics.glass_count = 3;
+        assert_eq!(metrics.most_submitted_type(), Some(WasteType::Glass));
+    }
+}
+
+#[cfg(test)]
+mod incentive_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    fn incentive(env: &soroban_sdk::Env, reward_points: u64, total_budget: u64) -> Incentive {
+        Incentive::new(env, 1, Address::generate(env), WasteType::Metal, reward_points, total_budget, 0)
+    }
+
+    #[test]
+    fn test_calculate_reward_truncates_partial_kilograms() {
+        let env = soroban_sdk::Env::default();
+        let incentive = incentive(&env, 100, 10000);
+
+        assert_eq!(incentive.calculate_reward(500), Some(0));
+        assert_eq!(incentive.calculate_reward(999), Some(0));
+        assert_eq!(incentive.calculate_reward(1500), Some(100));
+    }
+
+    #[test]
+    fn test_calculate_reward_precise() {
+        let env = soroban_sdk::Env::default();
+        let incentive = incentive(&env, 100, 10000);
+
+        assert_eq!(incentive.calculate_reward_precise(500), Some(50));
+        assert_eq!(incentive.calculate_reward_precise(999), Some(99));
+        assert_eq!(incentive.calculate_reward_precise(1500), Some(150));
+        assert_eq!(incentive.calculate_reward_precise(5000), Some(500));
+    }
+
+    #[test]
+    fn test_calculate_reward_scaled() {
+        let env = soroban_sdk::Env::default();
+        let incentive = incentive(&env, 100, 10000);
+
+        assert_eq!(incentive.calculate_reward_scaled(500, 1000), Some(50));
+        assert_eq!(incentive.calculate_reward_scaled(500, 100), Some(500));
+        assert_eq!(incentive.calculate_reward_scaled(500, 0), None);
+        assert_eq!(incentive.calculate_reward_scaled(5000, 100), Some(5000));
+    }
+
+    #[test]
+    fn test_claim_reward_uses_precise_calculation() {
+        let env = soroban_sdk::Env::default();
+        let mut incentive = incentive(&env, 100, 1000);
+
+        assert!(incentive.has_sufficient_budget(WasteType::Metal, 999, 1000));
+        assert_eq!(incentive.claim_reward(WasteType::Metal, 999, 1000), Some(99));
+        assert_eq!(incentive.claim_reward(WasteType::Metal, 1500, 1000), Some(150));
+        assert_eq!(incentive.remaining_budget, 751);
+    }
+
+    #[test]
+    fn test_claim_reward_uses_scale_factor() {
+        let env = soroban_sdk::Env::default();
+        let mut incentive = incentive(&env, 100, 1000);
+
+        assert!(incentive.has_sufficient_budget(WasteType::Metal, 500, 100));
+        assert!(!incentive.has_sufficient_budget(WasteType::Metal, 1500, 100));
+        assert_eq!(incentive.best_claimable_amount(WasteType::Metal, 1500, 100, 0), 1000);
+        assert_eq!(incentive.claim_reward(WasteType::Metal, 500, 100), Some(500));
+        assert_eq!(incentive.claim_reward(WasteType::Metal, 500, 0), None);
+        assert_eq!(incentive.remaining_budget, 500);
+    }
+
+    #[test]
+    fn test_weight_band_defaults_to_unbounded() {
+        let env = soroban_sdk::Env::default();
+        let mut incentive = incentive(&env, 100, 1000);
+
+        assert!(incentive.is_weight_in_band(0));
+        assert!(incentive.is_weight_in_band(u64::MAX));
+
+        incentive.min_weight = 1000;
+        incentive.max_weight = 5000;
+        assert!(!incentive.is_weight_in_band(999));
+        assert!(incentive.is_weight_in_band(1000));
+        assert!(incentive.is_weight_in_band(5000));
+        assert!(!incentive.is_weight_in_band(5001));
+    }
+
+    #[test]
+    fn test_region_defaults_to_unbounded() {
+        let env = soroban_sdk::Env::default();
+        let mut incentive = incentive(&env, 100, 1000);
+
+        assert!(!incentive.is_geofenced());
+        assert!(incentive.contains_location(90_000_000, -180_000_000));
+
+        incentive.min_lat = 6_000_000;
+        incentive.max_lat = 7_000_000;
+        incentive.min_lon = 3_000_000;
+        incentive.max_lon = 4_000_000;
+        assert!(incentive.is_geofenced());
+        assert!(incentive.contains_location(6_000_000, 4_000_000));
+        assert!(!incentive.contains_location(5_999_999, 3_500_000));
+        assert!(!incentive.contains_location(6_500_000, 4_000_001));
+    }
+
+    #[test]
+    fn test_claim_amount_deducts_and_deactivates() {
+        let env = soroban_sdk::Env::default();
+        let mut incentive = incentive(&env, 100, 500);
+
+        assert_eq!(incentive.claim_amount(WasteType::Metal, 600), None);
+        assert_eq!(incentive.claim_amount(WasteType::Metal, 300), Some(300));
+        assert_eq!(incentive.claim_amount(WasteType::Metal, 200), Some(200));
+        assert!(!incentive.active);
+        assert_eq!(incentive.claim_amount(WasteType::Metal, 0), None);
+    }
+
+    #[test]
+    fn test_release_only_revives_exhausted_incentive() {
+        let env = soroban_sdk::Env::default();
+
+        let mut exhausted = incentive(&env, 100, 500);
+        exhausted.claim_amount(WasteType::Metal, 500);
+        assert!(exhausted.exhausted);
+        exhausted.release_amount(WasteType::Metal, 200);
+        assert!(exhausted.active);
+        assert!(!exhausted.exhausted);
+
+        let mut deactivated = incentive(&env, 100, 500);
+        deactivated.claim_amount(WasteType::Metal, 500);
+        deactivated.deactivate();
+        deactivated.release_amount(WasteType::Metal, 200);
+        assert!(!deactivated.active);
+        assert_eq!(deactivated.remaining_budget, 200);
+    }
+
+    #[test]
+    fn test_best_claimable_amount_caps_at_remaining_budget() {
+        let env = soroban_sdk::Env::default();
+        let incentive = incentive(&env, 100, 300);
+
+        assert_eq!(incentive.best_claimable_amount(WasteType::Metal, 2000, 1000, 0), 200);
+        assert_eq!(incentive.best_claimable_amount(WasteType::Metal, 5000, 1000, 0), 300);
+        assert_eq!(incentive.best_claimable_amount(WasteType::Metal, u64::MAX, 1000, 0), 300);
+        // 2kg * 100 = 200 plus a 50% bonus is capped too
+        assert_eq!(incentive.best_claimable_amount(WasteType::Metal, 2000, 1000, 50), 300);
+        assert_eq!(incentive.calculate_reward_with_bonus(2000, 1000, 50), Some(300));
+    }
+
+    #[test]
+    fn test_reward_overflow_is_caught() {
+        let env = soroban_sdk::Env::default();
+        let mut incentive = incentive(&env, u64::MAX / 2, u64::MAX);
+
+        assert_eq!(incentive.calculate_reward(u64::MAX), None);
+        assert_eq!(incentive.calculate_reward_precise(u64::MAX), None);
+        assert!(!incentive.has_sufficient_budget(WasteType::Metal, u64::MAX, 1000));
+
+        assert_eq!(incentive.claim_reward(WasteType::Metal, u64::MAX, 1000), None);
+        assert_eq!(incentive.remaining_budget, u64::MAX);
+        assert!(incentive.active);
+    }
+
+    #[test]
+    fn test_single_type_claim_rejects_other_types() {
+        let env = soroban_sdk::Env::default();
+        let mut incentive = incentive(&env, 100, 1000);
+
+        assert!(incentive.accepts(WasteType::Metal));
+        assert!(!incentive.accepts(WasteType::Glass));
+        assert_eq!(incentive.remaining_for(WasteType::Metal), 1000);
+        assert_eq!(incentive.remaining_for(WasteType::Glass), 0);
+        assert_eq!(incentive.claim_amount(WasteType::Glass, 100), None);
+    }
+
+    #[test]
+    fn test_multi_type_claim_debits_sub_budget() {
+        let env = soroban_sdk::Env::default();
+        let mut incentive = incentive(&env, 100, 800);
+        incentive.accepted_types = soroban_sdk::vec![&env, WasteType::Metal, WasteType::Glass];
+        incentive.type_budgets = soroban_sdk::vec![&env, 300, 500];
+
+        assert_eq!(incentive.claim_amount(WasteType::Metal, 400), None);
+        assert_eq!(incentive.claim_amount(WasteType::Metal, 300), Some(300));
+        assert_eq!(incentive.remaining_for(WasteType::Metal), 0);
+        assert_eq!(incentive.remaining_for(WasteType::Glass), 500);
+        assert_eq!(incentive.remaining_budget, 500);
+        assert!(incentive.active);
+
+        assert_eq!(incentive.claim_amount(WasteType::Glass, 500), Some(500));
+        assert!(!incentive.active);
+    }
+}
+
 #[cfg(test)]
 mod material_tests {
     use super::*;
@@ -997,6 +1930,59 @@ mod material_tests {
         assert!(material.verified);
     }
 
+    #[test]
+    fn test_material_retire() {
+        let env = soroban_sdk::Env::default();
+        let submitter = Address::generate(&env);
+        let description = String::from_str(&env, "Test");
+
+        let mut material = Material::new(1, WasteType::Glass, 1000, submitter, 0, description);
+
+        assert!(!material.is_retired);
+        material.retire();
+        assert!(material.is_retired);
+    }
+
+    #[test]
+    fn test_material_status_forward_transitions() {
+        let env = soroban_sdk::Env::default();
+        let submitter = Address::generate(&env);
+        let description = String::from_str(&env, "Test");
+
+        let mut material = Material::new(1, WasteType::Metal, 1000, submitter, 0, description);
+        assert_eq!(material.status, WasteStatus::Submitted);
+
+        assert!(material.transition_status(WasteStatus::Collected));
+        assert!(material.transition_status(WasteStatus::Processed));
+        assert!(material.transition_status(WasteStatus::Recycled));
+        assert_eq!(material.status, WasteStatus::Recycled);
+
+        // Recycled is final
+        assert!(!material.transition_status(WasteStatus::Rejected));
+    }
+
+    #[test]
+    fn test_material_status_rejects_illegal_transitions() {
+        let env = soroban_sdk::Env::default();
+        let submitter = Address::generate(&env);
+        let description = String::from_str(&env, "Test");
+
+        let mut material = Material::new(1, WasteType::Metal, 1000, submitter, 0, description);
+
+        // Skipping ahead is not allowed
+        assert!(!material.transition_status(WasteStatus::Processed));
+        assert!(material.transition_status(WasteStatus::Collected));
+
+        // Neither is moving backwards or staying put
+        assert!(!material.transition_status(WasteStatus::Submitted));
+        assert!(!material.transition_status(WasteStatus::Collected));
+        assert_eq!(material.status, WasteStatus::Collected);
+
+        assert!(material.transition_status(WasteStatus::Rejected));
+        assert!(!material.transition_status(WasteStatus::Processed));
+        assert_eq!(material.status, WasteStatus::Rejected);
+    }
+
     #[test]
     fn test_meets_minimum_weight() {
         let env = soroban_sdk::Env::default();
@@ -1043,7 +2029,7 @@ mod material_tests {
             0,
             description.clone(),
         );
-        assert_eq!(paper.calculate_reward_points(), 50);
+        assert_eq!(paper.calculate_reward_points(), Some(50));
 
         // PetPlastic: 5kg * 3 * 10 = 150 points
         let pet = Material::new(
@@ -1054,7 +2040,7 @@ mod material_tests {
             0,
             description.clone(),
         );
-        assert_eq!(pet.calculate_reward_points(), 150);
+        assert_eq!(pet.calculate_reward_points(), Some(150));
 
         // Plastic: 5kg * 2 * 10 = 100 points
         let plastic = Material::new(
@@ -1065,7 +2051,7 @@ mod material_tests {
             0,
             description.clone(),
         );
-        assert_eq!(plastic.calculate_reward_points(), 100);
+        assert_eq!(plastic.calculate_reward_points(), Some(100));
 
         // Metal: 5kg * 5 * 10 = 250 points
         let metal = Material::new(
@@ -1076,17 +2062,28 @@ mod material_tests {
             0,
             description.clone(),
         );
-        assert_eq!(metal.calculate_reward_points(), 250);
+        assert_eq!(metal.calculate_reward_points(), Some(250));
 
         // Glass: 5kg * 2 * 10 = 100 points
         let glass = Material::new(5, WasteType::Glass, 5000, submitter, 0, description);
-        assert_eq!(glass.calculate_reward_points(), 100);
+        assert_eq!(glass.calculate_reward_points(), Some(100));
+    }
+
+    #[test]
+    fn test_calculate_reward_points_overflow() {
+        let env = soroban_sdk::Env::default();
+        let submitter = Address::generate(&env);
+        let description = String::from_str(&env, "Test");
+
+        let material = Material::new(1, WasteType::Metal, u64::MAX, submitter, 0, description);
+        assert_eq!(material.calculate_reward_points_with(u32::MAX), None);
+        assert_eq!(material.calculate_reward_points_with(0), Some(0));
     }
 
     #[test]
     fn test_material_storage_compatibility() {
         let env = soroban_sdk::Env::default();
-        let contract_id = env.register_contract(None, crate::ScavengerContract);
+        let _contract_id = env.register_contract(None, crate::ScavengerContract);
         let submitter = Address::generate(&env);
         let description = String::from_str(&env, "Storage test");
 
@@ -1196,6 +2193,24 @@ mod waste_type_tests {
         assert!(WasteType::Glass.is_infinitely_recyclable());
     }
 
+    #[test]
+    fn test_waste_type_impact_weight() {
+        assert_eq!(WasteType::Paper.impact_weight(), 1);
+        assert_eq!(WasteType::PetPlastic.impact_weight(), 2);
+        assert_eq!(WasteType::Plastic.impact_weight(), 2);
+        assert_eq!(WasteType::Metal.impact_weight(), 3);
+        assert_eq!(WasteType::Glass.impact_weight(), 3);
+    }
+
+    #[test]
+    fn test_waste_type_default_reward_multiplier() {
+        assert_eq!(WasteType::Paper.default_reward_multiplier(), 1);
+        assert_eq!(WasteType::PetPlastic.default_reward_multiplier(), 3);
+        assert_eq!(WasteType::Plastic.default_reward_multiplier(), 2);
+        assert_eq!(WasteType::Metal.default_reward_multiplier(), 5);
+        assert_eq!(WasteType::Glass.default_reward_multiplier(), 2);
+    }
+
     #[test]
     fn test_waste_type_clone_and_copy() {
         let waste1 = WasteType::Paper;
@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use soroban_sdk::{Env, String};
+use stellar_scavngr_contract::{ScavengerError, WasteStatus, WasteType};
+
+mod common;
+use common::setup_collector_recycler;
+
+#[test]
+fn test_acceptance_rate_after_rejections() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let mut ids = soroban_sdk::Vec::new(&env);
+    for _ in 0..5 {
+        ids.push_back(client.submit_material(&WasteType::Paper, &1000, &collector, &desc).id);
+    }
+
+    client.verify_material(&ids.get(0).unwrap(), &recycler);
+    let rejected = client.reject_material(&ids.get(1).unwrap(), &recycler);
+    assert_eq!(rejected.status, WasteStatus::Rejected);
+    client.reject_material(&ids.get(2).unwrap(), &recycler);
+
+    let stats = client.get_stats(&collector).unwrap();
+    assert_eq!(stats.total_submissions, 5);
+    assert_eq!(stats.rejected_submissions, 2);
+    // 3 accepted out of 5 submissions
+    assert_eq!(stats.acceptance_rate(), 60);
+    assert_eq!(stats.verification_rate(), 20);
+}
+
+#[test]
+#[should_panic(expected = "Cannot reject verified material")]
+fn test_cannot_reject_verified_material() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+    client.reject_material(&material.id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Material status is final")]
+fn test_cannot_reject_twice() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.reject_material(&material.id, &recycler);
+    client.reject_material(&material.id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Only recyclers can reject materials")]
+fn test_collector_cannot_reject() {
+    let env = Env::default();
+    let (client, collector, _) = setup_collector_recycler(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.reject_material(&material.id, &collector);
+}
+
+#[test]
+fn test_rejected_material_cannot_be_verified() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.reject_material(&material.id, &recycler);
+
+    let result = client.try_verify_material(&material.id, &recycler);
+    assert_eq!(result, Err(Ok(ScavengerError::MaterialRejected)));
+
+    // Batches skip rejected materials instead of rewarding them
+    let mut ids = soroban_sdk::Vec::new(&env);
+    ids.push_back(material.id);
+    assert_eq!(client.verify_materials_batch(&ids, &recycler).len(), 0);
+
+    assert!(!client.get_waste(&material.id).unwrap().verified);
+    assert_eq!(client.get_stats(&collector).unwrap().total_points, 0);
+}
@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, String, Symbol,
+};
+use stellar_scavngr_contract::{
+    DisputeStatus, ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    (client, admin, recycler, collector)
+}
+
+#[test]
+fn test_force_transfer_after_upheld_dispute() {
+    let env = Env::default();
+    let (client, admin, recycler, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &2000, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+
+    let claim = String::from_str(&env, "Transferred without payment");
+    let dispute = client.open_dispute(&waste_id, &recycler, &claim);
+    assert_eq!(dispute.status, DisputeStatus::Open);
+    assert_eq!(client.resolve_dispute(&admin, &waste_id, &true).status, DisputeStatus::Upheld);
+
+    let reason = String::from_str(&env, "Dispute upheld");
+    let transfer = client.admin_force_transfer(&admin, &waste_id, &recycler, &reason);
+    assert_eq!(transfer.from, collector);
+    assert_eq!(transfer.to, recycler);
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("v1"), Symbol::new(&env, "admin_xfer"), waste_id).into_val(&env)
+    );
+
+    let (waste, history) = client.get_waste_full(&waste_id).unwrap();
+    assert_eq!(waste.current_owner, recycler);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(1).unwrap().note, reason);
+    assert_eq!(client.get_dispute(&waste_id).unwrap().status, DisputeStatus::Resolved);
+}
+
+#[test]
+#[should_panic(expected = "No dispute for waste")]
+fn test_force_transfer_requires_dispute() {
+    let env = Env::default();
+    let (client, admin, recycler, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &2000, &recycler, &0, &0);
+    client.admin_force_transfer(&admin, &waste_id, &collector, &String::from_str(&env, "No claim"));
+}
+
+#[test]
+#[should_panic(expected = "Dispute was dismissed")]
+fn test_force_transfer_rejected_after_dismissal() {
+    let env = Env::default();
+    let (client, admin, recycler, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &2000, &recycler, &0, &0);
+    client.open_dispute(&waste_id, &collector, &String::from_str(&env, "Mine"));
+    client.resolve_dispute(&admin, &waste_id, &false);
+
+    client.admin_force_transfer(&admin, &waste_id, &collector, &String::from_str(&env, "Ruling"));
+}
+
+#[test]
+#[should_panic(expected = "Dispute already resolved")]
+fn test_second_force_transfer_rejected() {
+    let env = Env::default();
+    let (client, admin, recycler, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &2000, &recycler, &0, &0);
+    client.open_dispute(&waste_id, &collector, &String::from_str(&env, "Mine"));
+    client.admin_force_transfer(&admin, &waste_id, &collector, &String::from_str(&env, "Ruling"));
+    assert_eq!(client.get_dispute(&waste_id).unwrap().status, DisputeStatus::Resolved);
+
+    client.admin_force_transfer(&admin, &waste_id, &recycler, &String::from_str(&env, "Again"));
+}
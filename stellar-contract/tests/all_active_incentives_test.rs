@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+use soroban_sdk::Env;
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_manufacturer;
+
+#[test]
+fn test_returns_active_incentives_across_types() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let paper = client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+    let metal = client.create_incentive(&manufacturer, &WasteType::Metal, &20, &1000);
+    let glass = client.create_incentive(&manufacturer, &WasteType::Glass, &30, &1000);
+    let plastic = client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &1000);
+
+    client.deactivate_incentive(&metal.id, &manufacturer);
+
+    let active = client.get_all_active_incentives(&10);
+    assert_eq!(active.len(), 3);
+    assert_eq!(active.get(0).unwrap().id, paper.id);
+    assert_eq!(active.get(1).unwrap().id, glass.id);
+    assert_eq!(active.get(2).unwrap().id, plastic.id);
+    assert!(active.iter().all(|incentive| incentive.active));
+}
+
+#[test]
+fn test_respects_limit() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let first = client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+    let second = client.create_incentive(&manufacturer, &WasteType::Metal, &20, &1000);
+    let third = client.create_incentive(&manufacturer, &WasteType::Glass, &30, &1000);
+    client.create_incentive(&manufacturer, &WasteType::PetPlastic, &40, &1000);
+
+    client.deactivate_incentive(&second.id, &manufacturer);
+
+    let active = client.get_all_active_incentives(&2);
+    assert_eq!(active.len(), 2);
+    assert_eq!(active.get(0).unwrap().id, first.id);
+    assert_eq!(active.get(1).unwrap().id, third.id);
+
+    assert_eq!(client.get_all_active_incentives(&0).len(), 0);
+}
+
+#[test]
+fn test_empty_without_incentives() {
+    let env = Env::default();
+    let (client, _) = setup_manufacturer(&env);
+
+    assert_eq!(client.get_all_active_incentives(&10).len(), 0);
+}
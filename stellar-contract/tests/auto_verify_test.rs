@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use soroban_sdk::{Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup_with_admin;
+
+/// Give the collector a fully verified track record
+fn make_trusted(env: &Env, client: &ScavengerContractClient, collector: &Address, recycler: &Address) {
+    let desc = String::from_str(env, "History");
+    let material = client.submit_material(&WasteType::Paper, &1000, collector, &desc);
+    client.verify_material(&material.id, recycler);
+}
+
+#[test]
+fn test_small_submission_from_trusted_contributor_is_auto_verified() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+    make_trusted(&env, &client, &collector, &recycler);
+    client.set_auto_verify_threshold(&admin, &5000);
+    assert_eq!(client.get_auto_verify_threshold(), 5000);
+
+    let tokens_before = client.get_participant(&collector).unwrap().total_tokens_earned;
+    let desc = String::from_str(&env, "Small");
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+
+    assert!(material.verified);
+    assert!(client.get_material(&material.id).unwrap().verified);
+    assert_eq!(client.get_stats(&collector).unwrap().verified_submissions, 2);
+    assert!(client.get_participant(&collector).unwrap().total_tokens_earned > tokens_before);
+}
+
+#[test]
+fn test_large_submission_requires_manual_verification() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+    make_trusted(&env, &client, &collector, &recycler);
+    client.set_auto_verify_threshold(&admin, &5000);
+
+    let desc = String::from_str(&env, "Large");
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    assert!(!material.verified);
+
+    let verified = client.verify_material(&material.id, &recycler);
+    assert!(verified.verified);
+}
+
+#[test]
+fn test_unproven_participant_is_not_auto_verified() {
+    let env = Env::default();
+    let (client, admin, collector, _) = setup_with_admin(&env);
+    client.set_auto_verify_threshold(&admin, &5000);
+
+    let desc = String::from_str(&env, "Small");
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    assert!(!material.verified);
+}
+
+#[test]
+fn test_auto_verify_disabled_by_default() {
+    let env = Env::default();
+    let (client, _, collector, recycler) = setup_with_admin(&env);
+    make_trusted(&env, &client, &collector, &recycler);
+
+    let desc = String::from_str(&env, "Small");
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    assert!(!material.verified);
+}
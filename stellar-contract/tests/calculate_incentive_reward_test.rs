@@ -0,0 +1,49 @@
+#![cfg(test)]
+
+use soroban_sdk::Env;
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_manufacturer;
+
+#[test]
+fn test_reward_is_points_per_kilogram() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &50, &10000);
+
+    // 2.5kg * 50 points = 125
+    assert_eq!(client.calculate_incentive_reward(&incentive.id, &2500), 125);
+}
+
+#[test]
+fn test_reward_capped_at_remaining_budget() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &300);
+
+    // 10kg * 100 points = 1000, capped at the 300 point budget
+    assert_eq!(client.calculate_incentive_reward(&incentive.id, &10000), 300);
+}
+
+#[test]
+fn test_reward_zero_for_inactive_incentive() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Glass, &100, &5000);
+    client.update_incentive_status(&incentive.id, &false);
+
+    assert_eq!(client.calculate_incentive_reward(&incentive.id, &1000), 0);
+}
+
+#[test]
+#[should_panic(expected = "Incentive not found")]
+fn test_reward_for_unknown_incentive() {
+    let env = Env::default();
+    let (client, _) = setup_manufacturer(&env);
+
+    client.calculate_incentive_reward(&999, &1000);
+}
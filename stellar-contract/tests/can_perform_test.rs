@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, Symbol};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient};
+
+mod common;
+use common::setup_contract;
+
+fn register(env: &Env, client: &ScavengerContractClient, role: ParticipantRole) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &role, &symbol_short!("p"), &0, &0);
+    address
+}
+
+fn actions(env: &Env) -> [Symbol; 5] {
+    [
+        symbol_short!("submit"),
+        symbol_short!("verify"),
+        symbol_short!("collect"),
+        Symbol::new(env, "manufacture"),
+        Symbol::new(env, "create_incentive"),
+    ]
+}
+
+#[test]
+fn test_role_action_matrix() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+
+    let cases = [
+        (ParticipantRole::Recycler, [true, true, true, false, false]),
+        (
+            ParticipantRole::Collector,
+            [true, false, true, false, false],
+        ),
+        (
+            ParticipantRole::Manufacturer,
+            [true, false, false, true, true],
+        ),
+    ];
+
+    for (role, expected) in cases {
+        let address = register(&env, &client, role);
+        for (action, allowed) in actions(&env).iter().zip(expected) {
+            assert_eq!(client.can_perform(&address, action), allowed);
+        }
+
+        // Matches the dedicated role checks
+        assert_eq!(
+            client.can_perform(&address, &symbol_short!("collect")),
+            client.can_collect(&address)
+        );
+        assert_eq!(
+            client.can_perform(&address, &Symbol::new(&env, "manufacture")),
+            client.can_manufacture(&address)
+        );
+    }
+}
+
+#[test]
+fn test_unknown_action_and_unregistered_address() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+
+    let manufacturer = register(&env, &client, ParticipantRole::Manufacturer);
+    assert!(!client.can_perform(&manufacturer, &symbol_short!("teleport")));
+
+    let stranger = Address::generate(&env);
+    for action in actions(&env).iter() {
+        assert!(!client.can_perform(&stranger, action));
+    }
+
+    client.deregister_participant(&manufacturer);
+    for action in actions(&env).iter() {
+        assert!(!client.can_perform(&manufacturer, action));
+    }
+}
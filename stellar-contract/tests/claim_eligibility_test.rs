@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, String, Symbol};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn submit(env: &Env, client: &ScavengerContractClient, waste_type: WasteType, weight: u64, submitter: &Address) -> u64 {
+    let desc = String::from_str(env, "Test");
+    client.submit_material(&waste_type, &weight, submitter, &desc).id
+}
+
+#[test]
+fn test_eligible_claim_is_ok_and_state_unchanged() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let material = submit(&env, &client, WasteType::Metal, 2000, &collector);
+    client.verify_material(&material, &recycler);
+
+    assert_eq!(client.check_claim_eligibility(&incentive.id, &material, &collector), symbol_short!("ok"));
+    assert_eq!(client.get_incentive_by_id(&incentive.id).unwrap().remaining_budget, 10000);
+
+    client.claim_incentive_reward(&incentive.id, &material, &collector);
+    assert_eq!(
+        client.check_claim_eligibility(&incentive.id, &material, &collector),
+        Symbol::new(&env, "already_claimed")
+    );
+}
+
+#[test]
+fn test_material_failure_reasons() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let banded = client.create_incentive_with_band(&manufacturer, &WasteType::Metal, &100, &10000, &5000, &8000);
+
+    assert_eq!(client.check_claim_eligibility(&incentive.id, &99, &collector), symbol_short!("not_found"));
+    assert_eq!(client.check_claim_eligibility(&99, &1, &collector), symbol_short!("not_found"));
+
+    let unverified = submit(&env, &client, WasteType::Metal, 2000, &collector);
+    assert_eq!(
+        client.check_claim_eligibility(&incentive.id, &unverified, &collector),
+        Symbol::new(&env, "unverified")
+    );
+
+    client.verify_material(&unverified, &recycler);
+    assert_eq!(client.check_claim_eligibility(&incentive.id, &unverified, &recycler), symbol_short!("not_owner"));
+    assert_eq!(
+        client.check_claim_eligibility(&banded.id, &unverified, &collector),
+        Symbol::new(&env, "out_of_band")
+    );
+
+    let paper = submit(&env, &client, WasteType::Paper, 2000, &collector);
+    client.verify_material(&paper, &recycler);
+    assert_eq!(
+        client.check_claim_eligibility(&incentive.id, &paper, &collector),
+        Symbol::new(&env, "type_mismatch")
+    );
+}
+
+#[test]
+fn test_budget_failure_reasons() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    // A 2kg claim drains the metal sub-budget; glass keeps the incentive active
+    let budgets = vec![&env, (WasteType::Metal, 200u64), (WasteType::Glass, 1000u64)];
+    let incentive = client.create_multi_type_incentive(&manufacturer, &100, &budgets);
+    let small = submit(&env, &client, WasteType::Metal, 2000, &collector);
+    let large = submit(&env, &client, WasteType::Metal, 5000, &collector);
+    client.verify_material(&small, &recycler);
+    client.verify_material(&large, &recycler);
+
+    // A reward larger than the budget left is capped rather than refused
+    assert_eq!(client.check_claim_eligibility(&incentive.id, &large, &collector), symbol_short!("ok"));
+    client.claim_incentive_reward(&incentive.id, &small, &collector);
+    assert_eq!(
+        client.check_claim_eligibility(&incentive.id, &large, &collector),
+        Symbol::new(&env, "insufficient_budget")
+    );
+
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+    assert_eq!(client.check_claim_eligibility(&incentive.id, &large, &collector), symbol_short!("inactive"));
+}
+
+#[test]
+fn test_reserved_and_paused_reasons() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &200);
+    let material = submit(&env, &client, WasteType::Metal, 2000, &collector);
+    client.verify_material(&material, &recycler);
+    client.reserve_incentive(&incentive.id, &material, &collector);
+
+    // The reservation holds the whole budget, yet the holder can still claim
+    assert_eq!(client.check_claim_eligibility(&incentive.id, &material, &collector), symbol_short!("ok"));
+
+    client.pause(&admin);
+    assert_eq!(client.check_claim_eligibility(&incentive.id, &material, &collector), symbol_short!("paused"));
+}
@@ -0,0 +1,160 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup;
+
+#[test]
+fn test_claimable_incentives_filters_type_and_budget() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    // 5kg * 100 = 500 points; a 400 point budget still pays what remains
+    let matching = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    client.create_incentive(&manufacturer, &WasteType::Glass, &100, &10000);
+    let short = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &400);
+    let inactive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    client.deactivate_incentive(&inactive.id, &manufacturer);
+
+    let desc = String::from_str(&env, "Cans");
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    let claimable = client.get_claimable_incentives(&material.id);
+    assert_eq!(claimable.len(), 2);
+    assert_eq!(claimable.get(0).unwrap().id, matching.id);
+    assert_eq!(claimable.get(1).unwrap().id, short.id);
+
+    // Once the budget is spent the incentive is no longer offered
+    assert_eq!(client.claim_incentive_reward(&short.id, &material.id, &collector), 400);
+    let other = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&other.id, &recycler);
+    let claimable = client.get_claimable_incentives(&other.id);
+    assert_eq!(claimable.len(), 1);
+    assert_eq!(claimable.get(0).unwrap().id, matching.id);
+}
+
+#[test]
+fn test_claimable_incentives_use_tiered_reward() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    // 5kg earns 500 base + 50% = 750, more than the 600 point budget
+    let tiers = vec![&env, (5000u64, 50u32)];
+    let tiered = client.create_tiered_incentive(&manufacturer, &WasteType::Metal, &100, &600, &tiers);
+
+    let desc = String::from_str(&env, "Cans");
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    let claimable = client.get_claimable_incentives(&material.id);
+    assert_eq!(claimable.len(), 1);
+    assert_eq!(client.preview_claim(&tiered.id, &material.id), 600);
+    assert_eq!(client.claim_incentive_reward(&tiered.id, &material.id, &collector), 600);
+}
+
+#[test]
+fn test_claimable_incentives_empty_for_unverified_material() {
+    let env = Env::default();
+    let (client, manufacturer, collector, _) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+
+    let desc = String::from_str(&env, "Cans");
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+
+    assert_eq!(client.get_claimable_incentives(&material.id).len(), 0);
+    assert_eq!(client.get_claimable_incentives(&999).len(), 0);
+}
+
+#[test]
+fn test_claimable_incentives_excludes_already_claimed() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let first = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let second = client.create_incentive(&manufacturer, &WasteType::Metal, &50, &10000);
+
+    let desc = String::from_str(&env, "Cans");
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+    assert_eq!(client.get_claimable_incentives(&material.id).len(), 2);
+
+    client.claim_incentive_reward(&first.id, &material.id, &collector);
+
+    let claimable = client.get_claimable_incentives(&material.id);
+    assert_eq!(claimable.len(), 1);
+    assert_eq!(claimable.get(0).unwrap().id, second.id);
+}
+
+#[test]
+fn test_claimable_incentives_respect_weight_band() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let bulk = client.create_incentive_with_band(&manufacturer, &WasteType::Metal, &100, &10000, &4000, &10000);
+    let small = client.create_incentive_with_band(&manufacturer, &WasteType::Metal, &100, &10000, &0, &1000);
+
+    let desc = String::from_str(&env, "Cans");
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    // Only the band the material falls in is offered, matching what a claim accepts
+    let claimable = client.get_claimable_incentives(&material.id);
+    assert_eq!(claimable.len(), 1);
+    assert_eq!(claimable.get(0).unwrap().id, bulk.id);
+    assert!(client.try_claim_incentive_reward(&small.id, &material.id, &collector).is_err());
+}
+
+#[test]
+fn test_claimable_incentives_follow_reservations() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    client.set_reservation_ttl(&admin, &100);
+
+    // Budget covers exactly one 2kg claim
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &200);
+    let desc = String::from_str(&env, "Cans");
+    let reserved = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    let competing = client.submit_material(&WasteType::Metal, &2000, &recycler, &desc);
+    client.verify_material(&reserved.id, &recycler);
+    client.verify_material(&competing.id, &recycler);
+    client.reserve_incentive(&incentive.id, &reserved.id, &collector);
+
+    // The holder can still claim; the reservation leaves nothing for anyone else
+    assert_eq!(client.get_claimable_incentives(&reserved.id).len(), 1);
+    assert_eq!(client.get_claimable_incentives(&competing.id).len(), 0);
+
+    // Once the reservation expires its budget is on offer again
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    assert_eq!(client.get_claimable_incentives(&competing.id).len(), 1);
+}
+
+#[test]
+fn test_claimable_incentives_respect_region() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    // The shared fixture registers the collector at (0, 0)
+    let nearby = client.create_geofenced_incentive(
+        &manufacturer, &WasteType::Metal, &100, &10000, &-1_000_000, &1_000_000, &-1_000_000, &1_000_000,
+    );
+    client.create_geofenced_incentive(
+        &manufacturer, &WasteType::Metal, &100, &10000, &6_000_000, &7_000_000, &3_000_000, &4_000_000,
+    );
+
+    let desc = String::from_str(&env, "Cans");
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    let claimable = client.get_claimable_incentives(&material.id);
+    assert_eq!(claimable.len(), 1);
+    assert_eq!(claimable.get(0).unwrap().id, nearby.id);
+}
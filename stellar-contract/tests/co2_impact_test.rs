@@ -0,0 +1,45 @@
+#![cfg(test)]
+
+use soroban_sdk::{Env, String};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_with_admin;
+
+#[test]
+fn test_co2_avoided_per_submission_and_participant() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+
+    client.set_co2_coefficient(&admin, &WasteType::Metal, &4000);
+    client.set_co2_coefficient(&admin, &WasteType::Paper, &900);
+    assert_eq!(client.get_co2_coefficient(&WasteType::Metal), 4000);
+    assert_eq!(client.get_co2_coefficient(&WasteType::Glass), 0);
+
+    let desc = String::from_str(&env, "Test");
+    let metal = client.submit_material(&WasteType::Metal, &3000, &collector, &desc);
+    let paper = client.submit_material(&WasteType::Paper, &2500, &collector, &desc);
+    let unverified = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+
+    // 3kg * 4000 and 2kg (partial kilograms truncate) * 900
+    assert_eq!(client.get_submission_co2_avoided(&metal.id), 12000);
+    assert_eq!(client.get_submission_co2_avoided(&paper.id), 1800);
+    assert_eq!(client.get_submission_co2_avoided(&unverified.id), 20000);
+    assert_eq!(client.get_submission_co2_avoided(&999), 0);
+
+    // Only verified submissions count towards the participant
+    assert_eq!(client.get_participant_co2_avoided(&collector), 0);
+    client.verify_material(&metal.id, &recycler);
+    client.verify_material(&paper.id, &recycler);
+    assert_eq!(client.get_participant_co2_avoided(&collector), 13800);
+    assert_eq!(client.get_participant_co2_avoided(&recycler), 0);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_co2_coefficient_requires_admin() {
+    let env = Env::default();
+    let (client, _, collector, _) = setup_with_admin(&env);
+
+    client.set_co2_coefficient(&collector, &WasteType::Metal, &4000);
+}
@@ -0,0 +1,59 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let manufacturer = Address::generate(env);
+    let other_manufacturer = Address::generate(env);
+    let collector = Address::generate(env);
+    let recycler = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+    client.register_participant(&other_manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr2"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    (client, manufacturer, other_manufacturer, collector, recycler)
+}
+
+#[test]
+fn test_committed_budget_tracks_claims_and_deactivation() {
+    let env = Env::default();
+    let (client, manufacturer, other_manufacturer, collector, recycler) = setup(&env);
+
+    let metal = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &5000);
+    let paper = client.create_incentive(&manufacturer, &WasteType::Paper, &20, &3000);
+    client.create_incentive(&other_manufacturer, &WasteType::Glass, &50, &2000);
+
+    assert_eq!(client.get_committed_budget(&manufacturer), 8000);
+    assert_eq!(client.get_committed_budget(&other_manufacturer), 2000);
+    assert_eq!(client.get_total_committed_budget(), 10000);
+
+    // A 2kg metal claim draws 200 from the first incentive
+    let desc = String::from_str(&env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+    client.claim_incentive_reward(&metal.id, &material.id, &collector);
+
+    assert_eq!(client.get_committed_budget(&manufacturer), 7800);
+    assert_eq!(client.get_total_committed_budget(), 9800);
+
+    // Inactive incentives no longer count
+    client.deactivate_incentive(&paper.id, &manufacturer);
+    assert_eq!(client.get_committed_budget(&manufacturer), 4800);
+    assert_eq!(client.get_total_committed_budget(), 6800);
+}
+
+#[test]
+fn test_committed_budget_is_zero_without_incentives() {
+    let env = Env::default();
+    let (client, manufacturer, ..) = setup(&env);
+
+    assert_eq!(client.get_committed_budget(&manufacturer), 0);
+    assert_eq!(client.get_total_committed_budget(), 0);
+}
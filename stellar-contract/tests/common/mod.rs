@@ -0,0 +1,151 @@
+// Each test crate compiles this module on its own and uses only some of the fixtures
+#![allow(dead_code)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+/// Register the contract with a manufacturer, collector and recycler
+/// Returns (client, manufacturer, collector, recycler)
+pub fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let manufacturer = Address::generate(env);
+    let collector = Address::generate(env);
+    let recycler = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    (client, manufacturer, collector, recycler)
+}
+
+/// Register the contract with a collector and recycler
+/// Returns (client, collector, recycler)
+pub fn setup_collector_recycler(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let collector = Address::generate(env);
+    let recycler = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    (client, collector, recycler)
+}
+
+/// Register the contract with an admin, a collector and a recycler
+/// Returns (client, admin, collector, recycler)
+pub fn setup_with_admin(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let collector = Address::generate(env);
+    let recycler = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    (client, admin, collector, recycler)
+}
+
+/// Register the contract with no participants
+pub fn setup_contract(env: &Env) -> ScavengerContractClient<'_> {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    env.mock_all_auths();
+    ScavengerContractClient::new(env, &contract_id)
+}
+
+/// Register the contract with a manufacturer
+/// Returns (client, manufacturer)
+pub fn setup_manufacturer(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let manufacturer = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+
+    (client, manufacturer)
+}
+
+/// Register the contract with a collector
+/// Returns (client, collector)
+pub fn setup_collector(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let collector = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    (client, collector)
+}
+
+/// Register the contract with an admin and no participants
+/// Returns (client, admin)
+pub fn setup_admin(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    client.initialize_admin(&admin);
+
+    (client, admin)
+}
+
+/// Register the contract with an admin and a recycler
+/// Returns (client, admin, recycler)
+pub fn setup_admin_recycler(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    (client, admin, recycler)
+}
+
+/// Register the contract with an admin and a collector
+/// Returns (client, admin, collector)
+pub fn setup_admin_collector(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let collector = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    (client, admin, collector)
+}
+
+/// Register the contract with a recycler
+/// Returns (client, recycler)
+pub fn setup_recycler(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let recycler = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    (client, recycler)
+}
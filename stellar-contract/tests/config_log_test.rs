@@ -0,0 +1,143 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol, Vec,
+};
+use stellar_scavngr_contract::{ConfigChange, ConfigValue};
+
+mod common;
+use common::setup_admin;
+
+#[test]
+fn test_log_captures_old_and_new_values() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let charity = Address::generate(&env);
+    let new_charity = Address::generate(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    client.set_percentages(&admin, &30, &20);
+    client.set_charity_contract(&admin, &charity);
+
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.set_collector_percentage(&admin, &40);
+    client.set_charity_contract(&admin, &new_charity);
+    client.set_min_weight(&admin, &500);
+
+    let log = client.get_config_log(&0, &10);
+    assert_eq!(log.len(), 6);
+
+    let collector = log.get(0).unwrap();
+    assert_eq!(collector.admin, admin);
+    assert_eq!(collector.field, Symbol::new(&env, "collector_pct"));
+    assert_eq!(collector.old_value, ConfigValue::Unset);
+    assert_eq!(collector.new_value, ConfigValue::Number(30));
+    assert_eq!(collector.timestamp, 1000);
+
+    let owner = log.get(1).unwrap();
+    assert_eq!(owner.field, Symbol::new(&env, "owner_pct"));
+    assert_eq!(owner.new_value, ConfigValue::Number(20));
+
+    let first_charity = log.get(2).unwrap();
+    assert_eq!(first_charity.field, Symbol::new(&env, "charity"));
+    assert_eq!(first_charity.old_value, ConfigValue::Unset);
+    assert_eq!(first_charity.new_value, ConfigValue::Address(charity.clone()));
+
+    let collector_update = log.get(3).unwrap();
+    assert_eq!(collector_update.old_value, ConfigValue::Number(30));
+    assert_eq!(collector_update.new_value, ConfigValue::Number(40));
+    assert_eq!(collector_update.timestamp, 2000);
+
+    let charity_update = log.get(4).unwrap();
+    assert_eq!(charity_update.old_value, ConfigValue::Address(charity));
+    assert_eq!(charity_update.new_value, ConfigValue::Address(new_charity));
+
+    // Unconfigured settings log their default as the old value
+    let min_weight = log.get(5).unwrap();
+    assert_eq!(min_weight.field, Symbol::new(&env, "min_weight"));
+    assert_eq!(min_weight.old_value, ConfigValue::Number(100));
+    assert_eq!(min_weight.new_value, ConfigValue::Number(500));
+}
+
+#[test]
+fn test_log_captures_pause_and_speed_bonus() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+
+    client.pause(&admin);
+    client.unpause(&admin);
+    client.set_speed_bonus(&admin, &3600, &10);
+
+    let log = client.get_config_log(&0, &10);
+    assert_eq!(log.len(), 4);
+
+    assert_eq!(log.get(0).unwrap().old_value, ConfigValue::Flag(false));
+    assert_eq!(log.get(0).unwrap().new_value, ConfigValue::Flag(true));
+    assert_eq!(log.get(1).unwrap().old_value, ConfigValue::Flag(true));
+    assert_eq!(log.get(1).unwrap().new_value, ConfigValue::Flag(false));
+
+    assert_eq!(log.get(2).unwrap().field, Symbol::new(&env, "bonus_window"));
+    assert_eq!(log.get(2).unwrap().new_value, ConfigValue::Number(3600));
+    assert_eq!(log.get(3).unwrap().field, Symbol::new(&env, "bonus_percent"));
+    assert_eq!(log.get(3).unwrap().new_value, ConfigValue::Number(10));
+}
+
+#[test]
+fn test_log_records_approving_admin_for_proposals() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+
+    let mut admins = Vec::new(&env);
+    admins.push_back(Address::generate(&env));
+    admins.push_back(Address::generate(&env));
+    client.set_admins(&admin, &admins, &2);
+
+    let proposal_id = client
+        .propose_config_change(&admins.get(0).unwrap(), &ConfigChange::Percentages(40, 30));
+    client.approve_config_change(&admins.get(1).unwrap(), &proposal_id);
+
+    let log = client.get_config_log(&0, &10);
+    assert_eq!(log.len(), 3);
+    assert_eq!(log.get(0).unwrap().field, Symbol::new(&env, "admin_threshold"));
+    assert_eq!(log.get(0).unwrap().new_value, ConfigValue::Number(2));
+
+    let collector = log.get(1).unwrap();
+    assert_eq!(collector.admin, admins.get(1).unwrap());
+    assert_eq!(collector.new_value, ConfigValue::Number(40));
+}
+
+#[test]
+fn test_log_pagination() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+
+    for grams in 1..=5u64 {
+        client.set_min_weight(&admin, &(grams * 100));
+    }
+
+    let page = client.get_config_log(&1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().new_value, ConfigValue::Number(200));
+    assert_eq!(page.get(1).unwrap().new_value, ConfigValue::Number(300));
+
+    assert_eq!(client.get_config_log(&4, &10).len(), 1);
+    assert_eq!(client.get_config_log(&5, &10).len(), 0);
+}
+
+#[test]
+fn test_log_keeps_only_latest_entries() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, admin) = setup_admin(&env);
+
+    for grams in 1..=105u64 {
+        client.set_min_weight(&admin, &grams);
+    }
+
+    let log = client.get_config_log(&0, &100);
+    assert_eq!(log.len(), 100);
+    assert_eq!(log.get(0).unwrap().new_value, ConfigValue::Number(6));
+    assert_eq!(log.get(99).unwrap().new_value, ConfigValue::Number(105));
+    assert_eq!(client.get_config_log(&100, &10).len(), 0);
+}
@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ContractConfig, ScavengerContract, ScavengerContractClient};
+
+fn assert_matches_getters(client: &ScavengerContractClient, config: &ContractConfig) {
+    assert_eq!(config.admin, Some(client.get_admin()));
+    assert_eq!(config.charity, client.get_charity_contract());
+    assert_eq!(config.collector_percentage, client.get_collector_percentage());
+    assert_eq!(config.owner_percentage, client.get_owner_percentage());
+    assert_eq!(config.min_weight, client.get_min_weight());
+    assert_eq!(config.paused, client.is_paused());
+}
+
+#[test]
+fn test_config_before_initialization() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let config = client.get_config();
+    assert_eq!(config.admin, None);
+    assert_eq!(config.charity, None);
+    assert_eq!(config.collector_percentage, None);
+    assert_eq!(config.owner_percentage, None);
+    assert_eq!(config.min_weight, 100);
+    assert!(!config.paused);
+}
+
+#[test]
+fn test_config_matches_individual_getters() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let charity = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.set_charity_contract(&admin, &charity);
+    client.set_percentages(&admin, &30, &20);
+    assert_matches_getters(&client, &client.get_config());
+
+    client.set_collector_percentage(&admin, &40);
+    client.set_min_weight(&admin, &250);
+    client.pause(&admin);
+
+    let config = client.get_config();
+    assert_matches_getters(&client, &config);
+    assert_eq!(config.collector_percentage, Some(40));
+    assert_eq!(config.min_weight, 250);
+    assert!(config.paused);
+}
@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+mod common;
+use common::setup_contract;
+
+#[test]
+fn test_register_participant_accepts_boundary_coordinates() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+
+    let north_east = Address::generate(&env);
+    let south_west = Address::generate(&env);
+
+    let p1 = client.register_participant(
+        &north_east,
+        &ParticipantRole::Recycler,
+        &symbol_short!("ne"),
+        &90_000_000,
+        &180_000_000,
+    );
+    let p2 = client.register_participant(
+        &south_west,
+        &ParticipantRole::Recycler,
+        &symbol_short!("sw"),
+        &-90_000_000,
+        &-180_000_000,
+    );
+
+    assert_eq!(p1.latitude, 90_000_000);
+    assert_eq!(p1.longitude, 180_000_000);
+    assert_eq!(p2.latitude, -90_000_000);
+    assert_eq!(p2.longitude, -180_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Invalid coordinates")]
+fn test_register_participant_rejects_out_of_range_longitude() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("user"), &0, &180_000_001);
+}
+
+#[test]
+#[should_panic(expected = "Invalid coordinates")]
+fn test_update_location_rejects_out_of_range_latitude() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("user"), &0, &0);
+    client.update_location(&user, &-90_000_001, &0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid coordinates")]
+fn test_recycle_waste_rejects_out_of_range_longitude() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("user"), &0, &0);
+    client.recycle_waste(&WasteType::Glass, &1000, &user, &0, &-180_000_001);
+}
+
+#[test]
+#[should_panic(expected = "Invalid coordinates")]
+fn test_transfer_waste_v2_rejects_out_of_range_longitude() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+    let recycler = Address::generate(&env);
+    let collector = Address::generate(&env);
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    let waste_id = client.recycle_waste(&WasteType::Glass, &1000, &recycler, &0, &0);
+
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &200_000_000);
+}
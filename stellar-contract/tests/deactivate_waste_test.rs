@@ -1,26 +1,16 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env};
-use stellar_contract::{ScavengerContract, ScavengerContractClient};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
 
-fn create_test_contract(env: &Env) -> (ScavengerContractClient, Address, Address, Address) {
+fn create_test_contract(env: &Env) -> (ScavengerContractClient<'_>, Address) {
     let contract_id = env.register_contract(None, ScavengerContract);
     let client = ScavengerContractClient::new(env, &contract_id);
 
     let admin = Address::generate(env);
-    let token_address = Address::generate(env);
-    let charity_address = Address::generate(env);
-
-    client.initialize(
-        &admin,
-        &token_address,
-        &charity_address,
-        &50,
-        &30,
-        &20,
-    );
+    client.initialize_admin(&admin);
 
-    (client, admin, token_address, charity_address)
+    (client, admin)
 }
 
 #[test]
@@ -28,31 +18,26 @@ fn test_deactivate_waste() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, admin, _token, _charity) = create_test_contract(&env);
+    let (client, admin) = create_test_contract(&env);
 
     let owner = Address::generate(&env);
 
-    // Register owner as collector
-    client.register_participant(&owner, &stellar_contract::Role::Collector);
+    // Register owner as recycler
+    client.register_participant(&owner, &ParticipantRole::Recycler, &symbol_short!("owner"), &0, &0);
 
     // Register waste
-    let waste = client.register_waste(
-        &owner,
-        &stellar_contract::WasteType::Plastic,
-        &1000,
-        &45_000_000,
-        &-93_000_000,
-    );
+    let waste = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
 
     // Verify waste is active
-    assert_eq!(waste.is_active, true);
+    let (active, _) = client.get_waste_full(&waste).unwrap();
+    assert!(active.is_active);
 
     // Deactivate waste as admin
-    let deactivated = client.deactivate_waste(&waste.waste_id, &admin);
+    let deactivated = client.deactivate_waste(&waste, &admin);
 
     // Verify waste is deactivated
-    assert_eq!(deactivated.is_active, false);
-    assert_eq!(deactivated.waste_id, waste.waste_id);
+    assert!(!deactivated.is_active);
+    assert_eq!(deactivated.waste_id, waste);
 }
 
 #[test]
@@ -61,25 +46,19 @@ fn test_deactivate_waste_non_admin() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, _admin, _token, _charity) = create_test_contract(&env);
+    let (client, _admin) = create_test_contract(&env);
 
     let owner = Address::generate(&env);
     let non_admin = Address::generate(&env);
 
-    // Register owner as collector
-    client.register_participant(&owner, &stellar_contract::Role::Collector);
+    // Register owner as recycler
+    client.register_participant(&owner, &ParticipantRole::Recycler, &symbol_short!("owner"), &0, &0);
 
     // Register waste
-    let waste = client.register_waste(
-        &owner,
-        &stellar_contract::WasteType::Plastic,
-        &1000,
-        &45_000_000,
-        &-93_000_000,
-    );
+    let waste = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
 
     // Try to deactivate as non-admin (should panic)
-    client.deactivate_waste(&waste.waste_id, &non_admin);
+    client.deactivate_waste(&waste, &non_admin);
 }
 
 #[test]
@@ -88,27 +67,21 @@ fn test_deactivate_already_deactivated_waste() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, admin, _token, _charity) = create_test_contract(&env);
+    let (client, admin) = create_test_contract(&env);
 
     let owner = Address::generate(&env);
 
-    // Register owner as collector
-    client.register_participant(&owner, &stellar_contract::Role::Collector);
+    // Register owner as recycler
+    client.register_participant(&owner, &ParticipantRole::Recycler, &symbol_short!("owner"), &0, &0);
 
     // Register waste
-    let waste = client.register_waste(
-        &owner,
-        &stellar_contract::WasteType::Plastic,
-        &1000,
-        &45_000_000,
-        &-93_000_000,
-    );
+    let waste = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
 
     // Deactivate waste
-    client.deactivate_waste(&waste.waste_id, &admin);
+    client.deactivate_waste(&waste, &admin);
 
     // Try to deactivate again (should panic)
-    client.deactivate_waste(&waste.waste_id, &admin);
+    client.deactivate_waste(&waste, &admin);
 }
 
 #[test]
@@ -117,7 +90,7 @@ fn test_deactivate_nonexistent_waste() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, admin, _token, _charity) = create_test_contract(&env);
+    let (client, admin) = create_test_contract(&env);
 
     // Try to deactivate non-existent waste (should panic)
     client.deactivate_waste(&999, &admin);
@@ -128,47 +101,30 @@ fn test_deactivated_waste_not_counted_in_totals() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, admin, _token, _charity) = create_test_contract(&env);
+    let (client, admin) = create_test_contract(&env);
 
     let owner = Address::generate(&env);
 
-    // Register owner as collector
-    client.register_participant(&owner, &stellar_contract::Role::Collector);
+    // Register owner as recycler
+    client.register_participant(&owner, &ParticipantRole::Recycler, &symbol_short!("owner"), &0, &0);
 
     // Register two waste items
-    let waste1 = client.register_waste(
-        &owner,
-        &stellar_contract::WasteType::Plastic,
-        &1000,
-        &45_000_000,
-        &-93_000_000,
-    );
+    let waste1 = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
 
-    let waste2 = client.register_waste(
-        &owner,
-        &stellar_contract::WasteType::Metal,
-        &2000,
-        &45_000_000,
-        &-93_000_000,
-    );
+    let _waste2 = client.recycle_waste(&WasteType::Metal, &2000, &owner, &45_000_000, &-93_000_000);
 
     // Get initial stats
-    let initial_stats = client.get_stats();
-    let initial_weight = initial_stats.total_weight;
+    let (_, initial_weight, _) = client.get_supply_chain_stats();
+    assert_eq!(initial_weight, 3000);
 
     // Deactivate first waste
-    client.deactivate_waste(&waste1.waste_id, &admin);
+    client.deactivate_waste(&waste1, &admin);
 
     // Get updated stats
-    let updated_stats = client.get_stats();
-    let updated_weight = updated_stats.total_weight;
-
-    // Verify deactivated waste is not counted
-    // The weight should decrease by waste1's weight
-    assert!(updated_weight < initial_weight);
-    
-    // Verify waste2 is still counted
-    assert!(updated_weight > 0);
+    let (_, updated_weight, _) = client.get_supply_chain_stats();
+
+    // Verify deactivated waste is not counted while waste2 still is
+    assert_eq!(updated_weight, 2000);
 }
 
 #[test]
@@ -177,30 +133,24 @@ fn test_deactivated_waste_cannot_be_transferred() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, admin, _token, _charity) = create_test_contract(&env);
+    let (client, admin) = create_test_contract(&env);
 
     let owner = Address::generate(&env);
     let recipient = Address::generate(&env);
 
     // Register participants
-    client.register_participant(&owner, &stellar_contract::Role::Collector);
-    client.register_participant(&recipient, &stellar_contract::Role::Manufacturer);
+    client.register_participant(&owner, &ParticipantRole::Recycler, &symbol_short!("owner"), &0, &0);
+    client.register_participant(&recipient, &ParticipantRole::Manufacturer, &symbol_short!("recv"), &0, &0);
 
     // Register waste
-    let waste = client.register_waste(
-        &owner,
-        &stellar_contract::WasteType::Plastic,
-        &1000,
-        &45_000_000,
-        &-93_000_000,
-    );
+    let waste = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
 
     // Deactivate waste
-    client.deactivate_waste(&waste.waste_id, &admin);
+    client.deactivate_waste(&waste, &admin);
 
     // Try to transfer deactivated waste (should panic)
     client.transfer_waste_v2(
-        &waste.waste_id,
+        &waste,
         &owner,
         &recipient,
         &45_000_000,
@@ -215,26 +165,20 @@ fn test_deactivated_waste_cannot_be_confirmed() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, admin, _token, _charity) = create_test_contract(&env);
+    let (client, admin) = create_test_contract(&env);
 
     let owner = Address::generate(&env);
     let confirmer = Address::generate(&env);
 
-    // Register owner as collector
-    client.register_participant(&owner, &stellar_contract::Role::Collector);
+    // Register owner as recycler
+    client.register_participant(&owner, &ParticipantRole::Recycler, &symbol_short!("owner"), &0, &0);
 
     // Register waste
-    let waste = client.register_waste(
-        &owner,
-        &stellar_contract::WasteType::Plastic,
-        &1000,
-        &45_000_000,
-        &-93_000_000,
-    );
+    let waste = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
 
     // Deactivate waste
-    client.deactivate_waste(&waste.waste_id, &admin);
+    client.deactivate_waste(&waste, &admin);
 
     // Try to confirm deactivated waste (should panic)
-    client.confirm_waste_details(&waste.waste_id, &confirmer);
+    client.confirm_waste_details(&waste, &confirmer);
 }
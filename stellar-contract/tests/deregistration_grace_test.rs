@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+const GRACE: u64 = 3600;
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let collector = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.set_deregistration_grace(&admin, &GRACE);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    (client, collector)
+}
+
+#[test]
+fn test_participant_stays_registered_during_grace() {
+    let env = Env::default();
+    let (client, collector) = setup(&env);
+
+    let participant = client.deregister_participant(&collector);
+    assert!(participant.is_registered);
+    assert_eq!(client.get_deregister_at(&collector), Some(1000 + GRACE));
+    assert!(client.is_participant_registered(&collector));
+}
+
+#[test]
+fn test_finalize_after_grace() {
+    let env = Env::default();
+    let (client, collector) = setup(&env);
+
+    client.deregister_participant(&collector);
+    env.ledger().with_mut(|li| li.timestamp = 1000 + GRACE);
+
+    let participant = client.finalize_deregistration(&collector);
+    assert!(!participant.is_registered);
+    assert!(!client.get_participant(&collector).unwrap().is_registered);
+    assert_eq!(client.get_deregister_at(&collector), None);
+}
+
+#[test]
+#[should_panic(expected = "Grace period not elapsed")]
+fn test_finalize_during_grace_panics() {
+    let env = Env::default();
+    let (client, collector) = setup(&env);
+
+    client.deregister_participant(&collector);
+    env.ledger().with_mut(|li| li.timestamp = 1000 + GRACE - 1);
+
+    client.finalize_deregistration(&collector);
+}
+
+#[test]
+fn test_cancel_during_grace() {
+    let env = Env::default();
+    let (client, collector) = setup(&env);
+
+    client.deregister_participant(&collector);
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+
+    let participant = client.cancel_deregistration(&collector);
+    assert!(participant.is_registered);
+    assert_eq!(client.get_deregister_at(&collector), None);
+
+    // A cancelled request cannot be finalized later
+    env.ledger().with_mut(|li| li.timestamp = 1000 + GRACE);
+    assert!(client.try_finalize_deregistration(&collector).is_err());
+    assert!(client.get_participant(&collector).unwrap().is_registered);
+}
+
+#[test]
+#[should_panic(expected = "No pending deregistration")]
+fn test_cancel_without_request_panics() {
+    let env = Env::default();
+    let (client, collector) = setup(&env);
+
+    client.cancel_deregistration(&collector);
+}
+
+#[test]
+#[should_panic(expected = "Deregistration already pending")]
+fn test_duplicate_request_panics() {
+    let env = Env::default();
+    let (client, collector) = setup(&env);
+
+    client.deregister_participant(&collector);
+    client.deregister_participant(&collector);
+}
+
+#[test]
+fn test_zero_grace_deregisters_immediately() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+    let collector = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    assert_eq!(client.get_deregistration_grace(), 0);
+    let participant = client.deregister_participant(&collector);
+    assert!(!participant.is_registered);
+    assert_eq!(client.get_deregister_at(&collector), None);
+}
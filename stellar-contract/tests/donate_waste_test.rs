@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup_admin_recycler;
+
+fn confirmed_waste(env: &Env, client: &ScavengerContractClient, weight: u128, owner: &Address) -> u128 {
+    let waste_id = client.recycle_waste(&WasteType::Metal, &weight, owner, &0, &0);
+    client.confirm_waste_details(&waste_id, &Address::generate(env));
+    waste_id
+}
+
+#[test]
+fn test_donate_waste_moves_ownership_and_awards_bonus() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup_admin_recycler(&env);
+    let charity = Address::generate(&env);
+    client.set_charity_contract(&admin, &charity);
+    client.set_donation_bonus(&admin, &25);
+    assert_eq!(client.get_donation_bonus(), 25);
+
+    let waste_id = confirmed_waste(&env, &client, 2000, &recycler);
+    let transfer = client.donate_waste(&waste_id, &recycler);
+    assert_eq!(transfer.from, recycler);
+    assert_eq!(transfer.to, charity);
+    assert_eq!(transfer.note, String::from_str(&env, "donated"));
+
+    let (waste, history) = client.get_waste_full(&waste_id).unwrap();
+    assert_eq!(waste.current_owner, charity);
+    assert_eq!(history.len(), 1);
+
+    // 2kg * 25 points per kg
+    assert_eq!(client.get_stats(&recycler).unwrap().total_points, 50);
+
+    // The bonus survives a stats rebuild
+    assert_eq!(client.recompute_stats(&admin, &recycler).total_points, 50);
+}
+
+#[test]
+fn test_donate_waste_without_bonus() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup_admin_recycler(&env);
+    let charity = Address::generate(&env);
+    client.set_charity_contract(&admin, &charity);
+
+    let waste_id = confirmed_waste(&env, &client, 1000, &recycler);
+    client.donate_waste(&waste_id, &recycler);
+
+    assert_eq!(client.get_waste_full(&waste_id).unwrap().0.current_owner, charity);
+    assert!(client.get_stats(&recycler).is_none());
+}
+
+#[test]
+fn test_second_donation_pays_no_bonus() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup_admin_recycler(&env);
+    let first_charity = Address::generate(&env);
+    let second_charity = Address::generate(&env);
+    client.set_charity_contract(&admin, &first_charity);
+    client.set_donation_bonus(&admin, &25);
+
+    let waste_id = confirmed_waste(&env, &client, 2000, &recycler);
+    client.donate_waste(&waste_id, &recycler);
+
+    // The first charity passes the waste on once the charity address changes
+    client.set_charity_contract(&admin, &second_charity);
+    client.donate_waste(&waste_id, &first_charity);
+
+    assert_eq!(client.get_waste_full(&waste_id).unwrap().0.current_owner, second_charity);
+    assert!(client.get_stats(&first_charity).is_none());
+    assert_eq!(client.get_stats(&recycler).unwrap().total_points, 50);
+}
+
+#[test]
+#[should_panic(expected = "Waste not confirmed")]
+fn test_donate_waste_requires_confirmation() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup_admin_recycler(&env);
+    client.set_charity_contract(&admin, &Address::generate(&env));
+    client.set_donation_bonus(&admin, &25);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &2000, &recycler, &0, &0);
+    client.donate_waste(&waste_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Charity address not set")]
+fn test_donate_waste_requires_charity() {
+    let env = Env::default();
+    let (client, _admin, recycler) = setup_admin_recycler(&env);
+
+    let waste_id = confirmed_waste(&env, &client, 2000, &recycler);
+    client.donate_waste(&waste_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Caller does not own waste")]
+fn test_donate_waste_requires_owner() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup_admin_recycler(&env);
+    client.set_charity_contract(&admin, &Address::generate(&env));
+
+    let waste_id = confirmed_waste(&env, &client, 2000, &recycler);
+    client.donate_waste(&waste_id, &Address::generate(&env));
+}
@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Events, Address, Env, IntoVal, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn verified_material(
+    env: &Env,
+    client: &ScavengerContractClient,
+    weight: u64,
+    collector: &Address,
+    recycler: &Address,
+) -> u64 {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &weight, collector, &desc);
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+#[test]
+fn test_claims_accumulate_in_escrow() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let first = verified_material(&env, &client, 2000, &collector, &recycler);
+    let second = verified_material(&env, &client, 3000, &collector, &recycler);
+
+    assert_eq!(client.get_escrow_balance(&collector), 0);
+    client.claim_incentive_reward(&incentive.id, &first, &collector);
+    assert_eq!(client.get_escrow_balance(&collector), 200);
+    client.claim_incentive_reward(&incentive.id, &second, &collector);
+    assert_eq!(client.get_escrow_balance(&collector), 500);
+}
+
+#[test]
+fn test_withdraw_combined_balance_once() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let first = verified_material(&env, &client, 2000, &collector, &recycler);
+    let second = verified_material(&env, &client, 3000, &collector, &recycler);
+    client.claim_incentive_reward(&incentive.id, &first, &collector);
+    client.claim_incentive_reward(&incentive.id, &second, &collector);
+
+    assert_eq!(client.withdraw_escrow(&collector), 500);
+    assert_eq!(client.get_escrow_balance(&collector), 0);
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.1, (symbol_short!("v1"), symbol_short!("esc_wdrw"), collector.clone()).into_val(&env));
+
+    // Nothing left to withdraw
+    assert!(client.try_withdraw_escrow(&collector).is_err());
+}
+
+#[test]
+fn test_withdraw_credits_exactly_escrowed_amount() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let material = verified_material(&env, &client, 2000, &collector, &recycler);
+
+    // Claiming only moves the reward into escrow
+    let (tokens_before, points_before, _) = client.get_token_summary(&collector);
+    client.claim_incentive_reward(&incentive.id, &material, &collector);
+    assert_eq!(client.get_token_summary(&collector), (tokens_before, points_before, 200));
+
+    assert_eq!(client.withdraw_escrow(&collector), 200);
+    let (tokens_after, points_after, escrow_after) = client.get_token_summary(&collector);
+    assert_eq!(tokens_after - tokens_before, 200);
+    assert_eq!(points_after - points_before, 200);
+    assert_eq!(escrow_after, 0);
+}
+
+#[test]
+#[should_panic(expected = "No escrowed rewards")]
+fn test_withdraw_empty_escrow_panics() {
+    let env = Env::default();
+    let (client, _, collector, _) = setup(&env);
+
+    client.withdraw_escrow(&collector);
+}
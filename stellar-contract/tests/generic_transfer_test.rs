@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{
+    ScavengerContract, ScavengerContractClient, TransferItemType, TransferStatus,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let sender = Address::generate(env);
+    let recipient = Address::generate(env);
+    env.mock_all_auths();
+
+    (client, sender, recipient)
+}
+
+#[test]
+fn test_record_generic_transfer() {
+    let env = Env::default();
+    let (client, sender, recipient) = setup(&env);
+    let note = String::from_str(&env, "Token payout");
+
+    let first = client.record_generic_transfer(&sender, &recipient, &TransferItemType::Token, &7, &250, &note);
+    let second = client.record_generic_transfer(&sender, &recipient, &TransferItemType::Material, &8, &1, &note);
+    assert_eq!(first, 1);
+    assert_eq!(second, 2);
+
+    let record = client.get_transfer_record(&first).unwrap();
+    assert_eq!(record.from, sender);
+    assert_eq!(record.to, recipient);
+    assert_eq!(record.item_type, TransferItemType::Token);
+    assert_eq!(record.item_id, 7);
+    assert_eq!(record.amount, 250);
+    assert_eq!(record.status, TransferStatus::Pending);
+    assert_eq!(record.note, note);
+}
+
+#[test]
+#[should_panic(expected = "Amount must be greater than zero")]
+fn test_zero_amount_rejected() {
+    let env = Env::default();
+    let (client, sender, recipient) = setup(&env);
+    let note = String::from_str(&env, "Empty");
+
+    client.record_generic_transfer(&sender, &recipient, &TransferItemType::Token, &1, &0, &note);
+}
+
+#[test]
+#[should_panic(expected = "Sender and recipient cannot be the same")]
+fn test_self_transfer_rejected() {
+    let env = Env::default();
+    let (client, sender, _) = setup(&env);
+    let note = String::from_str(&env, "Loop");
+
+    client.record_generic_transfer(&sender, &sender, &TransferItemType::Token, &1, &10, &note);
+}
+
+#[test]
+fn test_update_transfer_status() {
+    let env = Env::default();
+    let (client, sender, recipient) = setup(&env);
+    let note = String::from_str(&env, "Handoff");
+
+    let id = client.record_generic_transfer(&sender, &recipient, &TransferItemType::Ownership, &3, &1, &note);
+
+    let record = client.update_transfer_status(&id, &TransferStatus::InProgress);
+    assert_eq!(record.status, TransferStatus::InProgress);
+
+    let record = client.update_transfer_status(&id, &TransferStatus::Completed);
+    assert_eq!(record.status, TransferStatus::Completed);
+    assert_eq!(client.get_transfer_record(&id).unwrap().status, TransferStatus::Completed);
+}
+
+#[test]
+#[should_panic(expected = "Transfer status is final")]
+fn test_final_status_cannot_change() {
+    let env = Env::default();
+    let (client, sender, recipient) = setup(&env);
+    let note = String::from_str(&env, "Handoff");
+
+    let id = client.record_generic_transfer(&sender, &recipient, &TransferItemType::Incentive, &3, &1, &note);
+    client.update_transfer_status(&id, &TransferStatus::Cancelled);
+    client.update_transfer_status(&id, &TransferStatus::InProgress);
+}
+
+#[test]
+#[should_panic(expected = "Transfer record not found")]
+fn test_update_missing_record_panics() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    client.update_transfer_status(&1, &TransferStatus::Completed);
+}
@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String, Symbol};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+// Lagos-area bounding box, scaled by 1e6
+const MIN_LAT: i128 = 6_000_000;
+const MAX_LAT: i128 = 7_000_000;
+const MIN_LON: i128 = 3_000_000;
+const MAX_LON: i128 = 4_000_000;
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let manufacturer = Address::generate(env);
+    let recycler = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    (client, manufacturer, recycler)
+}
+
+fn collector_at(env: &Env, client: &ScavengerContractClient, latitude: i128, longitude: i128) -> Address {
+    let collector = Address::generate(env);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &latitude, &longitude);
+    collector
+}
+
+fn verified_material(env: &Env, client: &ScavengerContractClient, submitter: &Address, recycler: &Address) -> u64 {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &2000, submitter, &desc);
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+#[test]
+fn test_claim_inside_region() {
+    let env = Env::default();
+    let (client, manufacturer, recycler) = setup(&env);
+
+    let incentive = client.create_geofenced_incentive(
+        &manufacturer, &WasteType::Metal, &100, &10000, &MIN_LAT, &MAX_LAT, &MIN_LON, &MAX_LON,
+    );
+    assert_eq!((incentive.min_lat, incentive.max_lon), (MIN_LAT, MAX_LON));
+
+    let collector = collector_at(&env, &client, 6_500_000, 3_400_000);
+    let material = verified_material(&env, &client, &collector, &recycler);
+
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &material, &collector), 200);
+}
+
+#[test]
+#[should_panic(expected = "Outside incentive region")]
+fn test_claim_outside_region() {
+    let env = Env::default();
+    let (client, manufacturer, recycler) = setup(&env);
+
+    let incentive = client.create_geofenced_incentive(
+        &manufacturer, &WasteType::Metal, &100, &10000, &MIN_LAT, &MAX_LAT, &MIN_LON, &MAX_LON,
+    );
+
+    let collector = collector_at(&env, &client, 9_000_000, 7_500_000);
+    let material = verified_material(&env, &client, &collector, &recycler);
+    assert_eq!(
+        client.check_claim_eligibility(&incentive.id, &material, &collector),
+        Symbol::new(&env, "outside_region")
+    );
+    assert_eq!(client.preview_claim(&incentive.id, &material), 0);
+
+    client.claim_incentive_reward(&incentive.id, &material, &collector);
+}
+
+#[test]
+fn test_promoted_waste_location_takes_precedence() {
+    let env = Env::default();
+    let (client, manufacturer, recycler) = setup(&env);
+
+    let incentive = client.create_geofenced_incentive(
+        &manufacturer, &WasteType::Metal, &100, &10000, &MIN_LAT, &MAX_LAT, &MIN_LON, &MAX_LON,
+    );
+
+    // The collector is registered outside the box but the waste was picked up inside it
+    let collector = collector_at(&env, &client, 9_000_000, 7_500_000);
+    let material = verified_material(&env, &client, &collector, &recycler);
+    client.promote_material_to_waste(&material, &6_200_000, &3_100_000);
+
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &material, &collector), 200);
+}
+
+#[test]
+fn test_unbounded_incentive_ignores_location() {
+    let env = Env::default();
+    let (client, manufacturer, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let collector = collector_at(&env, &client, -45_000_000, 170_000_000);
+    let material = verified_material(&env, &client, &collector, &recycler);
+
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &material, &collector), 200);
+}
+
+#[test]
+#[should_panic(expected = "Invalid incentive region")]
+fn test_inverted_region_rejected() {
+    let env = Env::default();
+    let (client, manufacturer, _) = setup(&env);
+
+    client.create_geofenced_incentive(
+        &manufacturer, &WasteType::Metal, &100, &10000, &MAX_LAT, &MIN_LAT, &MIN_LON, &MAX_LON,
+    );
+}
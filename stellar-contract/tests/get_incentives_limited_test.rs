@@ -0,0 +1,73 @@
+#![cfg(test)]
+
+use soroban_sdk::Env;
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_manufacturer;
+
+#[test]
+fn test_get_incentives_limited_with_many_incentives() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    // Rewards 1..=60, created in a scrambled order
+    for i in 0..60u64 {
+        let reward = (i * 37) % 60 + 1;
+        client.create_incentive(&manufacturer, &WasteType::Plastic, &reward, &10000);
+    }
+
+    // Bounded query fits within the default budget
+    env.budget().reset_default();
+    let top = client.get_incentives_limited(&WasteType::Plastic, &5);
+    assert_eq!(top.len(), 5);
+    for (i, incentive) in top.iter().enumerate() {
+        assert_eq!(incentive.reward_points, 60 - i as u64);
+    }
+
+    // Legacy query still returns every active incentive sorted descending
+    env.budget().reset_unlimited();
+    let all = client.get_incentives(&WasteType::Plastic);
+    assert_eq!(all.len(), 60);
+    for i in 1..all.len() {
+        assert!(all.get(i - 1).unwrap().reward_points >= all.get(i).unwrap().reward_points);
+    }
+    assert_eq!(all.get(0).unwrap().reward_points, 60);
+    assert_eq!(all.get(59).unwrap().reward_points, 1);
+}
+
+#[test]
+fn test_get_incentives_is_capped_at_page_size() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    for reward in 1..=105u64 {
+        client.create_incentive(&manufacturer, &WasteType::Paper, &reward, &10000);
+    }
+
+    let all = client.get_incentives(&WasteType::Paper);
+    assert_eq!(all.len(), 100);
+    assert_eq!(all.get(0).unwrap().reward_points, 105);
+    assert_eq!(all.get(99).unwrap().reward_points, 6);
+}
+
+#[test]
+fn test_get_incentives_limited_skips_inactive_and_other_types() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let best = client.create_incentive(&manufacturer, &WasteType::Metal, &90, &10000);
+    let second = client.create_incentive(&manufacturer, &WasteType::Metal, &50, &10000);
+    client.create_incentive(&manufacturer, &WasteType::Glass, &99, &10000);
+    let inactive = client.create_incentive(&manufacturer, &WasteType::Metal, &95, &10000);
+    client.deactivate_incentive(&inactive.id, &manufacturer);
+
+    let top = client.get_incentives_limited(&WasteType::Metal, &10);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top.get(0).unwrap().id, best.id);
+    assert_eq!(top.get(1).unwrap().id, second.id);
+
+    assert_eq!(client.get_incentives_limited(&WasteType::Metal, &0).len(), 0);
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let user1 = Address::generate(env);
+    let user2 = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("user1"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("user2"), &0, &0);
+
+    (client, user1, user2)
+}
+
+#[test]
+fn test_paged_returns_first_page_and_cursor() {
+    let env = Env::default();
+    let (client, user1, _) = setup(&env);
+    let description = String::from_str(&env, "Test");
+
+    for _ in 0..5 {
+        client.submit_material(&WasteType::Paper, &1000, &user1, &description);
+    }
+
+    let (page, cursor) = client.get_participant_wastes_paged(&user1, &1, &3);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap(), 1);
+    assert_eq!(page.get(2).unwrap(), 3);
+    assert_eq!(cursor, Some(4));
+
+    let (page, cursor) = client.get_participant_wastes_paged(&user1, &4, &3);
+    assert_eq!(page.len(), 2);
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn test_paged_reconstructs_full_list() {
+    let env = Env::default();
+    let (client, user1, user2) = setup(&env);
+    let description = String::from_str(&env, "Test");
+
+    for i in 0..25 {
+        let owner = if i % 3 == 0 { &user2 } else { &user1 };
+        client.submit_material(&WasteType::Plastic, &1000, owner, &description);
+    }
+
+    let mut collected: Vec<u64> = Vec::new(&env);
+    let mut cursor = Some(1u64);
+    while let Some(start_id) = cursor {
+        let (page, next) = client.get_participant_wastes_paged(&user1, &start_id, &7);
+        for id in page.iter() {
+            collected.push_back(id);
+        }
+        cursor = next;
+    }
+
+    assert_eq!(collected, client.get_participant_wastes(&user1));
+}
+
+#[test]
+fn test_paged_limit_is_capped() {
+    let env = Env::default();
+    let (client, user1, _) = setup(&env);
+    let description = String::from_str(&env, "Test");
+    env.budget().reset_unlimited();
+
+    for _ in 0..105 {
+        client.submit_material(&WasteType::Glass, &1000, &user1, &description);
+    }
+
+    let (page, cursor) = client.get_participant_wastes_paged(&user1, &1, &1000);
+    assert_eq!(page.len(), 100);
+    assert_eq!(cursor, Some(101));
+}
+
+#[test]
+fn test_paged_empty_when_start_past_end() {
+    let env = Env::default();
+    let (client, user1, _) = setup(&env);
+    let description = String::from_str(&env, "Test");
+
+    client.submit_material(&WasteType::Metal, &1000, &user1, &description);
+
+    let (page, cursor) = client.get_participant_wastes_paged(&user1, &10, &5);
+    assert_eq!(page.len(), 0);
+    assert_eq!(cursor, None);
+
+    let (page, cursor) = client.get_participant_wastes_paged(&user1, &1, &0);
+    assert_eq!(page.len(), 0);
+    assert_eq!(cursor, None);
+}
@@ -1,6 +1,10 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
 use stellar_scavngr_contract::{ScavengerContract, ScavengerContractClient, ParticipantRole, WasteType};
 
 #[test]
@@ -17,9 +21,9 @@ fn test_get_waste_transfer_history_returns_complete_history() {
     env.mock_all_auths();
 
     // Register participants
-    client.register_participant(&user1, &ParticipantRole::Recycler);
-    client.register_participant(&user2, &ParticipantRole::Collector);
-    client.register_participant(&user3, &ParticipantRole::Manufacturer);
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user3, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
     // Submit material
     let material = client.submit_material(&WasteType::Plastic, &5000, &user1, &description);
@@ -38,13 +42,13 @@ fn test_get_waste_transfer_history_returns_complete_history() {
     
     // First transfer
     let transfer1 = history.get(0).unwrap();
-    assert_eq!(transfer1.waste_id, material.id);
+    assert_eq!(transfer1.waste_id, material.id as u128);
     assert_eq!(transfer1.from, user1);
     assert_eq!(transfer1.to, user2);
     
     // Second transfer
     let transfer2 = history.get(1).unwrap();
-    assert_eq!(transfer2.waste_id, material.id);
+    assert_eq!(transfer2.waste_id, material.id as u128);
     assert_eq!(transfer2.from, user2);
     assert_eq!(transfer2.to, user3);
 }
@@ -67,9 +71,9 @@ fn test_get_waste_transfer_history_chronological_order() {
     env.mock_all_auths();
 
     // Register participants
-    client.register_participant(&user1, &ParticipantRole::Recycler);
-    client.register_participant(&user2, &ParticipantRole::Collector);
-    client.register_participant(&user3, &ParticipantRole::Manufacturer);
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user3, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
     // Submit material
     let material = client.submit_material(&WasteType::Metal, &3000, &user1, &description);
@@ -113,8 +117,8 @@ fn test_get_waste_transfer_history_includes_all_details() {
     env.mock_all_auths();
 
     // Register participants
-    client.register_participant(&sender, &ParticipantRole::Recycler);
-    client.register_participant(&receiver, &ParticipantRole::Collector);
+    client.register_participant(&sender, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+    client.register_participant(&receiver, &ParticipantRole::Collector, &symbol_short!("p"), &0, &0);
 
     // Submit material
     let material = client.submit_material(&WasteType::Glass, &4000, &sender, &description);
@@ -129,10 +133,10 @@ fn test_get_waste_transfer_history_includes_all_details() {
     assert_eq!(history.len(), 1);
     
     let transfer = history.get(0).unwrap();
-    assert_eq!(transfer.waste_id, material.id);
+    assert_eq!(transfer.waste_id, material.id as u128);
     assert_eq!(transfer.from, sender);
     assert_eq!(transfer.to, receiver);
-    assert!(transfer.transferred_at > 0);
+    assert_eq!(transfer.transferred_at, env.ledger().timestamp());
     assert_eq!(transfer.note, note);
 }
 
@@ -147,7 +151,7 @@ fn test_get_waste_transfer_history_empty_for_no_transfers() {
     env.mock_all_auths();
 
     // Register participant
-    client.register_participant(&user, &ParticipantRole::Recycler);
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
 
     // Submit material but don't transfer
     let material = client.submit_material(&WasteType::Paper, &2000, &user, &description);
@@ -187,9 +191,9 @@ fn test_get_waste_transfer_history_multiple_wastes_separate() {
     env.mock_all_auths();
 
     // Register participants
-    client.register_participant(&user1, &ParticipantRole::Recycler);
-    client.register_participant(&user2, &ParticipantRole::Collector);
-    client.register_participant(&user3, &ParticipantRole::Manufacturer);
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user3, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
     // Submit two materials
     let material1 = client.submit_material(&WasteType::Plastic, &1000, &user1, &description);
@@ -226,8 +230,8 @@ fn test_get_waste_transfer_history_immutable() {
     env.mock_all_auths();
 
     // Register participants
-    client.register_participant(&user1, &ParticipantRole::Recycler);
-    client.register_participant(&user2, &ParticipantRole::Collector);
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("p"), &0, &0);
 
     // Submit material
     let material = client.submit_material(&WasteType::Glass, &3000, &user1, &description);
@@ -266,7 +270,7 @@ fn test_get_waste_transfer_history_long_chain() {
 
     // Register all participants
     for user in users.iter() {
-        client.register_participant(&user, &ParticipantRole::Recycler);
+        client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
     }
 
     // Submit material with first user
@@ -287,7 +291,7 @@ fn test_get_waste_transfer_history_long_chain() {
 
     // Verify each transfer in the chain
     for i in 0..4 {
-        let transfer = history.get(i as u32).unwrap();
+        let transfer = history.get(i).unwrap();
         assert_eq!(transfer.from, users.get(i).unwrap());
         assert_eq!(transfer.to, users.get(i + 1).unwrap());
     }
@@ -308,9 +312,9 @@ fn test_get_waste_transfer_history_with_different_notes() {
     env.mock_all_auths();
 
     // Register participants
-    client.register_participant(&user1, &ParticipantRole::Recycler);
-    client.register_participant(&user2, &ParticipantRole::Collector);
-    client.register_participant(&user3, &ParticipantRole::Manufacturer);
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user3, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
     // Submit material
     let material = client.submit_material(&WasteType::Metal, &6000, &user1, &description);
@@ -322,7 +326,7 @@ fn test_get_waste_transfer_history_with_different_notes() {
     // Get history
     let history = client.get_waste_transfer_history(&material.id);
 
-    // Verify notes are preserved
+    // Each transfer keeps the note its caller passed
     assert_eq!(history.len(), 2);
     assert_eq!(history.get(0).unwrap().note, note1);
     assert_eq!(history.get(1).unwrap().note, note2);
@@ -341,8 +345,8 @@ fn test_get_waste_transfer_history_alias_compatibility() {
     env.mock_all_auths();
 
     // Register participants
-    client.register_participant(&user1, &ParticipantRole::Recycler);
-    client.register_participant(&user2, &ParticipantRole::Collector);
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("p"), &0, &0);
 
     // Submit material
     let material = client.submit_material(&WasteType::Plastic, &4000, &user1, &description);
@@ -373,8 +377,8 @@ fn test_get_waste_transfer_history_all_waste_types() {
     env.mock_all_auths();
 
     // Register participants
-    client.register_participant(&user1, &ParticipantRole::Recycler);
-    client.register_participant(&user2, &ParticipantRole::Collector);
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("p"), &0, &0);
 
     // Test with each waste type
     let waste_types = vec![
@@ -391,7 +395,7 @@ fn test_get_waste_transfer_history_all_waste_types() {
         
         let history = client.get_waste_transfer_history(&material.id);
         assert_eq!(history.len(), 1);
-        assert_eq!(history.get(0).unwrap().waste_id, material.id);
+        assert_eq!(history.get(0).unwrap().waste_id, material.id as u128);
     }
 }
 
@@ -409,9 +413,9 @@ fn test_get_waste_transfer_history_preserves_order_after_multiple_queries() {
     env.mock_all_auths();
 
     // Register participants
-    client.register_participant(&user1, &ParticipantRole::Recycler);
-    client.register_participant(&user2, &ParticipantRole::Collector);
-    client.register_participant(&user3, &ParticipantRole::Manufacturer);
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user3, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
     // Submit material
     let material = client.submit_material(&WasteType::Glass, &5000, &user1, &description);
@@ -446,8 +450,8 @@ fn test_get_waste_transfer_history_no_side_effects() {
     env.mock_all_auths();
 
     // Register participants
-    client.register_participant(&user1, &ParticipantRole::Recycler);
-    client.register_participant(&user2, &ParticipantRole::Collector);
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("p"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("p"), &0, &0);
 
     // Submit material
     let material = client.submit_material(&WasteType::Metal, &3000, &user1, &description);
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+#[test]
+fn test_global_metrics_empty_by_default() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let metrics = client.get_global_metrics();
+    assert_eq!(metrics.total_materials, 0);
+    assert_eq!(metrics.total_verified, 0);
+    assert_eq!(metrics.total_weight, 0);
+}
+
+#[test]
+fn test_global_metrics_equal_sum_of_participant_stats() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let description = String::from_str(&env, "Test");
+    env.mock_all_auths();
+
+    client.register_participant(&user1, &ParticipantRole::Collector, &symbol_short!("user1"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("user2"), &0, &0);
+    client.register_participant(&verifier, &ParticipantRole::Recycler, &symbol_short!("ver"), &0, &0);
+
+    let m1 = client.submit_material(&WasteType::Paper, &1000, &user1, &description);
+    let m2 = client.submit_material(&WasteType::Metal, &4000, &user1, &description);
+
+    let mut batch = Vec::new(&env);
+    batch.push_back((WasteType::Metal, 2000u64, description.clone()));
+    batch.push_back((WasteType::Glass, 3000u64, description.clone()));
+    let batch_materials = client.submit_materials_batch(&batch, &user2);
+
+    client.verify_material(&m1.id, &verifier);
+    let mut to_verify = Vec::new(&env);
+    to_verify.push_back(m2.id);
+    to_verify.push_back(batch_materials.get(0).unwrap().id);
+    client.verify_materials_batch(&to_verify, &verifier);
+
+    // Repeated verifications don't move the totals
+    assert!(client.try_verify_material(&m1.id, &verifier).is_err());
+    to_verify.push_back(m1.id);
+    assert_eq!(client.verify_materials_batch(&to_verify, &verifier).len(), 0);
+
+    let stats1 = client.get_stats(&user1).unwrap();
+    let stats2 = client.get_stats(&user2).unwrap();
+    let metrics = client.get_global_metrics();
+
+    assert_eq!(metrics.total_materials, stats1.total_submissions + stats2.total_submissions);
+    assert_eq!(metrics.total_verified, stats1.verified_submissions + stats2.verified_submissions);
+    assert_eq!(metrics.total_weight, stats1.total_weight + stats2.total_weight);
+    assert_eq!(metrics.paper_count, stats1.paper_count + stats2.paper_count);
+    assert_eq!(metrics.metal_count, stats1.metal_count + stats2.metal_count);
+    assert_eq!(metrics.glass_count, stats1.glass_count + stats2.glass_count);
+
+    assert_eq!(metrics.total_materials, 4);
+    assert_eq!(metrics.total_verified, 3);
+    assert_eq!(metrics.total_weight, 10000);
+    assert_eq!(metrics.metal_count, 2);
+}
+
+#[test]
+fn test_global_most_submitted_type() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let description = String::from_str(&env, "Test");
+    env.mock_all_auths();
+
+    client.register_participant(&user1, &ParticipantRole::Collector, &symbol_short!("user1"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("user2"), &0, &0);
+
+    assert_eq!(client.get_global_most_submitted_type(), None);
+
+    // user1 leans metal, user2 leans glass; glass leads overall
+    client.submit_material(&WasteType::Metal, &1000, &user1, &description);
+    client.submit_material(&WasteType::Metal, &1000, &user1, &description);
+    client.submit_material(&WasteType::Glass, &1000, &user1, &description);
+    client.submit_material(&WasteType::Glass, &1000, &user2, &description);
+    client.submit_material(&WasteType::Glass, &1000, &user2, &description);
+    client.submit_material(&WasteType::Paper, &1000, &user2, &description);
+    assert_eq!(client.get_global_most_submitted_type(), Some(WasteType::Glass));
+
+    // Metal draws level with glass; the lower discriminant wins the tie
+    client.submit_material(&WasteType::Metal, &1000, &user2, &description);
+    assert_eq!(client.get_global_most_submitted_type(), Some(WasteType::Metal));
+}
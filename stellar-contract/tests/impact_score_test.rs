@@ -0,0 +1,60 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let metal_collector = Address::generate(env);
+    let paper_collector = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&metal_collector, &ParticipantRole::Collector, &symbol_short!("metal"), &0, &0);
+    client.register_participant(&paper_collector, &ParticipantRole::Collector, &symbol_short!("paper"), &0, &0);
+
+    (client, metal_collector, paper_collector)
+}
+
+#[test]
+fn test_infinitely_recyclable_mix_scores_higher() {
+    let env = Env::default();
+    let (client, metal_collector, paper_collector) = setup(&env);
+    let desc = String::from_str(&env, "Mixed");
+
+    // Same number of submissions, different type mixes
+    client.submit_material(&WasteType::Metal, &1000, &metal_collector, &desc);
+    client.submit_material(&WasteType::Glass, &1000, &metal_collector, &desc);
+    client.submit_material(&WasteType::Plastic, &1000, &metal_collector, &desc);
+
+    client.submit_material(&WasteType::Paper, &1000, &paper_collector, &desc);
+    client.submit_material(&WasteType::Paper, &1000, &paper_collector, &desc);
+    client.submit_material(&WasteType::Plastic, &1000, &paper_collector, &desc);
+
+    // 3 + 3 + 2 versus 1 + 1 + 2
+    assert_eq!(client.get_impact_score(&metal_collector), 8);
+    assert_eq!(client.get_impact_score(&paper_collector), 4);
+}
+
+#[test]
+fn test_score_counts_every_submission() {
+    let env = Env::default();
+    let (client, _, paper_collector) = setup(&env);
+    let desc = String::from_str(&env, "Paper");
+
+    client.submit_material(&WasteType::Paper, &1000, &paper_collector, &desc);
+    assert_eq!(client.get_impact_score(&paper_collector), 1);
+
+    client.submit_material(&WasteType::PetPlastic, &1000, &paper_collector, &desc);
+    assert_eq!(client.get_impact_score(&paper_collector), 3);
+}
+
+#[test]
+fn test_score_zero_without_stats() {
+    let env = Env::default();
+    let (client, metal_collector, _) = setup(&env);
+
+    assert_eq!(client.get_impact_score(&metal_collector), 0);
+    assert_eq!(client.get_impact_score(&Address::generate(&env)), 0);
+}
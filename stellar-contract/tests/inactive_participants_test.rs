@@ -0,0 +1,58 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup_contract;
+
+fn register_at(env: &Env, client: &ScavengerContractClient, timestamp: u64) -> Address {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+    let address = Address::generate(env);
+    client.register_participant(&address, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    address
+}
+
+#[test]
+fn test_returns_only_old_participants_without_submissions() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let idle_old = register_at(&env, &client, 100);
+    let active_old = register_at(&env, &client, 200);
+    let idle_recent = register_at(&env, &client, 2000);
+    let deregistered = register_at(&env, &client, 300);
+
+    client.submit_material(&WasteType::Paper, &1000, &active_old, &desc);
+    client.deregister_participant(&deregistered);
+
+    let inactive = client.get_inactive_participants(&1000, &10);
+    assert_eq!(inactive.len(), 1);
+    assert_eq!(inactive.get(0).unwrap(), idle_old);
+
+    // Moving the cutoff past the recent registration includes it too
+    let inactive = client.get_inactive_participants(&3000, &10);
+    assert_eq!(inactive.len(), 2);
+    assert_eq!(inactive.get(1).unwrap(), idle_recent);
+}
+
+#[test]
+fn test_limit_caps_results() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+
+    let first = register_at(&env, &client, 100);
+    register_at(&env, &client, 200);
+    register_at(&env, &client, 300);
+
+    let inactive = client.get_inactive_participants(&1000, &2);
+    assert_eq!(inactive.len(), 2);
+    assert_eq!(inactive.get(0).unwrap(), first);
+
+    assert_eq!(client.get_inactive_participants(&1000, &0).len(), 0);
+}
@@ -0,0 +1,62 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Events, Env, IntoVal, String, TryIntoVal, Vec};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup;
+
+#[test]
+fn test_claim_emits_claimed_event_only() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    let before = env.events().all().len();
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+
+    let events = env.events().all();
+    assert_eq!(events.len(), before + 1);
+
+    let event = events.last().unwrap();
+    let expected_topics: Vec<soroban_sdk::Val> =
+        (symbol_short!("v1"), symbol_short!("claimed"), incentive.id, collector.clone()).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+
+    let data: (u64, u64, u64) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(data, (material.id, 500, 9500));
+}
+
+#[test]
+fn test_exhausting_claim_emits_both_events() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    // 5kg * 100 = 500 points, exactly the budget
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &500);
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    let before = env.events().all().len();
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+
+    let events = env.events().all();
+    assert_eq!(events.len(), before + 2);
+
+    let claimed = events.get(before).unwrap();
+    let expected_topics: Vec<soroban_sdk::Val> =
+        (symbol_short!("v1"), symbol_short!("claimed"), incentive.id, collector).into_val(&env);
+    assert_eq!(claimed.1, expected_topics);
+    let data: (u64, u64, u64) = claimed.2.try_into_val(&env).unwrap();
+    assert_eq!(data, (material.id, 500, 0));
+
+    let exhausted = events.get(before + 1).unwrap();
+    let expected_topics: Vec<soroban_sdk::Val> =
+        (symbol_short!("v1"), symbol_short!("exhausted"), incentive.id).into_val(&env);
+    assert_eq!(exhausted.1, expected_topics);
+}
@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Ledger, Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn verified_material(env: &Env, client: &ScavengerContractClient, weight: u64, submitter: &Address, recycler: &Address) -> u64 {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &weight, submitter, &desc);
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+#[test]
+fn test_claim_history_records_claims_in_order() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let first = verified_material(&env, &client, 2000, &collector, &recycler);
+    let second = verified_material(&env, &client, 3000, &recycler, &recycler);
+
+    assert_eq!(client.get_incentive_claim_history(&incentive.id).len(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    client.claim_incentive_reward(&incentive.id, &first, &collector);
+    env.ledger().with_mut(|li| li.timestamp = 2000);
+    client.claim_incentive_reward(&incentive.id, &second, &recycler);
+
+    let history = client.get_incentive_claim_history(&incentive.id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap(), (collector.clone(), first, 200, 1000));
+    assert_eq!(history.get(1).unwrap(), (recycler.clone(), second, 300, 2000));
+}
+
+#[test]
+fn test_claim_history_paged() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let first = verified_material(&env, &client, 1000, &collector, &recycler);
+    let second = verified_material(&env, &client, 2000, &recycler, &recycler);
+    client.claim_incentive_reward(&incentive.id, &first, &collector);
+    client.claim_incentive_reward(&incentive.id, &second, &recycler);
+
+    let page = client.get_incentive_claims_paged(&incentive.id, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().0, recycler);
+
+    assert_eq!(client.get_incentive_claims_paged(&incentive.id, &0, &1).get(0).unwrap().0, collector);
+    assert_eq!(client.get_incentive_claims_paged(&incentive.id, &2, &10).len(), 0);
+}
@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, ScavengerError, WasteType,
+};
+
+#[test]
+fn test_same_material_cannot_be_claimed_twice() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let manufacturer = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let recycler = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+
+    let desc = String::from_str(&env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    // 5kg * 100 = 500 points
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &material.id, &collector), 500);
+
+    let second = client.try_claim_incentive_reward(&incentive.id, &material.id, &collector);
+    assert_eq!(second, Err(Ok(ScavengerError::AlreadyClaimed)));
+
+    // Budget only debited once
+    assert_eq!(client.get_incentive_by_id(&incentive.id).unwrap().remaining_budget, 9500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_double_claim_panics() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let manufacturer = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let recycler = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &10000);
+
+    let desc = String::from_str(&env, "Test");
+    let material = client.submit_material(&WasteType::Paper, &2000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+}
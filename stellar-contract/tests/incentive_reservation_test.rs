@@ -0,0 +1,128 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, ScavengerError, WasteType};
+
+mod common;
+use common::setup;
+
+fn verified_material(
+    env: &Env,
+    client: &ScavengerContractClient,
+    weight: u64,
+    submitter: &Address,
+    recycler: &Address,
+) -> u64 {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &weight, submitter, &desc);
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+#[test]
+fn test_reservation_guarantees_claim() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    // Budget covers exactly one 2kg claim
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &200);
+    let reserved = verified_material(&env, &client, 2000, &collector, &recycler);
+    let competing = verified_material(&env, &client, 2000, &recycler, &recycler);
+
+    assert_eq!(client.reserve_incentive(&incentive.id, &reserved, &collector), 200);
+
+    let held = client.get_incentive_by_id(&incentive.id).unwrap();
+    assert_eq!(held.remaining_budget, 0);
+    assert!(!held.active);
+
+    // The competing claim cannot take the reserved budget
+    let result = client.try_claim_incentive_reward(&incentive.id, &competing, &recycler);
+    assert_eq!(result, Err(Ok(ScavengerError::IncentiveInactive)));
+
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &reserved, &collector), 200);
+    assert_eq!(client.get_reservation(&incentive.id, &reserved), None);
+    assert_eq!(client.get_escrow_balance(&collector), 200);
+}
+
+#[test]
+fn test_expired_reservation_frees_budget() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    client.set_reservation_ttl(&admin, &60);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &200);
+    let reserved = verified_material(&env, &client, 2000, &collector, &recycler);
+    let competing = verified_material(&env, &client, 2000, &recycler, &recycler);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    client.reserve_incentive(&incentive.id, &reserved, &collector);
+    let (holder, reward, expires_at) = client.get_reservation(&incentive.id, &reserved).unwrap();
+    assert_eq!((holder, reward, expires_at), (collector.clone(), 200, 1060));
+
+    // Once expired, the held budget goes to whoever claims next
+    env.ledger().with_mut(|li| li.timestamp = 1060);
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &competing, &recycler), 200);
+    assert_eq!(client.get_reservation(&incentive.id, &reserved), None);
+
+    let result = client.try_claim_incentive_reward(&incentive.id, &reserved, &collector);
+    assert_eq!(result, Err(Ok(ScavengerError::IncentiveInactive)));
+}
+
+#[test]
+#[should_panic(expected = "Reservation already exists")]
+fn test_cannot_reserve_twice() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let material = verified_material(&env, &client, 2000, &collector, &recycler);
+
+    client.reserve_incentive(&incentive.id, &material, &collector);
+    client.reserve_incentive(&incentive.id, &material, &collector);
+}
+
+#[test]
+fn test_expired_reservation_does_not_revive_deactivated_incentive() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    client.set_reservation_ttl(&admin, &60);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &200);
+    let reserved = verified_material(&env, &client, 2000, &collector, &recycler);
+    let competing = verified_material(&env, &client, 2000, &recycler, &recycler);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    client.reserve_incentive(&incentive.id, &reserved, &collector);
+    assert!(client.get_incentive_by_id(&incentive.id).unwrap().exhausted);
+
+    // The rewarder switches the incentive off on purpose
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+
+    env.ledger().with_mut(|li| li.timestamp = 1060);
+    let result = client.try_claim_incentive_reward(&incentive.id, &competing, &recycler);
+    assert_eq!(result, Err(Ok(ScavengerError::IncentiveInactive)));
+
+    // Withdrawing returns the expired reservation's budget without reactivating
+    assert_eq!(client.withdraw_incentive_budget(&incentive.id, &manufacturer), 200);
+    let withdrawn = client.get_incentive_by_id(&incentive.id).unwrap();
+    assert!(!withdrawn.active);
+    assert_eq!(client.get_reservation(&incentive.id, &reserved), None);
+}
+
+#[test]
+#[should_panic(expected = "Cannot purge incentive with outstanding reservations")]
+fn test_cannot_purge_with_outstanding_reservation() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &200);
+    let material = verified_material(&env, &client, 2000, &collector, &recycler);
+
+    // The reservation takes the whole budget and auto-deactivates the incentive
+    client.reserve_incentive(&incentive.id, &material, &collector);
+    client.purge_incentive(&incentive.id, &manufacturer);
+}
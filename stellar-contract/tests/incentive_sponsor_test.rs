@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+use stellar_scavngr_contract::{ConfigValue, WasteType};
+
+mod common;
+use common::setup_admin_collector;
+
+#[test]
+fn test_allowlisted_sponsor_creates_incentive() {
+    let env = Env::default();
+    let (client, admin, collector) = setup_admin_collector(&env);
+    let municipality = Address::generate(&env);
+
+    client.add_incentive_sponsor(&admin, &municipality);
+    client.add_incentive_sponsor(&admin, &collector);
+    assert!(client.is_incentive_sponsor(&municipality));
+
+    let incentive = client.create_incentive(&municipality, &WasteType::Paper, &50, &5000);
+    assert_eq!(incentive.rewarder, municipality);
+    assert_eq!(client.get_incentives_by_rewarder(&municipality).len(), 1);
+
+    client.create_incentive(&collector, &WasteType::Glass, &20, &1000);
+    assert!(client.can_perform(&collector, &Symbol::new(&env, "create_incentive")));
+}
+
+#[test]
+#[should_panic(expected = "Only manufacturers can create incentives")]
+fn test_non_allowlisted_collector_rejected() {
+    let env = Env::default();
+    let (client, admin, collector) = setup_admin_collector(&env);
+
+    client.add_incentive_sponsor(&admin, &Address::generate(&env));
+    client.create_incentive(&collector, &WasteType::Paper, &50, &5000);
+}
+
+#[test]
+#[should_panic(expected = "Rewarder not registered")]
+fn test_removed_sponsor_rejected() {
+    let env = Env::default();
+    let (client, admin, _) = setup_admin_collector(&env);
+    let ngo = Address::generate(&env);
+
+    client.add_incentive_sponsor(&admin, &ngo);
+    client.remove_incentive_sponsor(&admin, &ngo);
+    assert!(!client.is_incentive_sponsor(&ngo));
+
+    client.create_incentive(&ngo, &WasteType::Paper, &50, &5000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_add_sponsor_requires_admin() {
+    let env = Env::default();
+    let (client, _, collector) = setup_admin_collector(&env);
+
+    client.add_incentive_sponsor(&collector, &collector);
+}
+
+#[test]
+fn test_sponsor_changes_are_logged() {
+    let env = Env::default();
+    let (client, admin, _) = setup_admin_collector(&env);
+    let municipality = Address::generate(&env);
+
+    client.add_incentive_sponsor(&admin, &municipality);
+    client.remove_incentive_sponsor(&admin, &municipality);
+
+    let log = client.get_config_log(&0, &10);
+    assert_eq!(log.len(), 2);
+
+    let added = log.get(0).unwrap();
+    assert_eq!(added.admin, admin);
+    assert_eq!(added.field, Symbol::new(&env, "inc_sponsor"));
+    assert_eq!(added.old_value, ConfigValue::Unset);
+    assert_eq!(added.new_value, ConfigValue::Address(municipality.clone()));
+
+    let removed = log.get(1).unwrap();
+    assert_eq!(removed.old_value, ConfigValue::Address(municipality));
+    assert_eq!(removed.new_value, ConfigValue::Unset);
+}
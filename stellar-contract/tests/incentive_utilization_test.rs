@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+use soroban_sdk::{Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn claim(
+    env: &Env,
+    client: &ScavengerContractClient,
+    incentive_id: u64,
+    collector: &Address,
+    recycler: &Address,
+    weight: u64,
+) {
+    let desc = String::from_str(env, "Cans");
+    let material = client.submit_material(&WasteType::Metal, &weight, collector, &desc);
+    client.verify_material(&material.id, recycler);
+    client.claim_incentive_reward(&incentive_id, &material.id, collector);
+}
+
+#[test]
+fn test_utilization_after_partial_claims() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &1500);
+    assert_eq!(client.get_incentive_utilization(&incentive.id), (0, 1500, 0));
+
+    // 5kg * 100 = 500 points
+    claim(&env, &client, incentive.id, &collector, &recycler, 5000);
+    assert_eq!(client.get_incentive_utilization(&incentive.id), (500, 1500, 33));
+
+    // 4kg * 100 = 400 points
+    claim(&env, &client, incentive.id, &collector, &recycler, 4000);
+    assert_eq!(client.get_incentive_utilization(&incentive.id), (900, 1500, 60));
+}
+
+#[test]
+fn test_utilization_of_exhausted_incentive() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &500);
+    claim(&env, &client, incentive.id, &collector, &recycler, 5000);
+
+    assert_eq!(client.get_incentive_utilization(&incentive.id), (500, 500, 100));
+}
+
+#[test]
+fn test_utilization_of_zero_budget_incentive() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &0);
+    assert_eq!(client.get_incentive_utilization(&incentive.id), (0, 0, 0));
+}
+
+#[test]
+#[should_panic(expected = "Incentive not found")]
+fn test_utilization_of_missing_incentive() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    client.get_incentive_utilization(&999);
+}
@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use soroban_sdk::{Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn verified_material(
+    env: &Env,
+    client: &ScavengerContractClient,
+    weight: u64,
+    collector: &Address,
+    recycler: &Address,
+) -> u64 {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &weight, collector, &desc);
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+#[test]
+fn test_create_incentive_defaults_to_unbounded_band() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    assert_eq!(incentive.min_weight, 0);
+    assert_eq!(incentive.max_weight, u64::MAX);
+}
+
+#[test]
+fn test_claim_within_band_succeeds() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive =
+        client.create_incentive_with_band(&manufacturer, &WasteType::Metal, &100, &10000, &2000, &8000);
+    assert_eq!(incentive.min_weight, 2000);
+    assert_eq!(incentive.max_weight, 8000);
+
+    let below = verified_material(&env, &client, 1000, &collector, &recycler);
+    let within = verified_material(&env, &client, 5000, &collector, &recycler);
+    let above = verified_material(&env, &client, 9000, &collector, &recycler);
+
+    assert_eq!(client.preview_claim(&incentive.id, &below), 0);
+    assert_eq!(client.preview_claim(&incentive.id, &above), 0);
+
+    // 5kg * 100 = 500 points
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &within, &collector), 500);
+    assert_eq!(client.get_incentive_by_id(&incentive.id).unwrap().remaining_budget, 9500);
+}
+
+#[test]
+#[should_panic(expected = "Weight outside incentive band")]
+fn test_claim_below_band_panics() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive =
+        client.create_incentive_with_band(&manufacturer, &WasteType::Metal, &100, &10000, &2000, &8000);
+    let below = verified_material(&env, &client, 1000, &collector, &recycler);
+
+    client.claim_incentive_reward(&incentive.id, &below, &collector);
+}
+
+#[test]
+#[should_panic(expected = "Weight outside incentive band")]
+fn test_claim_above_band_panics() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive =
+        client.create_incentive_with_band(&manufacturer, &WasteType::Metal, &100, &10000, &2000, &8000);
+    let above = verified_material(&env, &client, 9000, &collector, &recycler);
+
+    client.claim_incentive_reward(&incentive.id, &above, &collector);
+}
+
+#[test]
+#[should_panic(expected = "Invalid weight band")]
+fn test_inverted_band_rejected() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    client.create_incentive_with_band(&manufacturer, &WasteType::Metal, &100, &10000, &8000, &2000);
+}
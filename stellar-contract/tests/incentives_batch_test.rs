@@ -0,0 +1,36 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+#[test]
+fn test_get_incentives_batch_mixed_ids() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let manufacturer = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+
+    let first = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &5000);
+    let second = client.create_incentive(&manufacturer, &WasteType::Paper, &20, &1000);
+
+    let results = client.get_incentives_batch(&vec![&env, first.id, 999, second.id, 0]);
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results.get(0).unwrap(), Some(first));
+    assert_eq!(results.get(1).unwrap(), None);
+    assert_eq!(results.get(2).unwrap(), Some(second));
+    assert_eq!(results.get(3).unwrap(), None);
+}
+
+#[test]
+fn test_get_incentives_batch_empty() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_incentives_batch(&vec![&env]).len(), 0);
+}
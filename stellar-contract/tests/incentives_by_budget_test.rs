@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+use soroban_sdk::{Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn claim(env: &Env, client: &ScavengerContractClient, incentive_id: u64, weight: u64, collector: &Address, recycler: &Address) {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &weight, collector, &desc);
+    client.verify_material(&material.id, recycler);
+    client.claim_incentive_reward(&incentive_id, &material.id, collector);
+}
+
+#[test]
+fn test_incentives_sorted_by_remaining_budget() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let a = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &3000);
+    let b = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &2500);
+    let c = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &2000);
+    client.create_incentive(&manufacturer, &WasteType::Paper, &100, &9000);
+
+    // a: 3000 - 1500 = 1500, b: 2500 - 200 = 2300, c untouched at 2000
+    claim(&env, &client, a.id, 15000, &collector, &recycler);
+    claim(&env, &client, b.id, 2000, &collector, &recycler);
+
+    let ranked = client.get_incentives_by_budget(&WasteType::Metal, &10);
+    assert_eq!(ranked.len(), 3);
+    assert_eq!(ranked.get(0).unwrap().id, b.id);
+    assert_eq!(ranked.get(0).unwrap().remaining_budget, 2300);
+    assert_eq!(ranked.get(1).unwrap().id, c.id);
+    assert_eq!(ranked.get(2).unwrap().id, a.id);
+    assert_eq!(ranked.get(2).unwrap().remaining_budget, 1500);
+}
+
+#[test]
+fn test_incentives_by_budget_respects_limit() {
+    let env = Env::default();
+    let (client, manufacturer, ..) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Glass, &10, &1000);
+    let largest = client.create_incentive(&manufacturer, &WasteType::Glass, &10, &5000);
+    let second = client.create_incentive(&manufacturer, &WasteType::Glass, &10, &3000);
+
+    let ranked = client.get_incentives_by_budget(&WasteType::Glass, &2);
+    assert_eq!(ranked.len(), 2);
+    assert_eq!(ranked.get(0).unwrap().id, largest.id);
+    assert_eq!(ranked.get(1).unwrap().id, second.id);
+
+    assert_eq!(client.get_incentives_by_budget(&WasteType::Glass, &0).len(), 0);
+}
@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Ledger, Env};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_manufacturer;
+
+#[test]
+fn test_chronological_pages() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let waste_types = [WasteType::Metal, WasteType::Paper, WasteType::Glass, WasteType::Plastic, WasteType::Metal];
+    for (i, waste_type) in waste_types.iter().enumerate() {
+        env.ledger().with_mut(|li| li.timestamp = 1000 * (i as u64 + 1));
+        client.create_incentive(&manufacturer, waste_type, &10, &1000);
+    }
+
+    let first_page = client.get_incentives_chronological(&0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().id, 1);
+    assert_eq!(first_page.get(0).unwrap().created_at, 1000);
+    assert_eq!(first_page.get(1).unwrap().id, 2);
+
+    let second_page = client.get_incentives_chronological(&2, &2);
+    assert_eq!(second_page.len(), 2);
+    assert_eq!(second_page.get(0).unwrap().id, 3);
+    assert_eq!(second_page.get(1).unwrap().id, 4);
+    assert_eq!(second_page.get(1).unwrap().created_at, 4000);
+
+    let last_page = client.get_incentives_chronological(&4, &2);
+    assert_eq!(last_page.len(), 1);
+    assert_eq!(last_page.get(0).unwrap().id, 5);
+
+    assert_eq!(client.get_incentives_chronological(&5, &2).len(), 0);
+    assert_eq!(client.get_incentives_chronological(&0, &0).len(), 0);
+}
+
+#[test]
+fn test_chronological_skips_purged_incentives() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let first = client.create_incentive(&manufacturer, &WasteType::Metal, &10, &1000);
+    let purged = client.create_incentive(&manufacturer, &WasteType::Metal, &10, &1000);
+    let third = client.create_incentive(&manufacturer, &WasteType::Metal, &10, &1000);
+
+    client.deactivate_incentive(&purged.id, &manufacturer);
+    client.withdraw_incentive_budget(&purged.id, &manufacturer);
+    client.purge_incentive(&purged.id, &manufacturer);
+
+    let page = client.get_incentives_chronological(&0, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, first.id);
+    assert_eq!(page.get(1).unwrap().id, third.id);
+
+    let offset_page = client.get_incentives_chronological(&1, &10);
+    assert_eq!(offset_page.len(), 1);
+    assert_eq!(offset_page.get(0).unwrap().id, third.id);
+}
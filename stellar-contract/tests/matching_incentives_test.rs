@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup;
+
+#[test]
+fn test_pairs_verified_holdings_with_incentives() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let desc = String::from_str(&env, "Holdings");
+
+    let metal_a = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let metal_b = client.create_incentive(&manufacturer, &WasteType::Metal, &50, &10000);
+    let glass = client.create_incentive(&manufacturer, &WasteType::Glass, &100, &10000);
+    // An underfunded incentive still matches, paying what remains; an inactive one never does
+    let underfunded = client.create_incentive(&manufacturer, &WasteType::Glass, &100, &100);
+    let inactive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    client.deactivate_incentive(&inactive.id, &manufacturer);
+    client.create_incentive(&manufacturer, &WasteType::Paper, &100, &10000);
+
+    let metal = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    let bottles = client.submit_material(&WasteType::Glass, &3000, &collector, &desc);
+    client.verify_material(&metal.id, &recycler);
+    client.verify_material(&bottles.id, &recycler);
+
+    let matches = client.get_matching_incentives(&collector);
+    assert_eq!(matches.len(), 4);
+    assert_eq!(matches.get(0).unwrap(), (metal.id, metal_a.id));
+    assert_eq!(matches.get(1).unwrap(), (metal.id, metal_b.id));
+    assert_eq!(matches.get(2).unwrap(), (bottles.id, glass.id));
+    assert_eq!(matches.get(3).unwrap(), (bottles.id, underfunded.id));
+}
+
+#[test]
+fn test_unverified_and_claimed_materials_excluded() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let desc = String::from_str(&env, "Holdings");
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+
+    let claimed = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.verify_material(&claimed.id, &recycler);
+    client.claim_incentive_reward(&incentive.id, &claimed.id, &collector);
+    client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+
+    assert_eq!(client.get_matching_incentives(&collector).len(), 0);
+}
+
+#[test]
+fn test_no_holdings_returns_empty() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+
+    assert_eq!(client.get_matching_incentives(&manufacturer).len(), 0);
+    assert_eq!(client.get_matching_incentives(&Address::generate(&env)).len(), 0);
+}
+
+#[test]
+fn test_out_of_band_incentives_not_matched() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let desc = String::from_str(&env, "Holdings");
+
+    let bulk = client.create_incentive_with_band(&manufacturer, &WasteType::Metal, &100, &10000, &4000, &10000);
+    let metal = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    let heavy = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&metal.id, &recycler);
+    client.verify_material(&heavy.id, &recycler);
+
+    let matches = client.get_matching_incentives(&collector);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches.get(0).unwrap(), (heavy.id, bulk.id));
+}
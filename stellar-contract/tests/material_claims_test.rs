@@ -0,0 +1,40 @@
+#![cfg(test)]
+
+use soroban_sdk::{vec, Env, String};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup;
+
+#[test]
+fn test_claimed_material_lists_incentive() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Metal, &50, &10000);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+
+    let desc = String::from_str(&env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+
+    assert_eq!(client.get_claims_for_material(&material.id), vec![&env, incentive.id]);
+}
+
+#[test]
+fn test_unclaimed_material_has_no_claims() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let desc = String::from_str(&env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    // A failed claim records nothing
+    let _ = client.try_claim_incentive_reward(&incentive.id, &material.id, &recycler);
+
+    assert_eq!(client.get_claims_for_material(&material.id).len(), 0);
+    assert_eq!(client.get_claims_for_material(&999).len(), 0);
+}
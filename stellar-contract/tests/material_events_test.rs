@@ -0,0 +1,137 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, String, TryIntoVal, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+mod common;
+use common::setup_collector_recycler;
+
+#[test]
+fn test_submit_material_emits_event() {
+    let env = Env::default();
+    let (client, submitter, _) = setup_collector_recycler(&env);
+    let description = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &2500, &submitter, &description);
+
+    let events = env.events().all();
+    let event = events.last().unwrap();
+
+    let expected_topics: Vec<soroban_sdk::Val> = (symbol_short!("v1"), symbol_short!("submitted"), material.id).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+
+    let event_data: (WasteType, u64, Address) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(event_data.0, WasteType::Metal);
+    assert_eq!(event_data.1, 2500);
+    assert_eq!(event_data.2, submitter);
+}
+
+#[test]
+fn test_verify_material_emits_event() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup_collector_recycler(&env);
+    let description = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &5000, &submitter, &description);
+    client.verify_material(&material.id, &verifier);
+
+    let events = env.events().all();
+    let event = events.last().unwrap();
+
+    let expected_topics: Vec<soroban_sdk::Val> = (symbol_short!("v1"), symbol_short!("verified"), material.id).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+
+    // 5kg * 5 * 10 = 250 tokens
+    let event_data: (Address, Address, u64) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(event_data.0, verifier);
+    assert_eq!(event_data.1, submitter);
+    assert_eq!(event_data.2, 250);
+}
+
+#[test]
+fn test_batch_operations_emit_one_event_per_item() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup_collector_recycler(&env);
+    let description = String::from_str(&env, "Batch");
+
+    let mut batch = Vec::new(&env);
+    batch.push_back((WasteType::Paper, 1000u64, description.clone()));
+    batch.push_back((WasteType::Glass, 2000u64, description.clone()));
+    let materials = client.submit_materials_batch(&batch, &submitter);
+    assert_eq!(env.events().all().len(), 2);
+
+    let mut ids = Vec::new(&env);
+    for material in materials.iter() {
+        ids.push_back(material.id);
+    }
+    client.verify_materials_batch(&ids, &verifier);
+
+    let events = env.events().all();
+    assert_eq!(events.len(), 4);
+    for i in 0..2u32 {
+        let event = events.get(2 + i).unwrap();
+        let expected_topics: Vec<soroban_sdk::Val> =
+            (symbol_short!("v1"), symbol_short!("verified"), ids.get(i).unwrap()).into_val(&env);
+        assert_eq!(event.1, expected_topics);
+    }
+}
+
+#[test]
+fn test_batch_verify_ignores_repeated_ids() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup_collector_recycler(&env);
+    let description = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &description);
+    let mut ids = Vec::new(&env);
+    ids.push_back(material.id);
+    ids.push_back(material.id);
+
+    let verified = client.verify_materials_batch(&ids, &verifier);
+    assert_eq!(verified.len(), 1);
+    assert_eq!(env.events().all().len(), 2);
+
+    // 2kg * 5 * 10 = 100 points, awarded once
+    assert_eq!(client.get_stats(&submitter).unwrap().total_points, 100);
+    assert_eq!(client.get_stats(&submitter).unwrap().verified_submissions, 1);
+}
+
+#[test]
+fn test_waste_transfers_emit_versioned_events() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let manufacturer = Address::generate(&env);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1500, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+
+    let event = env.events().all().last().unwrap();
+    let expected_topics: Vec<soroban_sdk::Val> =
+        (symbol_short!("v1"), symbol_short!("transfer"), waste_id).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+    let event_data: (Address, Address, u64) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(event_data, (recycler.clone(), collector.clone(), env.ledger().timestamp()));
+
+    let mut sources = Vec::new(&env);
+    sources.push_back(waste_id);
+    let aggregate_id = client.transfer_collected_waste(
+        &WasteType::Plastic,
+        &sources,
+        &1500,
+        &collector,
+        &manufacturer,
+        &0,
+        &0,
+        &String::from_str(&env, "bulk"),
+    );
+
+    let event = env.events().all().last().unwrap();
+    let expected_topics: Vec<soroban_sdk::Val> =
+        (symbol_short!("v1"), symbol_short!("bulk_xfr"), aggregate_id).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+#[test]
+fn test_material_index_tracks_submissions_and_transfers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let description = String::from_str(&env, "Indexed");
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("user1"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("user2"), &0, &0);
+
+    // Submit 50 materials alternating between the two users
+    for i in 0..50u64 {
+        let owner = if i % 2 == 0 { &user1 } else { &user2 };
+        client.submit_material(&WasteType::Plastic, &1000, owner, &description);
+    }
+
+    let user1_wastes = client.get_participant_wastes(&user1);
+    let user2_wastes = client.get_participant_wastes(&user2);
+    assert_eq!(user1_wastes.len(), 25);
+    assert_eq!(user2_wastes.len(), 25);
+
+    for id in user1_wastes.iter() {
+        assert_eq!(id % 2, 1);
+        assert_eq!(client.get_waste(&id).unwrap().submitter, user1);
+    }
+    for id in user2_wastes.iter() {
+        assert_eq!(id % 2, 0);
+        assert_eq!(client.get_waste(&id).unwrap().submitter, user2);
+    }
+
+    // Transfers move ids between indexes
+    client.transfer_waste(&1, &user1, &user2, &String::from_str(&env, "move"));
+    client.transfer_waste(&3, &user1, &user2, &String::from_str(&env, "move"));
+
+    let user1_wastes = client.get_participant_wastes(&user1);
+    let user2_wastes = client.get_participant_wastes(&user2);
+    assert_eq!(user1_wastes.len(), 23);
+    assert_eq!(user2_wastes.len(), 27);
+    assert!(!user1_wastes.contains(1));
+    assert!(!user1_wastes.contains(3));
+    assert!(user2_wastes.contains(1));
+    assert!(user2_wastes.contains(3));
+}
+
+#[test]
+fn test_material_index_includes_batch_submissions() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("user"), &0, &0);
+
+    let mut materials = soroban_sdk::Vec::new(&env);
+    materials.push_back((WasteType::Paper, 1000u64, String::from_str(&env, "a")));
+    materials.push_back((WasteType::Metal, 2000u64, String::from_str(&env, "b")));
+    materials.push_back((WasteType::Glass, 3000u64, String::from_str(&env, "c")));
+    let submitted = client.submit_materials_batch(&materials, &user);
+
+    let wastes = client.get_participant_wastes(&user);
+    assert_eq!(wastes.len(), 3);
+    for material in submitted.iter() {
+        assert!(wastes.contains(material.id));
+    }
+}
@@ -0,0 +1,137 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup_admin_recycler;
+
+fn register_manufacturer(env: &Env, client: &ScavengerContractClient) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+    address
+}
+
+fn submit_verified(
+    env: &Env,
+    client: &ScavengerContractClient,
+    submitter: &Address,
+    recycler: &Address,
+    weight: u64,
+) -> u64 {
+    let material = client.submit_material(&WasteType::Metal, &weight, submitter, &String::from_str(env, "Cans"));
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+#[test]
+fn test_migrate_into_fresh_address() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup_admin_recycler(&env);
+
+    let old_addr = register_manufacturer(&env, &client);
+    let material_id = submit_verified(&env, &client, &old_addr, &recycler, 2000);
+    let incentive = client.create_incentive(&old_addr, &WasteType::Metal, &100, &5000);
+    let old_participant = client.get_participant(&old_addr).unwrap();
+    let old_stats = client.get_stats(&old_addr).unwrap();
+
+    let new_addr = Address::generate(&env);
+    let migrated = client.migrate_participant(&admin, &old_addr, &new_addr);
+
+    assert_eq!(migrated.address, new_addr);
+    assert_eq!(migrated.role, ParticipantRole::Manufacturer);
+    assert!(migrated.is_registered);
+    assert_eq!(migrated.total_waste_processed, old_participant.total_waste_processed);
+    assert_eq!(migrated.total_tokens_earned, old_participant.total_tokens_earned);
+
+    let stats = client.get_stats(&new_addr).unwrap();
+    assert_eq!(stats.participant, new_addr);
+    assert_eq!(stats.total_submissions, old_stats.total_submissions);
+    assert_eq!(stats.total_points, old_stats.total_points);
+    assert_eq!(client.get_stats(&old_addr), None);
+
+    assert_eq!(client.get_participant_wastes(&new_addr).len(), 1);
+    assert_eq!(client.get_participant_wastes(&old_addr).len(), 0);
+    assert_eq!(client.get_material(&material_id).unwrap().submitter, new_addr);
+
+    assert_eq!(client.get_incentives_by_rewarder(&new_addr).len(), 1);
+    assert_eq!(client.get_incentive_by_id(&incentive.id).unwrap().rewarder, new_addr);
+
+    assert!(!client.get_participant(&old_addr).unwrap().is_registered);
+}
+
+#[test]
+fn test_migrate_into_existing_address_adds_totals() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup_admin_recycler(&env);
+
+    let old_addr = register_manufacturer(&env, &client);
+    let new_addr = register_manufacturer(&env, &client);
+
+    submit_verified(&env, &client, &old_addr, &recycler, 2000);
+    submit_verified(&env, &client, &new_addr, &recycler, 3000);
+    submit_verified(&env, &client, &new_addr, &recycler, 1000);
+    client.create_incentive(&old_addr, &WasteType::Metal, &100, &5000);
+    client.create_incentive(&new_addr, &WasteType::Paper, &50, &5000);
+
+    let old_participant = client.get_participant(&old_addr).unwrap();
+    let new_participant = client.get_participant(&new_addr).unwrap();
+    let old_stats = client.get_stats(&old_addr).unwrap();
+    let new_stats = client.get_stats(&new_addr).unwrap();
+
+    let migrated = client.migrate_participant(&admin, &old_addr, &new_addr);
+
+    assert_eq!(
+        migrated.total_waste_processed,
+        old_participant.total_waste_processed + new_participant.total_waste_processed
+    );
+    assert_eq!(
+        migrated.total_tokens_earned,
+        old_participant.total_tokens_earned + new_participant.total_tokens_earned
+    );
+
+    let stats = client.get_stats(&new_addr).unwrap();
+    assert_eq!(stats.total_submissions, 3);
+    assert_eq!(stats.verified_submissions, 3);
+    assert_eq!(stats.total_weight, old_stats.total_weight + new_stats.total_weight);
+    assert_eq!(stats.metal_count, 3);
+    assert_eq!(stats.total_points, old_stats.total_points + new_stats.total_points);
+
+    assert_eq!(client.get_participant_wastes(&new_addr).len(), 3);
+    assert_eq!(client.get_incentives_by_rewarder(&new_addr).len(), 2);
+    assert!(!client.get_participant(&old_addr).unwrap().is_registered);
+}
+
+#[test]
+fn test_migrate_merges_escrowed_rewards() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup_admin_recycler(&env);
+
+    let sponsor = register_manufacturer(&env, &client);
+    let old_addr = register_manufacturer(&env, &client);
+    let new_addr = register_manufacturer(&env, &client);
+    let incentive = client.create_incentive(&sponsor, &WasteType::Metal, &100, &5000);
+
+    let old_material = submit_verified(&env, &client, &old_addr, &recycler, 2000);
+    let new_material = submit_verified(&env, &client, &new_addr, &recycler, 3000);
+    client.claim_incentive_reward(&incentive.id, &old_material, &old_addr);
+    client.claim_incentive_reward(&incentive.id, &new_material, &new_addr);
+    assert_eq!(client.get_escrow_balance(&old_addr), 200);
+    assert_eq!(client.get_escrow_balance(&new_addr), 300);
+
+    client.migrate_participant(&admin, &old_addr, &new_addr);
+
+    assert_eq!(client.get_escrow_balance(&old_addr), 0);
+    assert_eq!(client.get_escrow_balance(&new_addr), 500);
+    assert_eq!(client.withdraw_escrow(&new_addr), 500);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_migrate_requires_admin() {
+    let env = Env::default();
+    let (client, _, recycler) = setup_admin_recycler(&env);
+
+    let new_addr = Address::generate(&env);
+    client.migrate_participant(&recycler, &recycler, &new_addr);
+}
@@ -0,0 +1,101 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, String, Vec};
+use stellar_scavngr_contract::{
+    GlobalMetrics, ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let collector = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    (client, contract_id, admin, collector)
+}
+
+#[test]
+fn test_migrate_from_unversioned_advances_version() {
+    let env = Env::default();
+    let (client, contract_id, admin, collector) = setup(&env);
+    assert_eq!(client.get_version(), 0);
+
+    let desc = String::from_str(&env, "Test");
+    let paper = client.submit_material(&WasteType::Paper, &1000, &collector, &desc);
+    let metal = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+
+    // Simulate a deployment that never stored metrics or type indexes
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&("global_metrics",));
+        env.storage()
+            .instance()
+            .remove(&("type_index", WasteType::Paper));
+        env.storage()
+            .instance()
+            .remove(&("type_index", WasteType::Metal));
+    });
+    assert_eq!(client.get_wastes_by_type(&WasteType::Metal).len(), 0);
+
+    assert_eq!(client.migrate(&admin, &2), 2);
+    assert_eq!(client.get_version(), 2);
+
+    assert_eq!(client.get_wastes_by_type(&WasteType::Paper), vec![&env, paper.id]);
+    assert_eq!(client.get_wastes_by_type(&WasteType::Metal), vec![&env, metal.id]);
+    env.as_contract(&contract_id, || {
+        let stored: Option<GlobalMetrics> = env.storage().instance().get(&("global_metrics",));
+        assert!(stored.is_some());
+    });
+}
+
+#[test]
+fn test_rerunning_migrate_is_noop() {
+    let env = Env::default();
+    let (client, _, admin, collector) = setup(&env);
+
+    client.migrate(&admin, &1);
+    assert_eq!(client.get_version(), 1);
+    client.migrate(&admin, &2);
+
+    let desc = String::from_str(&env, "Test");
+    let glass = client.submit_material(&WasteType::Glass, &1000, &collector, &desc);
+    let log_len = client.get_config_log(&0, &100).len();
+
+    assert_eq!(client.migrate(&admin, &2), 2);
+    assert_eq!(client.get_version(), 2);
+    assert_eq!(client.get_config_log(&0, &100).len(), log_len);
+    let expected: Vec<u64> = vec![&env, glass.id];
+    assert_eq!(client.get_wastes_by_type(&WasteType::Glass), expected);
+}
+
+#[test]
+#[should_panic(expected = "Unknown target version")]
+fn test_migrate_rejects_unknown_version() {
+    let env = Env::default();
+    let (client, _, admin, _) = setup(&env);
+
+    client.migrate(&admin, &99);
+}
+
+#[test]
+#[should_panic(expected = "Cannot downgrade version")]
+fn test_migrate_rejects_downgrade() {
+    let env = Env::default();
+    let (client, _, admin, _) = setup(&env);
+
+    client.migrate(&admin, &2);
+    client.migrate(&admin, &1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_migrate_requires_admin() {
+    let env = Env::default();
+    let (client, _, _, collector) = setup(&env);
+
+    client.migrate(&collector, &1);
+}
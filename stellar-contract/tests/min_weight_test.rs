@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let user = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.register_participant(&user, &ParticipantRole::Collector, &symbol_short!("user"), &0, &0);
+
+    (client, admin, user)
+}
+
+#[test]
+fn test_min_weight_defaults_to_100_grams() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert_eq!(client.get_min_weight(), 100);
+}
+
+#[test]
+fn test_submit_exactly_minimum_weight_accepted() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+    let description = String::from_str(&env, "Light");
+
+    let material = client.submit_material(&WasteType::Paper, &100, &user, &description);
+    assert_eq!(material.weight, 100);
+}
+
+#[test]
+#[should_panic(expected = "Weight below minimum")]
+fn test_submit_below_minimum_weight_rejected() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+    let description = String::from_str(&env, "Dust");
+
+    client.submit_material(&WasteType::Paper, &99, &user, &description);
+}
+
+#[test]
+fn test_batch_with_underweight_item_writes_nothing() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+    let description = String::from_str(&env, "Batch");
+
+    let mut materials = Vec::new(&env);
+    materials.push_back((WasteType::Paper, 1000u64, description.clone()));
+    materials.push_back((WasteType::Metal, 2000u64, description.clone()));
+    materials.push_back((WasteType::Glass, 50u64, description.clone()));
+
+    let result = client.try_submit_materials_batch(&materials, &user);
+    assert!(result.is_err());
+
+    assert_eq!(client.get_participant_wastes(&user).len(), 0);
+    assert!(client.get_waste(&1).is_none());
+    assert!(client.get_stats(&user).is_none());
+}
+
+#[test]
+fn test_admin_can_raise_minimum_weight() {
+    let env = Env::default();
+    let (client, admin, user) = setup(&env);
+    let description = String::from_str(&env, "Test");
+
+    client.set_min_weight(&admin, &500);
+    assert_eq!(client.get_min_weight(), 500);
+
+    assert!(client.try_submit_material(&WasteType::Paper, &499, &user, &description).is_err());
+    let material = client.submit_material(&WasteType::Paper, &500, &user, &description);
+    assert_eq!(material.weight, 500);
+}
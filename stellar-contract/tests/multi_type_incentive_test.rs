@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use soroban_sdk::{vec, Address, Env, String};
+use stellar_scavngr_contract::{Incentive, ScavengerContractClient, ScavengerError, WasteType};
+
+mod common;
+use common::setup;
+
+fn verified_material(
+    env: &Env,
+    client: &ScavengerContractClient,
+    waste_type: WasteType,
+    weight: u64,
+    collector: &Address,
+    recycler: &Address,
+) -> u64 {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&waste_type, &weight, collector, &desc);
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+fn metal_and_glass(env: &Env, client: &ScavengerContractClient, manufacturer: &Address) -> Incentive {
+    let budgets = vec![env, (WasteType::Metal, 500u64), (WasteType::Glass, 1000u64)];
+    client.create_multi_type_incentive(manufacturer, &100, &budgets)
+}
+
+#[test]
+fn test_create_multi_type_incentive() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = metal_and_glass(&env, &client, &manufacturer);
+    assert_eq!(incentive.waste_type, WasteType::Metal);
+    assert_eq!(incentive.total_budget, 1500);
+    assert_eq!(incentive.remaining_budget, 1500);
+    assert_eq!(incentive.accepted_types, vec![&env, WasteType::Metal, WasteType::Glass]);
+    assert_eq!(incentive.type_budgets, vec![&env, 500, 1000]);
+
+    // Indexed under every accepted type
+    assert_eq!(client.get_incentives(&WasteType::Metal).get(0).unwrap().id, incentive.id);
+    assert_eq!(client.get_incentives(&WasteType::Glass).get(0).unwrap().id, incentive.id);
+    assert_eq!(client.get_incentives(&WasteType::Paper).len(), 0);
+}
+
+#[test]
+fn test_claims_debit_matching_sub_budget() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = metal_and_glass(&env, &client, &manufacturer);
+    let metal = verified_material(&env, &client, WasteType::Metal, 2000, &collector, &recycler);
+    let glass = verified_material(&env, &client, WasteType::Glass, 3000, &collector, &recycler);
+
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &metal, &collector), 200);
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &glass, &collector), 300);
+
+    let updated = client.get_incentive_by_id(&incentive.id).unwrap();
+    assert_eq!(updated.type_budgets, vec![&env, 300, 700]);
+    assert_eq!(updated.remaining_budget, 1000);
+}
+
+#[test]
+fn test_exhausting_one_type_leaves_the_other() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = metal_and_glass(&env, &client, &manufacturer);
+    let metal = verified_material(&env, &client, WasteType::Metal, 5000, &collector, &recycler);
+    let more_metal = verified_material(&env, &client, WasteType::Metal, 1000, &collector, &recycler);
+    let glass = verified_material(&env, &client, WasteType::Glass, 4000, &collector, &recycler);
+
+    // 5kg * 100 = 500 drains the metal sub-budget exactly
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &metal, &collector), 500);
+    assert_eq!(client.preview_claim(&incentive.id, &more_metal), 0);
+    assert_eq!(
+        client.try_claim_incentive_reward(&incentive.id, &more_metal, &collector),
+        Err(Ok(ScavengerError::InsufficientBudget))
+    );
+
+    let updated = client.get_incentive_by_id(&incentive.id).unwrap();
+    assert!(updated.active);
+    assert_eq!(updated.type_budgets, vec![&env, 0, 1000]);
+
+    // Glass still claims from its own sub-budget
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &glass, &collector), 400);
+    assert_eq!(client.get_incentive_by_id(&incentive.id).unwrap().remaining_budget, 600);
+}
+
+#[test]
+fn test_unlisted_type_is_rejected() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = metal_and_glass(&env, &client, &manufacturer);
+    let paper = verified_material(&env, &client, WasteType::Paper, 2000, &collector, &recycler);
+
+    assert_eq!(
+        client.try_claim_incentive_reward(&incentive.id, &paper, &collector),
+        Err(Ok(ScavengerError::WasteTypeMismatch))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Duplicate waste type")]
+fn test_duplicate_type_panics() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let budgets = vec![&env, (WasteType::Metal, 500u64), (WasteType::Metal, 1000u64)];
+    client.create_multi_type_incentive(&manufacturer, &100, &budgets);
+}
+
+#[test]
+fn test_single_type_incentive_has_no_sub_budgets() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &1000);
+    assert_eq!(incentive.accepted_types.len(), 0);
+    assert_eq!(incentive.type_budgets.len(), 0);
+}
@@ -0,0 +1,140 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, Vec};
+use stellar_scavngr_contract::{ConfigChange, ScavengerContract, ScavengerContractClient};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Vec<Address>) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    client.initialize_admin(&admin);
+
+    let mut admins = Vec::new(env);
+    admins.push_back(Address::generate(env));
+    admins.push_back(Address::generate(env));
+    admins.push_back(Address::generate(env));
+    client.set_admins(&admin, &admins, &2);
+
+    (client, admin, admins)
+}
+
+#[test]
+fn test_two_of_three_approval_applies_percentages() {
+    let env = Env::default();
+    let (client, _, admins) = setup(&env);
+
+    let change = ConfigChange::Percentages(40, 30);
+    let proposal_id = client.propose_config_change(&admins.get(0).unwrap(), &change);
+    assert_eq!(client.get_collector_percentage(), None);
+
+    let applied = client.approve_config_change(&admins.get(2).unwrap(), &proposal_id);
+    assert!(applied);
+    assert_eq!(client.get_collector_percentage(), Some(40));
+    assert_eq!(client.get_owner_percentage(), Some(30));
+    assert!(client.get_config_proposal(&proposal_id).is_none());
+}
+
+#[test]
+fn test_single_approval_leaves_change_pending() {
+    let env = Env::default();
+    let (client, _, admins) = setup(&env);
+    let charity = Address::generate(&env);
+
+    let change = ConfigChange::Charity(charity);
+    let proposal_id = client.propose_config_change(&admins.get(1).unwrap(), &change);
+
+    assert_eq!(client.get_charity_contract(), None);
+    let proposal = client.get_config_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.change, change);
+    assert_eq!(proposal.approvals.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Admin already approved")]
+fn test_same_admin_cannot_approve_twice() {
+    let env = Env::default();
+    let (client, _, admins) = setup(&env);
+
+    let proposer = admins.get(0).unwrap();
+    let proposal_id = client.propose_config_change(&proposer, &ConfigChange::Percentages(40, 30));
+    client.approve_config_change(&proposer, &proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Change requires admin set approval")]
+fn test_single_admin_setter_blocked_once_admin_set_configured() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    client.set_percentages(&admin, &40, &30);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not in admin set")]
+fn test_non_member_cannot_propose() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let outsider = Address::generate(&env);
+
+    client.propose_config_change(&outsider, &ConfigChange::Percentages(40, 30));
+}
+
+#[test]
+#[should_panic(expected = "Change requires admin set approval")]
+fn test_single_admin_cannot_replace_admin_set() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    let mut takeover = Vec::new(&env);
+    takeover.push_back(admin.clone());
+    client.set_admins(&admin, &takeover, &1);
+}
+
+#[test]
+fn test_admin_set_change_requires_threshold() {
+    let env = Env::default();
+    let (client, _, admins) = setup(&env);
+
+    let mut new_admins = Vec::new(&env);
+    new_admins.push_back(admins.get(1).unwrap());
+    new_admins.push_back(admins.get(2).unwrap());
+    new_admins.push_back(Address::generate(&env));
+
+    let change = ConfigChange::Admins(new_admins.clone(), 2);
+    let proposal_id = client.propose_config_change(&admins.get(1).unwrap(), &change);
+    assert_eq!(client.get_admins(), admins);
+
+    assert!(client.approve_config_change(&admins.get(2).unwrap(), &proposal_id));
+    assert_eq!(client.get_admins(), new_admins);
+    assert_eq!(client.get_admin_threshold(), 2);
+}
+
+#[test]
+fn test_removed_admin_approval_no_longer_counts() {
+    let env = Env::default();
+    let (client, _, admins) = setup(&env);
+    let removed = admins.get(0).unwrap();
+    let newcomer = Address::generate(&env);
+
+    // The removed admin proposes before losing membership
+    let pending = client.propose_config_change(&removed, &ConfigChange::Percentages(40, 30));
+
+    let mut new_admins = Vec::new(&env);
+    new_admins.push_back(admins.get(1).unwrap());
+    new_admins.push_back(admins.get(2).unwrap());
+    new_admins.push_back(newcomer.clone());
+    let rotation = client.propose_config_change(
+        &admins.get(1).unwrap(),
+        &ConfigChange::Admins(new_admins, 2),
+    );
+    client.approve_config_change(&admins.get(2).unwrap(), &rotation);
+
+    // One current approval plus the stale one is not enough
+    assert!(!client.approve_config_change(&admins.get(1).unwrap(), &pending));
+    assert_eq!(client.get_collector_percentage(), None);
+
+    assert!(client.approve_config_change(&newcomer, &pending));
+    assert_eq!(client.get_collector_percentage(), Some(40));
+}
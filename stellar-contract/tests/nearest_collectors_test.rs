@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+fn setup(env: &Env) -> ScavengerContractClient<'_> {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+    env.mock_all_auths();
+    client
+}
+
+fn register_at(
+    env: &Env,
+    client: &ScavengerContractClient,
+    role: ParticipantRole,
+    latitude: i128,
+    longitude: i128,
+) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &role, &symbol_short!("p"), &latitude, &longitude);
+    address
+}
+
+#[test]
+fn test_nearest_collectors_ordered_by_distance() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let far = register_at(&env, &client, ParticipantRole::Collector, 10_000_000, 10_000_000);
+    let near = register_at(&env, &client, ParticipantRole::Collector, 1_000_000, 0);
+    let middle = register_at(&env, &client, ParticipantRole::Collector, -3_000_000, 4_000_000);
+
+    let result = client.find_nearest_collectors(&0, &0, &10);
+    assert_eq!(result.len(), 3);
+    assert_eq!(result.get(0).unwrap(), (near, 1_000_000i128 * 1_000_000));
+    assert_eq!(result.get(1).unwrap(), (middle, 5_000_000i128 * 5_000_000));
+    assert_eq!(result.get(2).unwrap(), (far, 2 * 10_000_000i128 * 10_000_000));
+}
+
+#[test]
+fn test_nearest_collectors_respects_limit() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    register_at(&env, &client, ParticipantRole::Collector, 50_000_000, 50_000_000);
+    let closest = register_at(&env, &client, ParticipantRole::Collector, 40_100_000, -73_900_000);
+    let second = register_at(&env, &client, ParticipantRole::Collector, 41_000_000, -73_000_000);
+
+    let result = client.find_nearest_collectors(&40_000_000, &-74_000_000, &2);
+    assert_eq!(result.len(), 2);
+    assert_eq!(result.get(0).unwrap().0, closest);
+    assert_eq!(result.get(1).unwrap().0, second);
+
+    assert_eq!(client.find_nearest_collectors(&0, &0, &0).len(), 0);
+}
+
+#[test]
+fn test_nearest_collectors_ignores_other_roles() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    register_at(&env, &client, ParticipantRole::Recycler, 0, 0);
+    register_at(&env, &client, ParticipantRole::Manufacturer, 0, 1);
+    let collector = register_at(&env, &client, ParticipantRole::Collector, 5_000_000, 5_000_000);
+
+    let result = client.find_nearest_collectors(&0, &0, &5);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result.get(0).unwrap().0, collector);
+}
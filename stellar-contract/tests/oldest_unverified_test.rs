@@ -0,0 +1,53 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Ledger, Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup_collector_recycler;
+
+fn submit_at(env: &Env, client: &ScavengerContractClient, waste_type: WasteType, submitter: &Address, timestamp: u64) -> u64 {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+    let desc = String::from_str(env, "Test");
+    client.submit_material(&waste_type, &1000, submitter, &desc).id
+}
+
+#[test]
+fn test_oldest_unverified_moves_forward_as_verified() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+
+    assert_eq!(client.get_oldest_unverified(&None), None);
+
+    let first = submit_at(&env, &client, WasteType::Paper, &collector, 100);
+    let second = submit_at(&env, &client, WasteType::Metal, &collector, 200);
+    let third = submit_at(&env, &client, WasteType::Paper, &collector, 300);
+
+    assert_eq!(client.get_oldest_unverified(&None), Some(first));
+
+    client.verify_material(&first, &recycler);
+    assert_eq!(client.get_oldest_unverified(&None), Some(second));
+
+    client.reject_material(&second, &recycler);
+    assert_eq!(client.get_oldest_unverified(&None), Some(third));
+
+    client.verify_material(&third, &recycler);
+    assert_eq!(client.get_oldest_unverified(&None), None);
+}
+
+#[test]
+fn test_oldest_unverified_filtered_by_type() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+
+    let paper = submit_at(&env, &client, WasteType::Paper, &collector, 100);
+    let metal = submit_at(&env, &client, WasteType::Metal, &collector, 200);
+    let later_metal = submit_at(&env, &client, WasteType::Metal, &collector, 300);
+
+    assert_eq!(client.get_oldest_unverified(&Some(WasteType::Metal)), Some(metal));
+    assert_eq!(client.get_oldest_unverified(&Some(WasteType::Paper)), Some(paper));
+    assert_eq!(client.get_oldest_unverified(&Some(WasteType::Glass)), None);
+
+    client.verify_material(&metal, &recycler);
+    assert_eq!(client.get_oldest_unverified(&Some(WasteType::Metal)), Some(later_metal));
+}
@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use soroban_sdk::Env;
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup;
+
+#[test]
+fn test_chain_without_transfers_is_current_owner() {
+    let env = Env::default();
+    let (client, _, _, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &1000, &recycler, &0, &0);
+
+    let chain = client.get_ownership_chain(&waste_id);
+    assert_eq!(chain.len(), 1);
+    assert_eq!(chain.get(0).unwrap(), recycler);
+}
+
+#[test]
+fn test_chain_after_one_transfer() {
+    let env = Env::default();
+    let (client, _, collector, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &2000, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &10, &20);
+
+    let chain = client.get_ownership_chain(&waste_id);
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain.get(0).unwrap(), recycler);
+    assert_eq!(chain.get(1).unwrap(), collector);
+}
+
+#[test]
+fn test_chain_after_multiple_transfers() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &1500, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &10, &20);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &30, &40);
+
+    let chain = client.get_ownership_chain(&waste_id);
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain.get(0).unwrap(), recycler);
+    assert_eq!(chain.get(1).unwrap(), collector);
+    assert_eq!(chain.get(2).unwrap(), manufacturer);
+
+    // The chain ends with the current owner
+    let (waste, _) = client.get_waste_full(&waste_id).unwrap();
+    assert_eq!(chain.last().unwrap(), waste.current_owner);
+}
+
+#[test]
+fn test_chain_empty_for_missing_waste() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    assert_eq!(client.get_ownership_chain(&99).len(), 0);
+}
@@ -0,0 +1,51 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_collector_recycler;
+
+#[test]
+fn test_badges_for_ten_submissions_at_eighty_percent() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup_collector_recycler(&env);
+    let description = String::from_str(&env, "Paper");
+
+    for i in 0..10 {
+        let material = client.submit_material(&WasteType::Paper, &1000, &submitter, &description);
+        if i < 8 {
+            client.verify_material(&material.id, &verifier);
+        }
+    }
+
+    let (rate, active_recycler, verified_contributor) = client.get_participant_badges(&submitter);
+    assert_eq!(rate, 80);
+    assert!(active_recycler);
+    assert!(verified_contributor);
+}
+
+#[test]
+fn test_badges_below_thresholds() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup_collector_recycler(&env);
+    let description = String::from_str(&env, "Paper");
+
+    for i in 0..4 {
+        let material = client.submit_material(&WasteType::Paper, &1000, &submitter, &description);
+        if i < 3 {
+            client.verify_material(&material.id, &verifier);
+        }
+    }
+
+    assert_eq!(client.get_participant_badges(&submitter), (75, false, false));
+}
+
+#[test]
+fn test_badges_default_without_stats() {
+    let env = Env::default();
+    let (client, _, _) = setup_collector_recycler(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(client.get_participant_badges(&stranger), (0, false, false));
+}
@@ -0,0 +1,46 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+#[test]
+fn test_profile_matches_individual_getters() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let manufacturer = Address::generate(&env);
+    let recycler = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    let desc = String::from_str(&env, "Offcuts");
+    let material = client.submit_material(&WasteType::Metal, &3000, &manufacturer, &desc);
+    client.submit_material(&WasteType::Paper, &1000, &manufacturer, &desc);
+    client.verify_material(&material.id, &recycler);
+    client.create_incentive(&manufacturer, &WasteType::Metal, &100, &5000);
+    client.create_incentive(&manufacturer, &WasteType::Glass, &50, &2000);
+
+    let profile = client.export_participant_profile(&manufacturer).unwrap();
+
+    assert_eq!(profile.participant, client.get_participant(&manufacturer).unwrap());
+    assert_eq!(profile.stats, client.get_stats(&manufacturer).unwrap());
+    assert_eq!(profile.waste_ids, client.get_participant_wastes(&manufacturer));
+    assert_eq!(profile.incentive_ids, client.get_incentives_by_rewarder(&manufacturer));
+
+    assert_eq!(profile.waste_ids.len(), 2);
+    assert_eq!(profile.incentive_ids.len(), 2);
+    assert_eq!(profile.stats.verified_submissions, 1);
+}
+
+#[test]
+fn test_profile_none_for_unregistered() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let stranger = Address::generate(&env);
+    assert!(client.export_participant_profile(&stranger).is_none());
+}
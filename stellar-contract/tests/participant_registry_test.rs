@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::ParticipantRole;
+
+mod common;
+use common::setup_contract;
+
+#[test]
+fn test_participant_count_after_registrations() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+    assert_eq!(client.get_participant_count(), 0);
+
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let user3 = Address::generate(&env);
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("u1"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("u2"), &0, &0);
+    client.register_participant(&user3, &ParticipantRole::Manufacturer, &symbol_short!("u3"), &0, &0);
+
+    assert_eq!(client.get_participant_count(), 3);
+
+    let all = client.get_all_participants(&0, &10);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(0).unwrap(), user1);
+    assert_eq!(all.get(1).unwrap(), user2);
+    assert_eq!(all.get(2).unwrap(), user3);
+
+    let page = client.get_all_participants(&1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), user2);
+
+    assert_eq!(client.get_all_participants(&3, &10).len(), 0);
+}
+
+#[test]
+fn test_duplicate_registration_does_not_double_count() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    client.register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("user"), &0, &0);
+    let result = client.try_register_participant(&user, &ParticipantRole::Recycler, &symbol_short!("user"), &0, &0);
+    assert!(result.is_err());
+
+    assert_eq!(client.get_participant_count(), 1);
+}
+
+#[test]
+fn test_deregistered_participant_stays_in_registry() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+
+    client.register_participant(&user1, &ParticipantRole::Recycler, &symbol_short!("u1"), &0, &0);
+    client.register_participant(&user2, &ParticipantRole::Collector, &symbol_short!("u2"), &0, &0);
+    client.deregister_participant(&user1);
+
+    assert_eq!(client.get_participant_count(), 2);
+
+    let active: u32 = client
+        .get_all_participants(&0, &10)
+        .iter()
+        .filter(|addr| client.get_participant(addr).unwrap().is_registered)
+        .count() as u32;
+    assert_eq!(active, 1);
+}
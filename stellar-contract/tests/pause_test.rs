@@ -0,0 +1,128 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String, Vec};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+mod common;
+use common::setup_admin_collector;
+
+#[test]
+#[should_panic(expected = "Contract is paused")]
+fn test_pause_blocks_submissions() {
+    let env = Env::default();
+    let (client, admin, collector) = setup_admin_collector(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    client.pause(&admin);
+    assert!(client.is_paused());
+
+    client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+}
+
+#[test]
+fn test_unpause_restores_submissions() {
+    let env = Env::default();
+    let (client, admin, collector) = setup_admin_collector(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    client.pause(&admin);
+    assert!(client.try_submit_material(&WasteType::Metal, &1000, &collector, &desc).is_err());
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    assert_eq!(material.weight, 1000);
+}
+
+#[test]
+fn test_getters_work_while_paused() {
+    let env = Env::default();
+    let (client, admin, collector) = setup_admin_collector(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.pause(&admin);
+
+    assert!(client.get_waste(&material.id).is_some());
+    assert!(client.get_participant(&collector).is_some());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_non_admin_cannot_pause() {
+    let env = Env::default();
+    let (client, _, collector) = setup_admin_collector(&env);
+
+    client.pause(&collector);
+}
+
+#[test]
+fn test_pause_blocks_batch_and_v2_paths() {
+    let env = Env::default();
+    let (client, admin, collector) = setup_admin_collector(&env);
+    let recycler = Address::generate(&env);
+    let manufacturer = Address::generate(&env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    let waste_id = client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+    client.pause(&admin);
+
+    let mut batch = Vec::new(&env);
+    batch.push_back((WasteType::Metal, 1000u64, desc.clone()));
+    assert!(client.try_submit_materials_batch(&batch, &collector).is_err());
+
+    let mut ids = Vec::new(&env);
+    ids.push_back(material.id);
+    assert!(client.try_verify_materials_batch(&ids, &recycler).is_err());
+    assert!(client.try_verify_materials_batch_resumable(&ids, &recycler, &0, &10).is_err());
+    assert!(client.try_transfer_wastes_batch(&ids, &collector, &manufacturer, &desc).is_err());
+    assert!(client.try_reject_material(&material.id, &recycler).is_err());
+
+    assert!(client.try_recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0).is_err());
+    assert!(client.try_transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0).is_err());
+
+    // Nothing changed while paused
+    assert!(!client.get_waste(&material.id).unwrap().verified);
+    assert_eq!(client.get_waste_full(&waste_id).unwrap().0.current_owner, recycler);
+
+    // The same calls go through once unpaused
+    client.unpause(&admin);
+    assert_eq!(client.submit_materials_batch(&batch, &collector).len(), 1);
+    assert_eq!(client.verify_materials_batch(&ids, &recycler).len(), 1);
+    assert_eq!(client.transfer_wastes_batch(&ids, &collector, &manufacturer, &desc).len(), 1);
+    client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Contract is paused")]
+fn test_pause_blocks_revoke_verification() {
+    let env = Env::default();
+    let (client, admin, collector) = setup_admin_collector(&env);
+    let recycler = Address::generate(&env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+    client.pause(&admin);
+
+    client.revoke_verification(&material.id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Contract is paused")]
+fn test_pause_blocks_material_promotion() {
+    let env = Env::default();
+    let (client, admin, collector) = setup_admin_collector(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.pause(&admin);
+
+    client.promote_material_to_waste(&material.id, &6_500_000, &3_400_000);
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use soroban_sdk::{Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn verified_material(
+    env: &Env,
+    client: &ScavengerContractClient,
+    waste_type: WasteType,
+    weight: u64,
+    collector: &Address,
+    recycler: &Address,
+) -> u64 {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&waste_type, &weight, collector, &desc);
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+#[test]
+fn test_preview_fully_covered_reward() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let material_id = verified_material(&env, &client, WasteType::Metal, 5000, &collector, &recycler);
+
+    // 5kg * 100 = 500, well within budget
+    assert_eq!(client.preview_claim(&incentive.id, &material_id), 500);
+
+    // Previewing leaves the incentive untouched and matches the claim
+    assert_eq!(client.get_incentive_by_id(&incentive.id).unwrap().remaining_budget, 10000);
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &material_id, &collector), 500);
+}
+
+#[test]
+fn test_preview_capped_when_budget_short() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &300);
+    let material_id = verified_material(&env, &client, WasteType::Metal, 5000, &collector, &recycler);
+
+    // 5kg * 100 = 500 exceeds the 300 point budget, so the claim pays what remains
+    assert_eq!(client.preview_claim(&incentive.id, &material_id), 300);
+    assert_eq!(client.get_incentive_by_id(&incentive.id).unwrap().remaining_budget, 300);
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &material_id, &collector), 300);
+
+    let updated = client.get_incentive_by_id(&incentive.id).unwrap();
+    assert_eq!(updated.remaining_budget, 0);
+    assert!(!updated.active);
+}
+
+#[test]
+fn test_preview_zero_when_not_claimable() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+
+    // Type mismatch
+    let paper_id = verified_material(&env, &client, WasteType::Paper, 5000, &collector, &recycler);
+    assert_eq!(client.preview_claim(&incentive.id, &paper_id), 0);
+
+    // Unverified material
+    let desc = String::from_str(&env, "Test");
+    let unverified = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    assert_eq!(client.preview_claim(&incentive.id, &unverified.id), 0);
+
+    // Already claimed
+    let metal_id = verified_material(&env, &client, WasteType::Metal, 5000, &collector, &recycler);
+    client.claim_incentive_reward(&incentive.id, &metal_id, &collector);
+    assert_eq!(client.preview_claim(&incentive.id, &metal_id), 0);
+
+    // Unknown incentive or material
+    assert_eq!(client.preview_claim(&999, &metal_id), 0);
+    assert_eq!(client.preview_claim(&incentive.id, &999), 0);
+}
+
+#[test]
+fn test_preview_zero_for_inactive_incentive() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Glass, &100, &10000);
+    let material_id = verified_material(&env, &client, WasteType::Glass, 2000, &collector, &recycler);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+
+    assert_eq!(client.preview_claim(&incentive.id, &material_id), 0);
+}
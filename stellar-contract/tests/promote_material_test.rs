@@ -0,0 +1,52 @@
+#![cfg(test)]
+
+use soroban_sdk::{vec, Env, String};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_collector;
+
+#[test]
+fn test_promoted_waste_matches_material() {
+    let env = Env::default();
+    let (client, collector) = setup_collector(&env);
+
+    let desc = String::from_str(&env, "Cans");
+    let material = client.submit_material(&WasteType::Metal, &2500, &collector, &desc);
+    assert_eq!(client.get_promoted_waste(&material.id), None);
+
+    let waste_id = client.promote_material_to_waste(&material.id, &6_500_000, &3_400_000);
+    assert_ne!(waste_id, material.id as u128);
+    assert_eq!(client.get_promoted_waste(&material.id), Some(waste_id));
+
+    let (waste, history) = client.get_waste_full(&waste_id).unwrap();
+    assert_eq!(waste.waste_type, WasteType::Metal);
+    assert_eq!(waste.weight, 2500);
+    assert_eq!(waste.current_owner, collector);
+    assert_eq!((waste.latitude, waste.longitude), (6_500_000, 3_400_000));
+    assert!(waste.is_active);
+    assert_eq!(history.len(), 0);
+
+    assert_eq!(client.get_unconfirmed_wastes_for(&collector, &10), vec![&env, waste_id]);
+}
+
+#[test]
+#[should_panic(expected = "Material already promoted")]
+fn test_cannot_promote_twice() {
+    let env = Env::default();
+    let (client, collector) = setup_collector(&env);
+
+    let desc = String::from_str(&env, "Cans");
+    let material = client.submit_material(&WasteType::Metal, &2500, &collector, &desc);
+    client.promote_material_to_waste(&material.id, &0, &0);
+    client.promote_material_to_waste(&material.id, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Material not found")]
+fn test_promote_missing_material() {
+    let env = Env::default();
+    let (client, _) = setup_collector(&env);
+
+    client.promote_material_to_waste(&42, &0, &0);
+}
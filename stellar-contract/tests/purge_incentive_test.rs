@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_manufacturer;
+
+#[test]
+fn test_purge_spent_incentive() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let spent = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &1000);
+    let kept = client.create_incentive(&manufacturer, &WasteType::Metal, &50, &1000);
+
+    // Deactivating and withdrawing leaves no budget behind
+    client.deactivate_incentive(&spent.id, &manufacturer);
+    client.withdraw_incentive_budget(&spent.id, &manufacturer);
+    client.purge_incentive(&spent.id, &manufacturer);
+
+    assert!(!client.incentive_exists(&spent.id));
+    assert!(client.get_incentive_by_id(&spent.id).is_none());
+
+    let by_rewarder = client.get_incentives_by_rewarder(&manufacturer);
+    assert_eq!(by_rewarder.len(), 1);
+    assert_eq!(by_rewarder.get(0).unwrap(), kept.id);
+
+    let by_type = client.get_incentives_by_waste_type(&WasteType::Metal);
+    assert_eq!(by_type.len(), 1);
+    assert_eq!(by_type.get(0).unwrap().id, kept.id);
+}
+
+#[test]
+#[should_panic(expected = "Cannot purge active incentive")]
+fn test_purge_active_incentive_panics() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &1000);
+    client.purge_incentive(&incentive.id, &manufacturer);
+}
+
+#[test]
+#[should_panic(expected = "Cannot purge incentive with remaining budget")]
+fn test_purge_with_remaining_budget_panics() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &1000);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+    client.purge_incentive(&incentive.id, &manufacturer);
+}
+
+#[test]
+#[should_panic(expected = "Only incentive creator can purge")]
+fn test_purge_by_other_rewarder_panics() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &1000);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+    client.withdraw_incentive_budget(&incentive.id, &manufacturer);
+
+    let other = Address::generate(&env);
+    client.purge_incentive(&incentive.id, &other);
+}
@@ -0,0 +1,48 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_manufacturer;
+
+#[test]
+fn test_reactivate_incentive_with_budget_remaining() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &10000);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+    assert!(!client.get_incentive_by_id(&incentive.id).unwrap().active);
+
+    let reactivated = client.reactivate_incentive(&incentive.id, &manufacturer);
+    assert!(reactivated.active);
+    assert_eq!(reactivated.id, incentive.id);
+    assert_eq!(reactivated.remaining_budget, 10000);
+    assert!(client.get_incentive_by_id(&incentive.id).unwrap().active);
+}
+
+#[test]
+#[should_panic(expected = "Cannot reactivate exhausted incentive")]
+fn test_reactivate_exhausted_incentive_panics() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &50, &0);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+
+    client.reactivate_incentive(&incentive.id, &manufacturer);
+}
+
+#[test]
+#[should_panic(expected = "Only incentive creator can reactivate")]
+fn test_reactivate_incentive_non_creator() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+    let other = Address::generate(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Glass, &50, &10000);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+
+    client.reactivate_incentive(&incentive.id, &other);
+}
@@ -0,0 +1,59 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_recycler;
+
+#[test]
+fn test_recycle_waste_builder_without_confirmer() {
+    let env = Env::default();
+    let (client, recycler) = setup_recycler(&env);
+
+    let waste = client.recycle_waste_builder(
+        &WasteType::Glass,
+        &2500,
+        &recycler,
+        &40_700_000,
+        &-74_000_000,
+        &1_700_000_000,
+        &None,
+    );
+
+    assert_eq!(waste.waste_type, WasteType::Glass);
+    assert_eq!(waste.weight, 2500);
+    assert_eq!(waste.current_owner, recycler);
+    assert_eq!(waste.latitude, 40_700_000);
+    assert_eq!(waste.longitude, -74_000_000);
+    assert_eq!(waste.recycled_timestamp, 1_700_000_000);
+    assert!(waste.is_active);
+    assert!(!waste.is_confirmed);
+    // Confirmer defaults to the owner
+    assert_eq!(waste.confirmer, recycler);
+}
+
+#[test]
+fn test_recycle_waste_builder_with_confirmer() {
+    let env = Env::default();
+    let (client, recycler) = setup_recycler(&env);
+    let confirmer = Address::generate(&env);
+
+    let waste = client.recycle_waste_builder(
+        &WasteType::Metal,
+        &1000,
+        &recycler,
+        &0,
+        &0,
+        &0,
+        &Some(confirmer.clone()),
+    );
+
+    assert!(waste.is_confirmed);
+    assert_eq!(waste.confirmer, confirmer);
+    assert_eq!(waste.current_owner, recycler);
+
+    // Stored waste can be reset by its confirmer like any other v2 waste
+    let reset = client.reset_waste_confirmation(&waste.waste_id, &confirmer);
+    assert!(!reset.is_confirmed);
+}
@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, Symbol, Vec};
+use stellar_scavngr_contract::ParticipantRole;
+
+mod common;
+use common::setup_admin;
+
+type Entry = (Address, ParticipantRole, Symbol, i128, i128);
+
+/// Five entries where the last one repeats the first address
+fn entries_with_duplicate(env: &Env) -> (Vec<Entry>, Vec<Address>) {
+    let roles = [
+        ParticipantRole::Recycler,
+        ParticipantRole::Collector,
+        ParticipantRole::Manufacturer,
+        ParticipantRole::Collector,
+    ];
+
+    let mut entries = Vec::new(env);
+    let mut addresses = Vec::new(env);
+    for role in roles {
+        let address = Address::generate(env);
+        entries.push_back((address.clone(), role, symbol_short!("p"), 0, 0));
+        addresses.push_back(address);
+    }
+    entries.push_back((addresses.get(0).unwrap(), ParticipantRole::Manufacturer, symbol_short!("dup"), 0, 0));
+
+    (entries, addresses)
+}
+
+#[test]
+fn test_batch_registers_and_skips_duplicate() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let (entries, addresses) = entries_with_duplicate(&env);
+
+    let registered = client.register_participants_batch(&admin, &entries, &true);
+    assert_eq!(registered.len(), 4);
+
+    for address in addresses.iter() {
+        assert!(client.is_participant_registered(&address));
+    }
+
+    // The duplicate keeps its original registration
+    let first = client.get_participant(&addresses.get(0).unwrap()).unwrap();
+    assert_eq!(first.role, ParticipantRole::Recycler);
+    assert_eq!(first.name, symbol_short!("p"));
+}
+
+#[test]
+fn test_batch_skips_previously_registered() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let (entries, addresses) = entries_with_duplicate(&env);
+
+    let existing = addresses.get(1).unwrap();
+    client.register_participant(&existing, &ParticipantRole::Recycler, &symbol_short!("old"), &0, &0);
+
+    let registered = client.register_participants_batch(&admin, &entries, &true);
+    assert_eq!(registered.len(), 3);
+    assert_eq!(client.get_participant(&existing).unwrap().role, ParticipantRole::Recycler);
+}
+
+#[test]
+#[should_panic(expected = "Participant already registered")]
+fn test_batch_errors_on_duplicate() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let (entries, _) = entries_with_duplicate(&env);
+
+    client.register_participants_batch(&admin, &entries, &false);
+}
+
+#[test]
+fn test_batch_error_mode_rolls_back() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let (entries, addresses) = entries_with_duplicate(&env);
+
+    let result = client.try_register_participants_batch(&admin, &entries, &false);
+    assert!(result.is_err());
+
+    // Nothing from the failed batch was stored
+    for address in addresses.iter() {
+        assert!(!client.is_participant_registered(&address));
+    }
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_batch_requires_admin() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let (entries, _) = entries_with_duplicate(&env);
+
+    let not_admin = Address::generate(&env);
+    client.register_participants_batch(&not_admin, &entries, &true);
+}
@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, Symbol, TryIntoVal, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, u128) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let owner = Address::generate(env);
+    let verifier = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&owner, &ParticipantRole::Recycler, &symbol_short!("owner"), &0, &0);
+    client.register_participant(&verifier, &ParticipantRole::Recycler, &symbol_short!("ver"), &0, &0);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
+
+    (client, owner, verifier, waste_id)
+}
+
+#[test]
+fn test_reject_waste_details_by_non_owner() {
+    let env = Env::default();
+    let (client, _, verifier, waste_id) = setup(&env);
+    let reason = symbol_short!("mislabel");
+
+    let waste = client.reject_waste_details(&waste_id, &verifier, &reason);
+
+    assert!(!waste.is_active);
+    assert!(!waste.is_confirmed);
+    assert_eq!(waste.rejection_reason, Some(reason.clone()));
+    assert_eq!(waste.confirmer, verifier);
+
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    let expected_topics: Vec<soroban_sdk::Val> = (symbol_short!("v1"), symbol_short!("rejected"), waste_id).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+
+    let event_data: (Address, Symbol) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(event_data.0, verifier);
+    assert_eq!(event_data.1, reason);
+}
+
+#[test]
+#[should_panic(expected = "Owner cannot reject own waste")]
+fn test_owner_cannot_reject_own_waste() {
+    let env = Env::default();
+    let (client, owner, _, waste_id) = setup(&env);
+
+    client.reject_waste_details(&waste_id, &owner, &symbol_short!("fraud"));
+}
+
+#[test]
+#[should_panic(expected = "Confirmed waste cannot be rejected")]
+fn test_confirmed_waste_cannot_be_rejected() {
+    let env = Env::default();
+    let (client, _, verifier, waste_id) = setup(&env);
+
+    client.confirm_waste_details(&waste_id, &verifier);
+    client.reject_waste_details(&waste_id, &verifier, &symbol_short!("fraud"));
+}
+
+#[test]
+#[should_panic(expected = "Cannot confirm deactivated waste")]
+fn test_rejected_waste_cannot_be_confirmed() {
+    let env = Env::default();
+    let (client, _, verifier, waste_id) = setup(&env);
+
+    client.reject_waste_details(&waste_id, &verifier, &symbol_short!("fraud"));
+    client.confirm_waste_details(&waste_id, &verifier);
+}
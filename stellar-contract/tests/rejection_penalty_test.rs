@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Events, Env, IntoVal, String, TryIntoVal, Val, Vec};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_with_admin;
+
+#[test]
+fn test_rejection_deducts_penalty_and_emits_event() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+    client.set_rejection_penalty(&admin, &5);
+    assert_eq!(client.get_rejection_penalty(), 5);
+
+    let desc = String::from_str(&env, "Test");
+    let good = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    client.verify_material(&good.id, &recycler);
+    let points_before = client.get_stats(&collector).unwrap().total_points;
+    assert!(points_before > 5);
+
+    let bad = client.submit_material(&WasteType::Paper, &1000, &collector, &desc);
+    client.reject_material(&bad.id, &recycler);
+
+    let stats = client.get_stats(&collector).unwrap();
+    assert_eq!(stats.total_points, points_before - 5);
+    assert_eq!(stats.rejected_submissions, 1);
+
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    let expected_topics: Vec<Val> = (symbol_short!("v1"), symbol_short!("penalized"), collector.clone()).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+    let data: (u64, u64, u64) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(data, (bad.id, 5, points_before - 5));
+
+    // Recomputing keeps the penalty
+    assert_eq!(client.recompute_stats(&admin, &collector).total_points, points_before - 5);
+}
+
+#[test]
+fn test_penalty_saturates_at_zero() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+    client.set_rejection_penalty(&admin, &50);
+
+    let desc = String::from_str(&env, "Test");
+    let bad = client.submit_material(&WasteType::Paper, &1000, &collector, &desc);
+    client.reject_material(&bad.id, &recycler);
+
+    assert_eq!(client.get_stats(&collector).unwrap().total_points, 0);
+
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    let expected_topics: Vec<Val> = (symbol_short!("v1"), symbol_short!("penalized"), collector.clone()).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+}
+
+#[test]
+fn test_no_penalty_by_default() {
+    let env = Env::default();
+    let (client, _, collector, recycler) = setup_with_admin(&env);
+
+    let desc = String::from_str(&env, "Test");
+    let good = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    client.verify_material(&good.id, &recycler);
+    let points_before = client.get_stats(&collector).unwrap().total_points;
+
+    let bad = client.submit_material(&WasteType::Paper, &1000, &collector, &desc);
+    client.reject_material(&bad.id, &recycler);
+
+    assert_eq!(client.get_stats(&collector).unwrap().total_points, points_before);
+    let penalized = env.events().all().iter().any(|event| {
+        let topics: Vec<Val> = (symbol_short!("v1"), symbol_short!("penalized"), collector.clone()).into_val(&env);
+        event.1 == topics
+    });
+    assert!(!penalized);
+}
@@ -1,26 +1,23 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env};
-use stellar_contract::{ScavengerContract, ScavengerContractClient};
-
-fn create_test_contract(env: &Env) -> (ScavengerContractClient, Address, Address, Address) {
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, Symbol, Val, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn create_test_contract(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
     let contract_id = env.register_contract(None, ScavengerContract);
     let client = ScavengerContractClient::new(env, &contract_id);
 
-    let admin = Address::generate(env);
-    let token_address = Address::generate(env);
-    let charity_address = Address::generate(env);
+    let owner = Address::generate(env);
+    let confirmer = Address::generate(env);
 
-    client.initialize(
-        &admin,
-        &token_address,
-        &charity_address,
-        &50,
-        &30,
-        &20,
-    );
+    client.register_participant(&owner, &ParticipantRole::Recycler, &symbol_short!("owner"), &0, &0);
+    client.register_participant(&confirmer, &ParticipantRole::Collector, &symbol_short!("conf"), &0, &0);
 
-    (client, admin, token_address, charity_address)
+    (client, owner, confirmer)
 }
 
 #[test]
@@ -28,99 +25,94 @@ fn test_reset_waste_confirmation() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, _admin, _token, _charity) = create_test_contract(&env);
-
-    let owner = Address::generate(&env);
-    let confirmer = Address::generate(&env);
-
-    // Register owner as collector
-    client.register_participant(&owner, &stellar_contract::Role::Collector);
+    let (client, owner, confirmer) = create_test_contract(&env);
 
     // Register waste
-    let waste = client.register_waste(
-        &owner,
-        &stellar_contract::WasteType::Plastic,
-        &1000,
-        &45_000_000,
-        &-93_000_000,
-    );
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
 
     // Confirm the waste
-    client.confirm_waste_details(&waste.waste_id, &confirmer);
-
-    // Verify waste is confirmed
-    let confirmed_waste = client.get_waste(&waste.waste_id);
-    assert_eq!(confirmed_waste.is_confirmed, true);
+    let confirmed_waste = client.confirm_waste_details(&waste_id, &confirmer);
+    assert!(confirmed_waste.is_confirmed);
     assert_eq!(confirmed_waste.confirmer, confirmer);
 
-    // Reset confirmation
-    let reset_waste = client.reset_waste_confirmation(&waste.waste_id, &owner);
+    // Reset confirmation as the confirmer
+    let reset_waste = client.reset_waste_confirmation(&waste_id, &confirmer);
 
     // Verify confirmation is reset
-    assert_eq!(reset_waste.is_confirmed, false);
+    assert!(!reset_waste.is_confirmed);
     assert_eq!(reset_waste.confirmer, owner);
 
-    // Verify waste can be re-confirmed
-    let reconfirmed = client.confirm_waste_details(&waste.waste_id, &confirmer);
-    assert_eq!(reconfirmed.is_confirmed, true);
-    assert_eq!(reconfirmed.confirmer, confirmer);
+    let event = env.events().all().last().unwrap();
+    let expected_topics: Vec<Val> = (symbol_short!("v1"), Symbol::new(&env, "unconfirmed"), waste_id).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+
+    // Verify waste can be re-confirmed by a different party
+    let other = Address::generate(&env);
+    client.register_participant(&other, &ParticipantRole::Collector, &symbol_short!("other"), &0, &0);
+    let reconfirmed = client.confirm_waste_details(&waste_id, &other);
+    assert!(reconfirmed.is_confirmed);
+    assert_eq!(reconfirmed.confirmer, other);
 }
 
 #[test]
-#[should_panic(expected = "Only owner can reset confirmation")]
-fn test_reset_waste_confirmation_non_owner() {
+fn test_admin_can_reset_confirmation() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, _admin, _token, _charity) = create_test_contract(&env);
+    let (client, owner, confirmer) = create_test_contract(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
 
-    let owner = Address::generate(&env);
-    let confirmer = Address::generate(&env);
-    let non_owner = Address::generate(&env);
+    let waste_id = client.recycle_waste(&WasteType::Metal, &1000, &owner, &45_000_000, &-93_000_000);
+    client.confirm_waste_details(&waste_id, &confirmer);
 
-    // Register owner as collector
-    client.register_participant(&owner, &stellar_contract::Role::Collector);
+    let reset_waste = client.reset_waste_confirmation(&waste_id, &admin);
+    assert!(!reset_waste.is_confirmed);
+    assert_eq!(reset_waste.confirmer, owner);
+}
 
-    // Register waste
-    let waste = client.register_waste(
-        &owner,
-        &stellar_contract::WasteType::Plastic,
-        &1000,
-        &45_000_000,
-        &-93_000_000,
-    );
+#[test]
+#[should_panic(expected = "Only confirmer or admin can reset confirmation")]
+fn test_owner_cannot_reset_confirmation() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    // Confirm the waste
-    client.confirm_waste_details(&waste.waste_id, &confirmer);
+    let (client, owner, confirmer) = create_test_contract(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
+    client.confirm_waste_details(&waste_id, &confirmer);
 
-    // Try to reset as non-owner (should panic)
-    client.reset_waste_confirmation(&waste.waste_id, &non_owner);
+    // The owner is neither the confirmer nor the admin
+    client.reset_waste_confirmation(&waste_id, &owner);
 }
 
 #[test]
-#[should_panic(expected = "Waste is not confirmed")]
-fn test_reset_unconfirmed_waste() {
+#[should_panic(expected = "Only confirmer or admin can reset confirmation")]
+fn test_reset_waste_confirmation_by_stranger() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, _admin, _token, _charity) = create_test_contract(&env);
+    let (client, owner, confirmer) = create_test_contract(&env);
+    let stranger = Address::generate(&env);
 
-    let owner = Address::generate(&env);
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
+    client.confirm_waste_details(&waste_id, &confirmer);
 
-    // Register owner as collector
-    client.register_participant(&owner, &stellar_contract::Role::Collector);
+    client.reset_waste_confirmation(&waste_id, &stranger);
+}
 
-    // Register waste
-    let waste = client.register_waste(
-        &owner,
-        &stellar_contract::WasteType::Plastic,
-        &1000,
-        &45_000_000,
-        &-93_000_000,
-    );
+#[test]
+#[should_panic(expected = "Waste is not confirmed")]
+fn test_reset_unconfirmed_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, owner, _) = create_test_contract(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &45_000_000, &-93_000_000);
 
     // Try to reset unconfirmed waste (should panic)
-    client.reset_waste_confirmation(&waste.waste_id, &owner);
+    client.reset_waste_confirmation(&waste_id, &owner);
 }
 
 #[test]
@@ -129,9 +121,7 @@ fn test_reset_nonexistent_waste() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, _admin, _token, _charity) = create_test_contract(&env);
-
-    let owner = Address::generate(&env);
+    let (client, owner, _) = create_test_contract(&env);
 
     // Try to reset non-existent waste (should panic)
     client.reset_waste_confirmation(&999, &owner);
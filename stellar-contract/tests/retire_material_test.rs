@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, ScavengerError, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let owner = Address::generate(env);
+    let receiver = Address::generate(env);
+    let verifier = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&owner, &ParticipantRole::Collector, &symbol_short!("owner"), &0, &0);
+    client.register_participant(&receiver, &ParticipantRole::Manufacturer, &symbol_short!("recv"), &0, &0);
+    client.register_participant(&verifier, &ParticipantRole::Recycler, &symbol_short!("ver"), &0, &0);
+
+    (client, owner, receiver, verifier)
+}
+
+#[test]
+fn test_retire_material_success() {
+    let env = Env::default();
+    let (client, owner, _, _) = setup(&env);
+    let description = String::from_str(&env, "Burned");
+
+    let material = client.submit_material(&WasteType::Paper, &1000, &owner, &description);
+    assert!(!material.is_retired);
+
+    let retired = client.retire_material(&material.id, &owner);
+    assert!(retired.is_retired);
+    assert!(client.get_waste(&material.id).unwrap().is_retired);
+}
+
+#[test]
+fn test_active_materials_count_excludes_retired() {
+    let env = Env::default();
+    let (client, owner, _, _) = setup(&env);
+    let description = String::from_str(&env, "Test");
+
+    let m1 = client.submit_material(&WasteType::Paper, &1000, &owner, &description);
+    client.submit_material(&WasteType::Metal, &1000, &owner, &description);
+    client.submit_material(&WasteType::Glass, &1000, &owner, &description);
+    assert_eq!(client.get_active_materials_count(), 3);
+
+    client.retire_material(&m1.id, &owner);
+    assert_eq!(client.get_active_materials_count(), 2);
+}
+
+#[test]
+fn test_retired_material_cannot_be_transferred() {
+    let env = Env::default();
+    let (client, owner, receiver, _) = setup(&env);
+    let description = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Plastic, &1000, &owner, &description);
+    client.retire_material(&material.id, &owner);
+
+    let result = client.try_transfer_waste(&material.id, &owner, &receiver, &description);
+    assert_eq!(result, Err(Ok(ScavengerError::MaterialRetired)));
+}
+
+#[test]
+fn test_retired_material_cannot_be_verified() {
+    let env = Env::default();
+    let (client, owner, _, verifier) = setup(&env);
+    let description = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Plastic, &1000, &owner, &description);
+    client.retire_material(&material.id, &owner);
+
+    let result = client.try_verify_material(&material.id, &verifier);
+    assert_eq!(result, Err(Ok(ScavengerError::MaterialRetired)));
+}
+
+#[test]
+#[should_panic(expected = "Only material owner can retire")]
+fn test_non_owner_cannot_retire() {
+    let env = Env::default();
+    let (client, owner, receiver, _) = setup(&env);
+    let description = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &owner, &description);
+    client.retire_material(&material.id, &receiver);
+}
+
+#[test]
+#[should_panic(expected = "Material already retired")]
+fn test_cannot_retire_twice() {
+    let env = Env::default();
+    let (client, owner, _, _) = setup(&env);
+    let description = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &owner, &description);
+    client.retire_material(&material.id, &owner);
+    client.retire_material(&material.id, &owner);
+}
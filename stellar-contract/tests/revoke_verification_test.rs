@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_collector_recycler;
+
+#[test]
+fn test_revoke_verification_restores_stats() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup_collector_recycler(&env);
+    let description = String::from_str(&env, "Fake metal");
+
+    let material = client.submit_material(&WasteType::Metal, &5000, &submitter, &description);
+    let stats_before = client.get_stats(&submitter).unwrap();
+    let tokens_before = client.get_participant(&submitter).unwrap().total_tokens_earned;
+    let metrics_before = client.get_global_metrics();
+
+    client.verify_material(&material.id, &verifier);
+    assert_eq!(client.get_stats(&submitter).unwrap().total_points, 250);
+
+    let revoked = client.revoke_verification(&material.id, &verifier);
+    assert!(!revoked.verified);
+    assert!(!client.get_waste(&material.id).unwrap().verified);
+
+    let stats_after = client.get_stats(&submitter).unwrap();
+    assert_eq!(stats_after, stats_before);
+    assert_eq!(client.get_participant(&submitter).unwrap().total_tokens_earned, tokens_before);
+    assert_eq!(client.get_global_metrics(), metrics_before);
+}
+
+#[test]
+#[should_panic(expected = "Material not verified")]
+fn test_revoke_unverified_material_panics() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup_collector_recycler(&env);
+    let description = String::from_str(&env, "Paper");
+
+    let material = client.submit_material(&WasteType::Paper, &1000, &submitter, &description);
+    client.revoke_verification(&material.id, &verifier);
+}
+
+#[test]
+#[should_panic(expected = "Only recyclers can revoke verification")]
+fn test_non_recycler_cannot_revoke() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup_collector_recycler(&env);
+    let description = String::from_str(&env, "Paper");
+
+    let material = client.submit_material(&WasteType::Paper, &1000, &submitter, &description);
+    client.verify_material(&material.id, &verifier);
+    client.revoke_verification(&material.id, &submitter);
+}
+
+#[test]
+fn test_revoke_after_multiplier_change_claws_back_stored_reward() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup_collector_recycler(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    let description = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &5000, &submitter, &description);
+    client.verify_material(&material.id, &verifier);
+    assert_eq!(client.get_stats(&submitter).unwrap().total_points, 250);
+
+    // A later multiplier change does not alter what was paid
+    client.set_reward_multiplier(&admin, &WasteType::Metal, &500);
+    client.revoke_verification(&material.id, &verifier);
+    assert_eq!(client.get_stats(&submitter).unwrap().total_points, 0);
+}
+
+#[test]
+fn test_revoke_without_stored_reward_claws_back_nothing() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup_collector_recycler(&env);
+    let description = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &5000, &submitter, &description);
+    client.verify_material(&material.id, &verifier);
+
+    // Simulate a material verified before rewards were stored
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&("verify_reward", material.id));
+    });
+
+    client.revoke_verification(&material.id, &verifier);
+    assert!(!client.get_waste(&material.id).unwrap().verified);
+    assert_eq!(client.get_stats(&submitter).unwrap().total_points, 250);
+}
@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::{setup_contract, setup_with_admin};
+
+const EPOCH: u64 = 1000;
+
+/// Submit and verify a 10kg metal material at `timestamp`, returning the points awarded
+fn reward_at(env: &Env, client: &ScavengerContractClient, collector: &Address, recycler: &Address, timestamp: u64) -> u64 {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &10000, collector, &desc);
+
+    let before = client.get_stats(collector).unwrap().total_points;
+    client.verify_material(&material.id, recycler);
+    client.get_stats(collector).unwrap().total_points - before
+}
+
+#[test]
+fn test_rewards_halve_each_epoch() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+
+    client.set_halving(&admin, &EPOCH, &2);
+    assert_eq!(client.get_halving(), Some((EPOCH, 2)));
+
+    let full = reward_at(&env, &client, &collector, &recycler, EPOCH - 1);
+    assert_eq!(client.get_halving_epoch(), 0);
+    assert!(full > 0);
+
+    let halved = reward_at(&env, &client, &collector, &recycler, EPOCH);
+    assert_eq!(client.get_halving_epoch(), 1);
+    assert_eq!(halved, full / 2);
+
+    let quartered = reward_at(&env, &client, &collector, &recycler, 2 * EPOCH);
+    assert_eq!(client.get_halving_epoch(), 2);
+    assert_eq!(quartered, full / 4);
+}
+
+#[test]
+fn test_halving_configured_late_counts_from_contract_start() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+
+    let full = reward_at(&env, &client, &collector, &recycler, 1000);
+
+    // Configured three epochs after the admin was initialized at time 0
+    env.ledger().with_mut(|li| li.timestamp = 3 * EPOCH + 500);
+    client.set_halving(&admin, &EPOCH, &2);
+    assert_eq!(client.get_halving_epoch(), 3);
+    assert_eq!(reward_at(&env, &client, &collector, &recycler, 3 * EPOCH + 500), full / 8);
+
+    // Reconfiguring changes the schedule but never moves the start
+    client.set_halving(&admin, &(2 * EPOCH), &2);
+    assert_eq!(client.get_halving_epoch(), 1);
+    assert_eq!(reward_at(&env, &client, &collector, &recycler, 4 * EPOCH), full / 4);
+}
+
+#[test]
+fn test_start_time_recorded_at_initialization() {
+    let env = Env::default();
+    let client = setup_contract(&env);
+    let admin = Address::generate(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 5000);
+    client.initialize_admin(&admin);
+
+    env.ledger().with_mut(|li| li.timestamp = 5000 + 2 * EPOCH);
+    client.set_halving(&admin, &EPOCH, &2);
+    assert_eq!(client.get_halving_epoch(), 2);
+}
+
+#[test]
+fn test_divisor_is_capped_after_many_epochs() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+
+    client.set_halving(&admin, &1, &10);
+    assert_eq!(reward_at(&env, &client, &collector, &recycler, 1_000_000), 0);
+}
+
+#[test]
+fn test_no_halving_by_default() {
+    let env = Env::default();
+    let (client, _, collector, recycler) = setup_with_admin(&env);
+
+    let early = reward_at(&env, &client, &collector, &recycler, 0);
+    let late = reward_at(&env, &client, &collector, &recycler, 10_000_000);
+    assert_eq!(early, late);
+    assert_eq!(client.get_halving_epoch(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Halving divisor must be positive")]
+fn test_zero_divisor_rejected() {
+    let env = Env::default();
+    let (client, admin, _, _) = setup_with_admin(&env);
+
+    client.set_halving(&admin, &EPOCH, &0);
+}
+
+#[test]
+#[should_panic(expected = "Epoch length must be positive")]
+fn test_zero_epoch_rejected() {
+    let env = Env::default();
+    let (client, admin, _, _) = setup_with_admin(&env);
+
+    client.set_halving(&admin, &0, &2);
+}
@@ -0,0 +1,97 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, ScavengerError, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let submitter = Address::generate(env);
+    let verifier = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.register_participant(&submitter, &ParticipantRole::Collector, &symbol_short!("sub"), &0, &0);
+    client.register_participant(&verifier, &ParticipantRole::Recycler, &symbol_short!("ver"), &0, &0);
+
+    (client, admin, submitter, verifier)
+}
+
+#[test]
+fn test_default_multipliers_match_builtin_values() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    assert_eq!(client.get_reward_multiplier(&WasteType::Paper), 1);
+    assert_eq!(client.get_reward_multiplier(&WasteType::PetPlastic), 3);
+    assert_eq!(client.get_reward_multiplier(&WasteType::Plastic), 2);
+    assert_eq!(client.get_reward_multiplier(&WasteType::Metal), 5);
+    assert_eq!(client.get_reward_multiplier(&WasteType::Glass), 2);
+}
+
+#[test]
+fn test_default_multiplier_rewards_unchanged() {
+    let env = Env::default();
+    let (client, _, submitter, verifier) = setup(&env);
+    let description = String::from_str(&env, "Metal");
+
+    // 5kg * 5 * 10 = 250 points
+    let material = client.submit_material(&WasteType::Metal, &5000, &submitter, &description);
+    client.verify_material(&material.id, &verifier);
+
+    assert_eq!(client.get_stats(&submitter).unwrap().total_points, 250);
+    assert_eq!(client.get_participant(&submitter).unwrap().total_tokens_earned, 250);
+}
+
+#[test]
+fn test_changed_multiplier_applies_to_next_verification() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+    let description = String::from_str(&env, "Paper");
+
+    let first = client.submit_material(&WasteType::Paper, &2000, &submitter, &description);
+    client.verify_material(&first.id, &verifier);
+    // 2kg * 1 * 10 = 20 points
+    assert_eq!(client.get_stats(&submitter).unwrap().total_points, 20);
+
+    client.set_reward_multiplier(&admin, &WasteType::Paper, &4);
+    assert_eq!(client.get_reward_multiplier(&WasteType::Paper), 4);
+
+    let second = client.submit_material(&WasteType::Paper, &2000, &submitter, &description);
+    client.verify_material(&second.id, &verifier);
+    // 20 + 2kg * 4 * 10 = 100 points
+    assert_eq!(client.get_stats(&submitter).unwrap().total_points, 100);
+    assert_eq!(client.get_participant(&submitter).unwrap().total_tokens_earned, 100);
+
+    // Other types keep their defaults
+    assert_eq!(client.get_reward_multiplier(&WasteType::Metal), 5);
+}
+
+#[test]
+fn test_reward_overflow_rejects_verification() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+    let description = String::from_str(&env, "Scrap");
+
+    client.set_reward_multiplier(&admin, &WasteType::Metal, &u32::MAX);
+    let material = client.submit_material(&WasteType::Metal, &1_000_000_000_000, &submitter, &description);
+
+    assert_eq!(
+        client.try_verify_material(&material.id, &verifier),
+        Err(Ok(ScavengerError::Overflow))
+    );
+    assert!(!client.get_material(&material.id).unwrap().verified);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_reward_multiplier_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    client.set_reward_multiplier(&submitter, &WasteType::Paper, &4);
+}
@@ -0,0 +1,29 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+#[test]
+#[should_panic(expected = "Reward calculation overflow")]
+fn test_claim_with_overflowing_reward_panics() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let manufacturer = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let recycler = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    // 1e15 g * 1e6 points overflows u64 before the /1000 scaling
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &1_000_000, &u64::MAX);
+    let desc = String::from_str(&env, "Huge");
+    let material = client.submit_material(&WasteType::Metal, &1_000_000_000_000_000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+}
@@ -0,0 +1,60 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let manufacturer = Address::generate(env);
+    let collector = Address::generate(env);
+    let recycler = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    (client, admin, manufacturer, collector, recycler)
+}
+
+#[test]
+fn test_default_scale_is_per_kilogram() {
+    let env = Env::default();
+    let (client, _, manufacturer, _, _) = setup(&env);
+
+    assert_eq!(client.get_scale_factor(), 1000);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    assert_eq!(client.calculate_incentive_reward(&incentive.id, &500), 50);
+}
+
+#[test]
+fn test_scale_of_100_rewards_per_100_grams() {
+    let env = Env::default();
+    let (client, admin, manufacturer, collector, recycler) = setup(&env);
+
+    client.set_scale_factor(&admin, &100);
+
+    // 10 points per 100g: a 500g submission earns 50 instead of the per-kg 5
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &10, &10000);
+    assert_eq!(client.calculate_incentive_reward(&incentive.id, &500), 50);
+
+    let material = client.submit_material(&WasteType::Metal, &500, &collector, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &recycler);
+
+    assert_eq!(client.preview_claim(&incentive.id, &material.id), 50);
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &material.id, &collector), 50);
+}
+
+#[test]
+#[should_panic(expected = "Scale factor must be greater than zero")]
+fn test_zero_scale_rejected() {
+    let env = Env::default();
+    let (client, admin, _, _, _) = setup(&env);
+
+    client.set_scale_factor(&admin, &0);
+}
@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+use stellar_scavngr_contract::{ScavengerError, WasteType};
+
+mod common;
+use common::setup;
+
+#[test]
+fn test_submit_material_unregistered() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+    let stranger = Address::generate(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let result = client.try_submit_material(&WasteType::Paper, &1000, &stranger, &desc);
+    assert_eq!(result, Err(Ok(ScavengerError::NotRegistered)));
+}
+
+#[test]
+fn test_transfer_waste_errors() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let result = client.try_transfer_waste(&99, &collector, &recycler, &desc);
+    assert_eq!(result, Err(Ok(ScavengerError::WasteNotFound)));
+
+    let material = client.submit_material(&WasteType::Paper, &1000, &collector, &desc);
+    let result = client.try_transfer_waste(&material.id, &manufacturer, &recycler, &desc);
+    assert_eq!(result, Err(Ok(ScavengerError::Unauthorized)));
+
+    let stranger = Address::generate(&env);
+    let result = client.try_transfer_waste(&material.id, &collector, &stranger, &desc);
+    assert_eq!(result, Err(Ok(ScavengerError::NotRegistered)));
+}
+
+#[test]
+fn test_verify_material_errors() {
+    let env = Env::default();
+    let (client, _, collector, recycler) = setup(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let result = client.try_verify_material(&99, &recycler);
+    assert_eq!(result, Err(Ok(ScavengerError::WasteNotFound)));
+
+    let material = client.submit_material(&WasteType::Paper, &1000, &collector, &desc);
+    let result = client.try_verify_material(&material.id, &collector);
+    assert_eq!(result, Err(Ok(ScavengerError::Unauthorized)));
+
+    let stranger = Address::generate(&env);
+    let result = client.try_verify_material(&material.id, &stranger);
+    assert_eq!(result, Err(Ok(ScavengerError::NotRegistered)));
+}
+
+#[test]
+fn test_claim_incentive_reward_errors() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10000);
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+
+    let result = client.try_claim_incentive_reward(&99, &material.id, &collector);
+    assert_eq!(result, Err(Ok(ScavengerError::IncentiveNotFound)));
+
+    let result = client.try_claim_incentive_reward(&incentive.id, &material.id, &collector);
+    assert_eq!(result, Err(Ok(ScavengerError::NotVerified)));
+
+    client.verify_material(&material.id, &recycler);
+
+    let result = client.try_claim_incentive_reward(&incentive.id, &material.id, &recycler);
+    assert_eq!(result, Err(Ok(ScavengerError::Unauthorized)));
+
+    let paper = client.submit_material(&WasteType::Paper, &5000, &collector, &desc);
+    client.verify_material(&paper.id, &recycler);
+    let result = client.try_claim_incentive_reward(&incentive.id, &paper.id, &collector);
+    assert_eq!(result, Err(Ok(ScavengerError::WasteTypeMismatch)));
+}
+
+#[test]
+fn test_claim_incentive_reward_inactive_and_budget() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+
+    // A 0.5kg claim drains the metal sub-budget, leaving nothing for this one
+    let budgets = vec![&env, (WasteType::Metal, 50u64), (WasteType::Glass, 1000u64)];
+    let small = client.create_multi_type_incentive(&manufacturer, &100, &budgets);
+    let other = client.submit_material(&WasteType::Metal, &500, &collector, &desc);
+    client.verify_material(&other.id, &recycler);
+    client.claim_incentive_reward(&small.id, &other.id, &collector);
+    let result = client.try_claim_incentive_reward(&small.id, &material.id, &collector);
+    assert_eq!(result, Err(Ok(ScavengerError::InsufficientBudget)));
+
+    client.deactivate_incentive(&small.id, &manufacturer);
+    let result = client.try_claim_incentive_reward(&small.id, &material.id, &collector);
+    assert_eq!(result, Err(Ok(ScavengerError::IncentiveInactive)));
+}
+
+#[test]
+fn test_deregistered_participant_rejected() {
+    let env = Env::default();
+    let (client, _, collector, recycler) = setup(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Paper, &1000, &collector, &desc);
+    client.deregister_participant(&collector);
+
+    let result = client.try_submit_material(&WasteType::Paper, &1000, &collector, &desc);
+    assert_eq!(result, Err(Ok(ScavengerError::NotRegistered)));
+
+    let result = client.try_transfer_waste(&material.id, &collector, &recycler, &desc);
+    assert_eq!(result, Err(Ok(ScavengerError::NotRegistered)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_deregistered_recycler_cannot_recycle() {
+    let env = Env::default();
+    let (client, _, _, recycler) = setup(&env);
+
+    client.deregister_participant(&recycler);
+    client.recycle_waste(&WasteType::Metal, &2000, &recycler, &0, &0);
+}
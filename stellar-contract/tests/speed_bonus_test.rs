@@ -0,0 +1,60 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Ledger, Env, String};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_with_admin;
+
+#[test]
+fn test_verification_within_window_earns_bonus() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    client.set_speed_bonus(&admin, &3600, &20);
+    assert_eq!(client.get_speed_bonus(), Some((3600, 20)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 3600);
+    client.verify_material(&material.id, &recycler);
+
+    // 5kg * 5 * 10 = 250 base points + 20% bonus
+    assert_eq!(client.get_stats(&collector).unwrap().total_points, 300);
+}
+
+#[test]
+fn test_verification_after_window_earns_base_points() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    client.set_speed_bonus(&admin, &3600, &20);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 3601);
+    client.verify_material(&material.id, &recycler);
+
+    assert_eq!(client.get_stats(&collector).unwrap().total_points, 250);
+}
+
+#[test]
+fn test_revocation_reverses_bonus() {
+    let env = Env::default();
+    let (client, admin, collector, recycler) = setup_with_admin(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    client.set_speed_bonus(&admin, &3600, &20);
+
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+    assert_eq!(client.get_stats(&collector).unwrap().total_points, 300);
+
+    client.revoke_verification(&material.id, &recycler);
+    assert_eq!(client.get_stats(&collector).unwrap().total_points, 0);
+    assert_eq!(client.get_participant(&collector).unwrap().total_tokens_earned, 0);
+}
@@ -0,0 +1,37 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_collector;
+
+#[test]
+fn test_stats_by_type_tracked_independently() {
+    let env = Env::default();
+    let (client, collector) = setup_collector(&env);
+    let desc = String::from_str(&env, "Mixed");
+
+    client.submit_material(&WasteType::Paper, &1000, &collector, &desc);
+    client.submit_material(&WasteType::Paper, &2500, &collector, &desc);
+    client.submit_material(&WasteType::Metal, &4000, &collector, &desc);
+    client.submit_material(&WasteType::PetPlastic, &700, &collector, &desc);
+
+    assert_eq!(client.get_stats_by_type(&collector, &WasteType::Paper), (2, 3500));
+    assert_eq!(client.get_stats_by_type(&collector, &WasteType::Metal), (1, 4000));
+    assert_eq!(client.get_stats_by_type(&collector, &WasteType::PetPlastic), (1, 700));
+    assert_eq!(client.get_stats_by_type(&collector, &WasteType::Glass), (0, 0));
+    assert_eq!(client.get_stats_by_type(&collector, &WasteType::Plastic), (0, 0));
+
+    let stats = client.get_stats(&collector).unwrap();
+    assert_eq!(stats.total_weight, 8200);
+}
+
+#[test]
+fn test_stats_by_type_unknown_participant() {
+    let env = Env::default();
+    let (client, _) = setup_collector(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(client.get_stats_by_type(&stranger, &WasteType::Paper), (0, 0));
+}
@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, String, Symbol, TryIntoVal, Val, Vec,
+};
+use stellar_scavngr_contract::{Participant, ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+#[test]
+fn test_submission_saturates_waste_total_and_emits_event() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let collector = Address::generate(&env);
+    env.mock_all_auths();
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    // Preload the participant just below the u128 ceiling
+    env.as_contract(&contract_id, || {
+        let key = (collector.clone(),);
+        let mut participant: Participant = env.storage().instance().get(&key).unwrap();
+        participant.total_waste_processed = u128::MAX - 100;
+        env.storage().instance().set(&key, &participant);
+    });
+
+    let desc = String::from_str(&env, "Heavy");
+    client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+
+    let participant = client.get_participant(&collector).unwrap();
+    assert_eq!(participant.total_waste_processed, u128::MAX);
+
+    let saturated = env.events().all().iter().find(|event| {
+        let symbol: Symbol = event.1.get(1).unwrap().try_into_val(&env).unwrap_or(symbol_short!("none"));
+        symbol == Symbol::new(&env, "stats_saturated")
+    });
+    let event = saturated.expect("stats_saturated event not emitted");
+
+    let expected_topics: Vec<Val> = (symbol_short!("v1"), Symbol::new(&env, "stats_saturated"), collector.clone()).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+
+    let data: (u128, u128) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(data, (u128::MAX, 0));
+}
+
+#[test]
+fn test_no_event_below_ceiling() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let collector = Address::generate(&env);
+    env.mock_all_auths();
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    let desc = String::from_str(&env, "Light");
+    client.submit_material(&WasteType::Paper, &1000, &collector, &desc);
+
+    assert_eq!(client.get_participant(&collector).unwrap().total_waste_processed, 1000);
+    let saturated = env.events().all().iter().any(|event| {
+        let symbol: Symbol = event.1.get(1).unwrap().try_into_val(&env).unwrap_or(symbol_short!("none"));
+        symbol == Symbol::new(&env, "stats_saturated")
+    });
+    assert!(!saturated);
+}
@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env, String, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let collector = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    // Collectors may submit 5kg per hour
+    client.set_submission_cap(&admin, &ParticipantRole::Collector, &5000, &3600);
+
+    (client, admin, collector)
+}
+
+#[test]
+fn test_submission_cap_resets_after_window() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, _, collector) = setup(&env);
+    let description = String::from_str(&env, "Paper");
+
+    client.submit_material(&WasteType::Paper, &3000, &collector, &description);
+    client.submit_material(&WasteType::Paper, &2000, &collector, &description);
+
+    let over = client.try_submit_material(&WasteType::Paper, &100, &collector, &description);
+    assert!(over.is_err());
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 3600);
+    let material = client.submit_material(&WasteType::Paper, &5000, &collector, &description);
+    assert_eq!(material.weight, 5000);
+}
+
+#[test]
+#[should_panic(expected = "Submission cap exceeded")]
+fn test_submission_cap_exceeded_panics() {
+    let env = Env::default();
+    let (client, _, collector) = setup(&env);
+    let description = String::from_str(&env, "Paper");
+
+    client.submit_material(&WasteType::Paper, &4000, &collector, &description);
+    client.submit_material(&WasteType::Paper, &1001, &collector, &description);
+}
+
+#[test]
+fn test_batch_counts_toward_submission_cap() {
+    let env = Env::default();
+    let (client, _, collector) = setup(&env);
+    let description = String::from_str(&env, "Batch");
+
+    let mut batch = Vec::new(&env);
+    batch.push_back((WasteType::Metal, 3000u64, description.clone()));
+    batch.push_back((WasteType::Glass, 3000u64, description.clone()));
+
+    assert!(client.try_submit_materials_batch(&batch, &collector).is_err());
+    assert_eq!(client.get_participant_wastes(&collector).len(), 0);
+}
+
+#[test]
+fn test_submission_cap_only_applies_to_configured_role() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let recycler = Address::generate(&env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    let description = String::from_str(&env, "Metal");
+
+    let material = client.submit_material(&WasteType::Metal, &10000, &recycler, &description);
+    assert_eq!(material.weight, 10000);
+    assert_eq!(client.get_submission_cap(&ParticipantRole::Recycler), None);
+    assert_eq!(client.get_submission_cap(&ParticipantRole::Collector), Some((5000, 3600)));
+}
@@ -0,0 +1,48 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+#[test]
+fn test_recycler_submits_and_verifies() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let recycler = Address::generate(&env);
+    env.mock_all_auths();
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    let desc = String::from_str(&env, "Self-processed");
+    let material = client.submit_and_verify(&WasteType::Metal, &5000, &recycler, &desc);
+
+    assert!(material.verified);
+    assert_eq!(material.submitter, recycler);
+    assert!(client.get_material(&material.id).unwrap().verified);
+
+    let stats = client.get_stats(&recycler).unwrap();
+    assert_eq!(stats.total_submissions, 1);
+    assert_eq!(stats.verified_submissions, 1);
+    assert_eq!(stats.total_weight, 5000);
+
+    // The verification reward is credited to the recycler
+    let participant = client.get_participant(&recycler).unwrap();
+    assert_eq!(participant.total_waste_processed, 5000);
+    assert_eq!(participant.total_tokens_earned, stats.total_points as u128);
+    assert!(participant.total_tokens_earned > 0);
+}
+
+#[test]
+#[should_panic(expected = "Only recyclers can self-verify")]
+fn test_collector_rejected() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let collector = Address::generate(&env);
+    env.mock_all_auths();
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    let desc = String::from_str(&env, "Not allowed");
+    client.submit_and_verify(&WasteType::Paper, &1000, &collector, &desc);
+}
@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use soroban_sdk::{vec, Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn verified_material(
+    env: &Env,
+    client: &ScavengerContractClient,
+    weight: u64,
+    collector: &Address,
+    recycler: &Address,
+) -> u64 {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &weight, collector, &desc);
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+#[test]
+fn test_three_tier_schedule() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    // 1kg+ earns +10%, 5kg+ earns +25%, 10kg+ earns +50%
+    let tiers = vec![&env, (1000u64, 10u32), (5000u64, 25u32), (10000u64, 50u32)];
+    let incentive =
+        client.create_tiered_incentive(&manufacturer, &WasteType::Metal, &100, &100000, &tiers);
+    assert_eq!(client.get_incentive_tiers(&incentive.id), tiers);
+
+    let below = verified_material(&env, &client, 500, &collector, &recycler);
+    let small = verified_material(&env, &client, 2000, &collector, &recycler);
+    let medium = verified_material(&env, &client, 5000, &collector, &recycler);
+    let large = verified_material(&env, &client, 12000, &collector, &recycler);
+
+    // Below the first tier: base 50 only
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &below, &collector), 50);
+    // Small: base 200 + 10%
+    assert_eq!(client.preview_claim(&incentive.id, &small), 220);
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &small, &collector), 220);
+    // Medium, exactly at the threshold: base 500 + 25%
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &medium, &collector), 625);
+    // Large: base 1200 + 50%
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &large, &collector), 1800);
+
+    let remaining = client.get_incentive_by_id(&incentive.id).unwrap().remaining_budget;
+    assert_eq!(remaining, 100000 - 50 - 220 - 625 - 1800);
+}
+
+#[test]
+fn test_untiered_incentive_has_no_bonus() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &100000);
+    assert_eq!(client.get_incentive_tiers(&incentive.id).len(), 0);
+
+    let material = verified_material(&env, &client, 12000, &collector, &recycler);
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &material, &collector), 1200);
+}
+
+#[test]
+#[should_panic(expected = "Tiers must be sorted ascending")]
+fn test_unsorted_tiers_rejected() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let tiers = vec![&env, (5000u64, 25u32), (1000u64, 10u32)];
+    client.create_tiered_incentive(&manufacturer, &WasteType::Metal, &100, &100000, &tiers);
+}
+
+#[test]
+#[should_panic(expected = "Tiers must be sorted ascending")]
+fn test_duplicate_thresholds_rejected() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let tiers = vec![&env, (1000u64, 10u32), (1000u64, 20u32)];
+    client.create_tiered_incentive(&manufacturer, &WasteType::Metal, &100, &100000, &tiers);
+}
@@ -0,0 +1,73 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let verifier = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&verifier, &ParticipantRole::Recycler, &symbol_short!("ver"), &0, &0);
+
+    (client, verifier)
+}
+
+/// Register a collector and verify a metal submission of the given weight
+/// Metal earns 5 * 10 = 50 tokens per kilogram
+fn collector_with_metal(
+    env: &Env,
+    client: &ScavengerContractClient,
+    verifier: &Address,
+    weight: u64,
+) -> Address {
+    let collector = Address::generate(env);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    let description = String::from_str(env, "Metal");
+    let material = client.submit_material(&WasteType::Metal, &weight, &collector, &description);
+    client.verify_material(&material.id, verifier);
+
+    collector
+}
+
+#[test]
+fn test_top_participants_ranked_by_tokens() {
+    let env = Env::default();
+    let (client, verifier) = setup(&env);
+
+    let low = collector_with_metal(&env, &client, &verifier, 1000);
+    let high = collector_with_metal(&env, &client, &verifier, 5000);
+    let mid = collector_with_metal(&env, &client, &verifier, 3000);
+
+    let top = client.get_top_participants(&3);
+    assert_eq!(top.len(), 3);
+    assert_eq!(top.get(0).unwrap(), (high, 250));
+    assert_eq!(top.get(1).unwrap(), (mid, 150));
+    assert_eq!(top.get(2).unwrap(), (low, 50));
+}
+
+#[test]
+fn test_top_participants_respects_limit() {
+    let env = Env::default();
+    let (client, verifier) = setup(&env);
+
+    collector_with_metal(&env, &client, &verifier, 1000);
+    let high = collector_with_metal(&env, &client, &verifier, 5000);
+    let mid = collector_with_metal(&env, &client, &verifier, 3000);
+    collector_with_metal(&env, &client, &verifier, 2000);
+
+    let top = client.get_top_participants(&2);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top.get(0).unwrap(), (high, 250));
+    assert_eq!(top.get(1).unwrap(), (mid, 150));
+
+    // Registry includes the verifier, who earned nothing
+    let all = client.get_top_participants(&10);
+    assert_eq!(all.len(), 5);
+    assert_eq!(all.get(4).unwrap(), (verifier, 0));
+
+    assert_eq!(client.get_top_participants(&0).len(), 0);
+}
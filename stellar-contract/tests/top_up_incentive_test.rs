@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use soroban_sdk::{Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn verified_material(
+    env: &Env,
+    client: &ScavengerContractClient,
+    collector: &Address,
+    recycler: &Address,
+) -> u64 {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &5000, collector, &desc);
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+#[test]
+fn test_top_up_exhausted_incentive_and_claim_again() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    // 5kg * 100 = 500 points exhausts the budget
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &500);
+    let first = verified_material(&env, &client, &collector, &recycler);
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &first, &collector), 500);
+
+    let exhausted = client.get_incentive_by_id(&incentive.id).unwrap();
+    assert_eq!(exhausted.remaining_budget, 0);
+    assert!(!exhausted.active);
+
+    let topped_up = client.top_up_incentive(&incentive.id, &manufacturer, &1000);
+    assert_eq!(topped_up.total_budget, 1500);
+    assert_eq!(topped_up.remaining_budget, 1000);
+    assert!(topped_up.active);
+
+    let second = verified_material(&env, &client, &collector, &recycler);
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &second, &collector), 500);
+    assert_eq!(client.get_incentive_by_id(&incentive.id).unwrap().remaining_budget, 500);
+}
+
+#[test]
+fn test_top_up_does_not_reactivate_manually_deactivated_incentive() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &500);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+
+    let topped_up = client.top_up_incentive(&incentive.id, &manufacturer, &500);
+    assert_eq!(topped_up.remaining_budget, 1000);
+    assert!(!topped_up.active);
+}
+
+#[test]
+#[should_panic(expected = "Additional budget must be greater than zero")]
+fn test_top_up_zero_budget_panics() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &500);
+    client.top_up_incentive(&incentive.id, &manufacturer, &0);
+}
+
+#[test]
+#[should_panic(expected = "Only incentive creator can top up")]
+fn test_top_up_non_creator_panics() {
+    let env = Env::default();
+    let (client, manufacturer, collector, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &500);
+    client.top_up_incentive(&incentive.id, &collector, &500);
+}
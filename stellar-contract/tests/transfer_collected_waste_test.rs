@@ -0,0 +1,160 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn collect(
+    client: &ScavengerContractClient,
+    recycler: &Address,
+    collector: &Address,
+    weight: u128,
+) -> u128 {
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &weight, recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, recycler, collector, &0, &0);
+    waste_id
+}
+
+#[test]
+fn test_aggregates_owned_wastes() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let sources: Vec<u128> = vec![
+        &env,
+        collect(&client, &recycler, &collector, 1000),
+        collect(&client, &recycler, &collector, 2500),
+        collect(&client, &recycler, &collector, 500),
+    ];
+
+    let aggregate_id = client.transfer_collected_waste(
+        &WasteType::Plastic,
+        &sources,
+        &4000,
+        &collector,
+        &manufacturer,
+        &0,
+        &0,
+        &String::from_str(&env, "bulk"),
+    );
+
+    let (aggregate, history) = client.get_waste_full(&aggregate_id).unwrap();
+    assert_eq!(aggregate.weight, 4000);
+    assert_eq!(aggregate.current_owner, manufacturer);
+    assert!(aggregate.is_active);
+    assert_eq!(history.len(), 1);
+
+    for source_id in sources.iter() {
+        let (source, _) = client.get_waste_full(&source_id).unwrap();
+        assert!(!source.is_active);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Amount does not match source weight")]
+fn test_amount_must_match_sources() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let sources: Vec<u128> = vec![&env, collect(&client, &recycler, &collector, 1000)];
+
+    client.transfer_collected_waste(
+        &WasteType::Plastic,
+        &sources,
+        &5000,
+        &collector,
+        &manufacturer,
+        &0,
+        &0,
+        &String::from_str(&env, "bulk"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Collector does not own source waste")]
+fn test_sources_must_be_owned_by_collector() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let owned = collect(&client, &recycler, &collector, 1000);
+    let not_owned = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    let sources: Vec<u128> = vec![&env, owned, not_owned];
+
+    client.transfer_collected_waste(
+        &WasteType::Plastic,
+        &sources,
+        &2000,
+        &collector,
+        &manufacturer,
+        &0,
+        &0,
+        &String::from_str(&env, "bulk"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Duplicate source waste")]
+fn test_duplicate_sources_rejected() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let owned = collect(&client, &recycler, &collector, 1000);
+    let sources: Vec<u128> = vec![&env, owned, owned];
+
+    client.transfer_collected_waste(
+        &WasteType::Plastic,
+        &sources,
+        &2000,
+        &collector,
+        &manufacturer,
+        &0,
+        &0,
+        &String::from_str(&env, "bulk"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Contract is paused")]
+fn test_paused_contract_rejects_aggregation() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+
+    let sources: Vec<u128> = vec![&env, collect(&client, &recycler, &collector, 1000)];
+    client.pause(&admin);
+
+    client.transfer_collected_waste(
+        &WasteType::Plastic,
+        &sources,
+        &1000,
+        &collector,
+        &manufacturer,
+        &0,
+        &0,
+        &String::from_str(&env, "bulk"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_deregistered_manufacturer_rejected() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let sources: Vec<u128> = vec![&env, collect(&client, &recycler, &collector, 1000)];
+    client.deregister_participant(&manufacturer);
+
+    client.transfer_collected_waste(
+        &WasteType::Plastic,
+        &sources,
+        &1000,
+        &collector,
+        &manufacturer,
+        &0,
+        &0,
+        &String::from_str(&env, "bulk"),
+    );
+}
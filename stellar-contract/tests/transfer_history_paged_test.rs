@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+use soroban_sdk::{Address, Env, String};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup_collector_recycler;
+
+/// Submit a material and trade it back and forth `transfers` times
+fn traded_material(
+    env: &Env,
+    client: &ScavengerContractClient,
+    collector: &Address,
+    recycler: &Address,
+    transfers: u32,
+) -> u64 {
+    let desc = String::from_str(env, "Test");
+    let material = client.submit_material(&WasteType::Plastic, &1000, collector, &desc);
+    let note = String::from_str(env, "trade");
+
+    for i in 0..transfers {
+        let (from, to) = if i % 2 == 0 { (collector, recycler) } else { (recycler, collector) };
+        client.transfer_waste(&material.id, from, to, &note);
+    }
+
+    material.id
+}
+
+#[test]
+fn test_paged_history_reconstructs_full_history() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let waste_id = traded_material(&env, &client, &collector, &recycler, 20);
+
+    assert_eq!(client.get_transfer_count(&waste_id), 20);
+
+    let full = client.get_transfer_history(&waste_id);
+    let mut offset = 0;
+    let mut index = 0;
+    loop {
+        let page = client.get_transfer_history_paged(&waste_id, &offset, &6);
+        if page.is_empty() {
+            break;
+        }
+        for transfer in page.iter() {
+            assert_eq!(transfer, full.get(index).unwrap());
+            index += 1;
+        }
+        offset += page.len();
+    }
+    assert_eq!(index, 20);
+
+    // Chronological order alternates between the two parties
+    let first = client.get_transfer_history_paged(&waste_id, &0, &1).get(0).unwrap();
+    let last = client.get_transfer_history_paged(&waste_id, &19, &1).get(0).unwrap();
+    assert_eq!(first.from, collector);
+    assert_eq!(last.from, recycler);
+}
+
+#[test]
+fn test_paged_history_bounds() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let waste_id = traded_material(&env, &client, &collector, &recycler, 3);
+
+    assert_eq!(client.get_transfer_history_paged(&waste_id, &2, &10).len(), 1);
+    assert!(client.get_transfer_history_paged(&waste_id, &3, &10).is_empty());
+    assert!(client.get_transfer_history_paged(&waste_id, &0, &0).is_empty());
+
+    assert_eq!(client.get_transfer_count(&999), 0);
+    assert!(client.get_transfer_history_paged(&999, &0, &10).is_empty());
+}
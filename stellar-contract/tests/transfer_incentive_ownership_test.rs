@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let old_owner = Address::generate(env);
+    let new_owner = Address::generate(env);
+    let collector = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&old_owner, &ParticipantRole::Manufacturer, &symbol_short!("old"), &0, &0);
+    client.register_participant(&new_owner, &ParticipantRole::Manufacturer, &symbol_short!("new"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    (client, old_owner, new_owner, collector)
+}
+
+#[test]
+fn test_transfer_moves_incentive_to_new_owner() {
+    let env = Env::default();
+    let (client, old_owner, new_owner, _) = setup(&env);
+
+    let kept = client.create_incentive(&old_owner, &WasteType::Paper, &50, &1000);
+    let incentive = client.create_incentive(&old_owner, &WasteType::Metal, &100, &5000);
+
+    let transferred = client.transfer_incentive_ownership(&incentive.id, &old_owner, &new_owner);
+    assert_eq!(transferred.rewarder, new_owner);
+
+    let old_list = client.get_incentives_by_rewarder(&old_owner);
+    assert_eq!(old_list.len(), 1);
+    assert_eq!(old_list.get(0).unwrap(), kept.id);
+
+    let new_list = client.get_incentives_by_rewarder(&new_owner);
+    assert_eq!(new_list.len(), 1);
+    assert_eq!(new_list.get(0).unwrap(), incentive.id);
+
+    // The new owner can now manage the incentive
+    let deactivated = client.deactivate_incentive(&incentive.id, &new_owner);
+    assert!(!deactivated.active);
+}
+
+#[test]
+#[should_panic(expected = "Only incentive creator can deactivate")]
+fn test_old_owner_cannot_modify_after_transfer() {
+    let env = Env::default();
+    let (client, old_owner, new_owner, _) = setup(&env);
+
+    let incentive = client.create_incentive(&old_owner, &WasteType::Metal, &100, &5000);
+    client.transfer_incentive_ownership(&incentive.id, &old_owner, &new_owner);
+
+    client.deactivate_incentive(&incentive.id, &old_owner);
+}
+
+#[test]
+#[should_panic(expected = "New rewarder must be a registered manufacturer")]
+fn test_transfer_to_non_manufacturer_panics() {
+    let env = Env::default();
+    let (client, old_owner, _, collector) = setup(&env);
+
+    let incentive = client.create_incentive(&old_owner, &WasteType::Metal, &100, &5000);
+    client.transfer_incentive_ownership(&incentive.id, &old_owner, &collector);
+}
+
+#[test]
+#[should_panic(expected = "Only incentive creator can transfer ownership")]
+fn test_non_owner_cannot_transfer() {
+    let env = Env::default();
+    let (client, old_owner, new_owner, _) = setup(&env);
+
+    let incentive = client.create_incentive(&old_owner, &WasteType::Metal, &100, &5000);
+    client.transfer_incentive_ownership(&incentive.id, &new_owner, &new_owner);
+}
@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+#[test]
+fn test_transfers_from_and_to_for_sender_and_receiver() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let description = String::from_str(&env, "Test");
+    let note = String::from_str(&env, "handoff");
+    env.mock_all_auths();
+
+    client.register_participant(&alice, &ParticipantRole::Recycler, &symbol_short!("alice"), &0, &0);
+    client.register_participant(&bob, &ParticipantRole::Collector, &symbol_short!("bob"), &0, &0);
+    client.register_participant(&carol, &ParticipantRole::Manufacturer, &symbol_short!("carol"), &0, &0);
+
+    let m1 = client.submit_material(&WasteType::Plastic, &1000, &alice, &description);
+    let m2 = client.submit_material(&WasteType::Metal, &2000, &bob, &description);
+
+    // Alice sends m1 to Bob, Bob sends m2 to Alice, Bob forwards m1 to Carol
+    client.transfer_waste(&m1.id, &alice, &bob, &note);
+    client.transfer_waste(&m2.id, &bob, &alice, &note);
+    client.transfer_waste(&m1.id, &bob, &carol, &note);
+
+    let alice_sent = client.get_transfers_from(&alice);
+    assert_eq!(alice_sent.len(), 1);
+    let (waste_id, history) = alice_sent.get(0).unwrap();
+    assert_eq!(waste_id, m1.id);
+    assert_eq!(history.len(), 2);
+
+    let alice_received = client.get_transfers_to(&alice);
+    assert_eq!(alice_received.len(), 1);
+    let (waste_id, history) = alice_received.get(0).unwrap();
+    assert_eq!(waste_id, m2.id);
+    assert_eq!(history.get(0).unwrap().from, bob);
+
+    let bob_sent = client.get_transfers_from(&bob);
+    assert_eq!(bob_sent.len(), 2);
+    assert_eq!(bob_sent.get(0).unwrap().0, m2.id);
+    assert_eq!(bob_sent.get(1).unwrap().0, m1.id);
+
+    let bob_received = client.get_transfers_to(&bob);
+    assert_eq!(bob_received.len(), 1);
+    assert_eq!(bob_received.get(0).unwrap().0, m1.id);
+
+    let carol_received = client.get_transfers_to(&carol);
+    assert_eq!(carol_received.len(), 1);
+    assert_eq!(client.get_transfers_from(&carol).len(), 0);
+}
+
+#[test]
+fn test_transfers_index_includes_v2_transfers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let recycler = Address::generate(&env);
+    let collector = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &1500, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &10, &20);
+
+    let sent = client.get_transfers_from(&recycler);
+    assert_eq!(sent.len(), 1);
+    let (id, history) = sent.get(0).unwrap();
+    assert_eq!(id as u128, waste_id);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().to, collector);
+
+    let received = client.get_transfers_to(&collector);
+    assert_eq!(received.len(), 1);
+    assert_eq!(received.get(0).unwrap().0 as u128, waste_id);
+}
+
+#[test]
+fn test_transfers_empty_for_unknown_address() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let stranger = Address::generate(&env);
+
+    assert_eq!(client.get_transfers_from(&stranger).len(), 0);
+    assert_eq!(client.get_transfers_to(&stranger).len(), 0);
+}
@@ -0,0 +1,46 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Ledger, Address, Env};
+use stellar_scavngr_contract::{ScavengerContractClient, WasteType};
+
+mod common;
+use common::setup;
+
+fn transfer_at(
+    env: &Env,
+    client: &ScavengerContractClient,
+    waste_id: u128,
+    from: &Address,
+    to: &Address,
+    timestamp: u64,
+) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+    client.transfer_waste_v2(&waste_id, from, to, &0, &0);
+}
+
+#[test]
+fn test_velocity_averages_intervals() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &2000, &recycler, &0, &0);
+    transfer_at(&env, &client, waste_id, &recycler, &collector, 1000);
+    transfer_at(&env, &client, waste_id, &collector, &manufacturer, 1600);
+
+    assert_eq!(client.get_transfer_velocity(&waste_id), (2, 600));
+}
+
+#[test]
+fn test_velocity_needs_two_transfers() {
+    let env = Env::default();
+    let (client, _, collector, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &2000, &recycler, &0, &0);
+    assert_eq!(client.get_transfer_velocity(&waste_id), (0, 0));
+
+    transfer_at(&env, &client, waste_id, &recycler, &collector, 1000);
+    assert_eq!(client.get_transfer_velocity(&waste_id), (0, 0));
+
+    // Unknown wastes report no movement
+    assert_eq!(client.get_transfer_velocity(&999), (0, 0));
+}
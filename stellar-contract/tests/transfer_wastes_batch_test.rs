@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+
+    (client, collector, manufacturer)
+}
+
+#[test]
+fn test_transfer_five_materials_in_one_call() {
+    let env = Env::default();
+    let (client, collector, manufacturer) = setup(&env);
+    let description = String::from_str(&env, "Bundle");
+
+    let mut ids = Vec::new(&env);
+    for _ in 0..5 {
+        let material = client.submit_material(&WasteType::Plastic, &1000, &collector, &description);
+        ids.push_back(material.id);
+    }
+
+    let note = String::from_str(&env, "Bulk pickup");
+    let transferred = client.transfer_wastes_batch(&ids, &collector, &manufacturer, &note);
+
+    assert_eq!(transferred.len(), 5);
+    for material in transferred.iter() {
+        assert_eq!(material.submitter, manufacturer);
+        assert_eq!(client.get_waste(&material.id).unwrap().submitter, manufacturer);
+        assert_eq!(client.get_transfer_history(&material.id).len(), 1);
+    }
+
+    assert_eq!(client.get_participant_wastes(&collector).len(), 0);
+    assert_eq!(client.get_participant_wastes(&manufacturer), ids);
+}
+
+#[test]
+fn test_transfer_batch_with_unowned_id_changes_nothing() {
+    let env = Env::default();
+    let (client, collector, manufacturer) = setup(&env);
+    let other = Address::generate(&env);
+    client.register_participant(&other, &ParticipantRole::Collector, &symbol_short!("other"), &0, &0);
+    let description = String::from_str(&env, "Bundle");
+
+    let m1 = client.submit_material(&WasteType::Paper, &1000, &collector, &description);
+    let m2 = client.submit_material(&WasteType::Metal, &1000, &other, &description);
+    let m3 = client.submit_material(&WasteType::Glass, &1000, &collector, &description);
+
+    let mut ids = Vec::new(&env);
+    ids.push_back(m1.id);
+    ids.push_back(m2.id);
+    ids.push_back(m3.id);
+
+    let note = String::from_str(&env, "Bulk pickup");
+    let result = client.try_transfer_wastes_batch(&ids, &collector, &manufacturer, &note);
+    assert!(result.is_err());
+
+    assert_eq!(client.get_waste(&m1.id).unwrap().submitter, collector);
+    assert_eq!(client.get_waste(&m2.id).unwrap().submitter, other);
+    assert_eq!(client.get_waste(&m3.id).unwrap().submitter, collector);
+    assert_eq!(client.get_participant_wastes(&collector).len(), 2);
+    assert_eq!(client.get_participant_wastes(&manufacturer).len(), 0);
+    assert_eq!(client.get_transfer_history(&m1.id).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate waste ID in batch")]
+fn test_duplicate_ids_abort_batch() {
+    let env = Env::default();
+    let (client, collector, manufacturer) = setup(&env);
+    let description = String::from_str(&env, "Bundle");
+
+    let material = client.submit_material(&WasteType::Plastic, &1000, &collector, &description);
+    let mut ids = Vec::new(&env);
+    ids.push_back(material.id);
+    ids.push_back(material.id);
+
+    client.transfer_wastes_batch(&ids, &collector, &manufacturer, &String::from_str(&env, "Twice"));
+}
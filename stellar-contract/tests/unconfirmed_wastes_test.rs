@@ -0,0 +1,57 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let owner = Address::generate(env);
+    let other = Address::generate(env);
+    let confirmer = Address::generate(env);
+    env.mock_all_auths();
+
+    client.initialize_admin(&admin);
+    client.register_participant(&owner, &ParticipantRole::Recycler, &symbol_short!("owner"), &0, &0);
+    client.register_participant(&other, &ParticipantRole::Recycler, &symbol_short!("other"), &0, &0);
+    client.register_participant(&confirmer, &ParticipantRole::Collector, &symbol_short!("conf"), &0, &0);
+
+    (client, admin, owner, other, confirmer)
+}
+
+#[test]
+fn test_lists_only_active_unconfirmed_wastes() {
+    let env = Env::default();
+    let (client, admin, owner, other, confirmer) = setup(&env);
+
+    let first = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &0, &0);
+    let confirmed = client.recycle_waste(&WasteType::Metal, &2000, &owner, &0, &0);
+    let deactivated = client.recycle_waste(&WasteType::Glass, &3000, &other, &0, &0);
+    let last = client.recycle_waste(&WasteType::Paper, &4000, &other, &0, &0);
+
+    client.confirm_waste_details(&confirmed, &confirmer);
+    client.deactivate_waste(&deactivated, &admin);
+
+    assert_eq!(client.get_unconfirmed_wastes(&10), vec![&env, first, last]);
+    assert_eq!(client.get_unconfirmed_wastes(&1), vec![&env, first]);
+    assert_eq!(client.get_unconfirmed_wastes(&0).len(), 0);
+}
+
+#[test]
+fn test_lists_unconfirmed_wastes_for_owner() {
+    let env = Env::default();
+    let (client, _, owner, other, confirmer) = setup(&env);
+
+    let first = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &0, &0);
+    let confirmed = client.recycle_waste(&WasteType::Metal, &2000, &owner, &0, &0);
+    let third = client.recycle_waste(&WasteType::Paper, &1500, &owner, &0, &0);
+    client.recycle_waste(&WasteType::Glass, &3000, &other, &0, &0);
+
+    client.confirm_waste_details(&confirmed, &confirmer);
+
+    assert_eq!(client.get_unconfirmed_wastes_for(&owner, &10), vec![&env, first, third]);
+    assert_eq!(client.get_unconfirmed_wastes_for(&owner, &1), vec![&env, first]);
+    assert_eq!(client.get_unconfirmed_wastes_for(&confirmer, &10).len(), 0);
+}
@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
 use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
 
 #[test]
@@ -13,29 +13,29 @@ fn test_update_incentive_success() {
     env.mock_all_auths();
 
     // Register manufacturer
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
-    // Create incentive using the correct signature: (waste_type, reward, max_waste_amount, rewarder)
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
-    assert_eq!(incentive.reward, 100);
-    assert_eq!(incentive.max_waste_amount, 5000);
+    // Create incentive: (rewarder, waste_type, reward_points, total_budget)
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &5000);
+    assert_eq!(incentive.reward_points, 100);
+    assert_eq!(incentive.total_budget, 5000);
 
     // Update incentive
-    let updated = client.update_incentive(&incentive.id, &200, &10000);
-    assert_eq!(updated.reward, 200);
-    assert_eq!(updated.max_waste_amount, 10000);
+    let updated = client.update_incentive(&incentive.id, &200);
+    assert_eq!(updated.reward_points, 200);
 
     // Verify immutable fields unchanged
     assert_eq!(updated.id, incentive.id);
     assert_eq!(updated.waste_type, incentive.waste_type);
     assert_eq!(updated.rewarder, incentive.rewarder);
-    assert_eq!(updated.is_active, incentive.is_active);
+    assert_eq!(updated.total_budget, incentive.total_budget);
+    assert_eq!(updated.remaining_budget, incentive.remaining_budget);
+    assert_eq!(updated.active, incentive.active);
     assert_eq!(updated.created_at, incentive.created_at);
 
     // Verify persistence
     let retrieved = client.get_incentive_by_id(&incentive.id).unwrap();
-    assert_eq!(retrieved.reward, 200);
-    assert_eq!(retrieved.max_waste_amount, 10000);
+    assert_eq!(retrieved.reward_points, 200);
 }
 
 #[test]
@@ -48,10 +48,10 @@ fn test_update_incentive_not_found() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
     // Try to update non-existent incentive
-    client.update_incentive(&999, &100, &5000);
+    client.update_incentive(&999, &100);
 }
 
 #[test]
@@ -64,14 +64,14 @@ fn test_update_incentive_inactive() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
     // Create and deactivate incentive
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &5000);
     client.update_incentive_status(&incentive.id, &false);
 
     // Try to update inactive incentive
-    client.update_incentive(&incentive.id, &200, &10000);
+    client.update_incentive(&incentive.id, &200);
 }
 
 #[test]
@@ -84,17 +84,16 @@ fn test_update_incentive_zero_reward() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &5000);
 
     // Try to update with zero reward
-    client.update_incentive(&incentive.id, &0, &5000);
+    client.update_incentive(&incentive.id, &0);
 }
 
 #[test]
-#[should_panic(expected = "Max waste amount must be greater than zero")]
-fn test_update_incentive_zero_max_waste() {
+fn test_update_incentive_reactivated() {
     let env = Env::default();
     let contract_id = env.register_contract(None, ScavengerContract);
     let client = ScavengerContractClient::new(&env, &contract_id);
@@ -102,12 +101,15 @@ fn test_update_incentive_zero_max_waste() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &5000);
+    client.update_incentive_status(&incentive.id, &false);
+    client.update_incentive_status(&incentive.id, &true);
 
-    // Try to update with zero max_waste_amount
-    client.update_incentive(&incentive.id, &100, &0);
+    let updated = client.update_incentive(&incentive.id, &150);
+    assert!(updated.active);
+    assert_eq!(updated.reward_points, 150);
 }
 
 #[test]
@@ -119,14 +121,13 @@ fn test_update_incentive_minimum_values() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &5000);
 
-    // Update with minimum valid values
-    let updated = client.update_incentive(&incentive.id, &1, &1);
-    assert_eq!(updated.reward, 1);
-    assert_eq!(updated.max_waste_amount, 1);
+    // Update with minimum valid value
+    let updated = client.update_incentive(&incentive.id, &1);
+    assert_eq!(updated.reward_points, 1);
 }
 
 #[test]
@@ -138,22 +139,20 @@ fn test_update_incentive_multiple_times() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("p"), &0, &0);
 
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &5000);
 
     // First update
-    let updated1 = client.update_incentive(&incentive.id, &200, &10000);
-    assert_eq!(updated1.reward, 200);
-    assert_eq!(updated1.max_waste_amount, 10000);
+    let updated1 = client.update_incentive(&incentive.id, &200);
+    assert_eq!(updated1.reward_points, 200);
 
     // Second update
-    let updated2 = client.update_incentive(&incentive.id, &300, &15000);
-    assert_eq!(updated2.reward, 300);
-    assert_eq!(updated2.max_waste_amount, 15000);
+    let updated2 = client.update_incentive(&incentive.id, &300);
+    assert_eq!(updated2.reward_points, 300);
 
     // Third update
-    let updated3 = client.update_incentive(&incentive.id, &400, &20000);
-    assert_eq!(updated3.reward, 400);
-    assert_eq!(updated3.max_waste_amount, 20000);
+    let updated3 = client.update_incentive(&incentive.id, &400);
+    assert_eq!(updated3.reward_points, 400);
+    assert_eq!(updated3.remaining_budget, 5000);
 }
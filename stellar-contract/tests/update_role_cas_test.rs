@@ -0,0 +1,49 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let participant = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&participant, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    (client, participant)
+}
+
+#[test]
+fn test_cas_succeeds_with_expected_role() {
+    let env = Env::default();
+    let (client, participant) = setup(&env);
+
+    let updated = client.update_role_cas(&participant, &ParticipantRole::Collector, &ParticipantRole::Recycler);
+    assert_eq!(updated.role, ParticipantRole::Recycler);
+    assert_eq!(client.get_participant(&participant).unwrap().role, ParticipantRole::Recycler);
+}
+
+#[test]
+#[should_panic(expected = "Role precondition failed")]
+fn test_cas_fails_after_concurrent_change() {
+    let env = Env::default();
+    let (client, participant) = setup(&env);
+
+    // Another transaction changed the role first
+    client.update_role(&participant, &ParticipantRole::Manufacturer);
+
+    client.update_role_cas(&participant, &ParticipantRole::Collector, &ParticipantRole::Recycler);
+}
+
+#[test]
+fn test_failed_cas_leaves_role_unchanged() {
+    let env = Env::default();
+    let (client, participant) = setup(&env);
+    client.update_role(&participant, &ParticipantRole::Manufacturer);
+
+    let result = client.try_update_role_cas(&participant, &ParticipantRole::Collector, &ParticipantRole::Recycler);
+    assert!(result.is_err());
+    assert_eq!(client.get_participant(&participant).unwrap().role, ParticipantRole::Manufacturer);
+}
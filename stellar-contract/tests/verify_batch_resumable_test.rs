@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+use soroban_sdk::{Address, Env, String, Vec};
+use stellar_scavngr_contract::{ScavengerContractClient, ScavengerError, WasteType};
+
+mod common;
+use common::setup_collector_recycler;
+
+fn submit_batch(env: &Env, client: &ScavengerContractClient, collector: &Address, count: u32) -> Vec<u64> {
+    let desc = String::from_str(env, "Batch");
+    let mut ids = Vec::new(env);
+    for _ in 0..count {
+        ids.push_back(client.submit_material(&WasteType::Plastic, &1000, collector, &desc).id);
+    }
+    ids
+}
+
+#[test]
+fn test_verify_ten_in_two_chunks() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let ids = submit_batch(&env, &client, &collector, 10);
+
+    let (first, cursor) = client.verify_materials_batch_resumable(&ids, &recycler, &0, &5);
+    assert_eq!(first.len(), 5);
+    assert_eq!(cursor, 5);
+    assert_eq!(first.get(0).unwrap().id, ids.get(0).unwrap());
+    assert!(!client.get_material(&ids.get(5).unwrap()).unwrap().verified);
+
+    let (second, cursor) = client.verify_materials_batch_resumable(&ids, &recycler, &cursor, &5);
+    assert_eq!(second.len(), 5);
+    assert_eq!(cursor, 10);
+    assert_eq!(second.get(0).unwrap().id, ids.get(5).unwrap());
+
+    for id in ids.iter() {
+        assert!(client.get_material(&id).unwrap().verified);
+    }
+    assert_eq!(client.get_stats(&collector).unwrap().verified_submissions, 10);
+}
+
+#[test]
+fn test_cursor_clamped_to_batch_length() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let ids = submit_batch(&env, &client, &collector, 3);
+
+    let (results, cursor) = client.verify_materials_batch_resumable(&ids, &recycler, &1, &10);
+    assert_eq!(results.len(), 2);
+    assert_eq!(cursor, 3);
+
+    // Resuming from the end does nothing
+    let (results, cursor) = client.verify_materials_batch_resumable(&ids, &recycler, &cursor, &10);
+    assert_eq!(results.len(), 0);
+    assert_eq!(cursor, 3);
+}
+
+#[test]
+fn test_stale_cursor_does_not_reverify() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let ids = submit_batch(&env, &client, &collector, 4);
+
+    client.verify_materials_batch_resumable(&ids, &recycler, &0, &4);
+    let points = client.get_stats(&collector).unwrap().total_points;
+    let tokens = client.get_participant(&collector).unwrap().total_tokens_earned;
+
+    // Resuming from a stale cursor and verifying singly again both leave rewards alone
+    let (results, cursor) = client.verify_materials_batch_resumable(&ids, &recycler, &0, &4);
+    assert_eq!(results.len(), 0);
+    assert_eq!(cursor, 4);
+    assert_eq!(
+        client.try_verify_material(&ids.get(0).unwrap(), &recycler),
+        Err(Ok(ScavengerError::AlreadyVerified))
+    );
+
+    let stats = client.get_stats(&collector).unwrap();
+    assert_eq!(stats.total_points, points);
+    assert_eq!(stats.verified_submissions, 4);
+    assert_eq!(client.get_participant(&collector).unwrap().total_tokens_earned, tokens);
+}
+
+#[test]
+#[should_panic(expected = "Only recyclers can verify materials")]
+fn test_non_recycler_cannot_verify() {
+    let env = Env::default();
+    let (client, collector, _) = setup_collector_recycler(&env);
+    let ids = submit_batch(&env, &client, &collector, 2);
+
+    client.verify_materials_batch_resumable(&ids, &collector, &0, &2);
+}
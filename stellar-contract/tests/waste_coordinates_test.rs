@@ -0,0 +1,33 @@
+#![cfg(test)]
+
+use soroban_sdk::{vec, Env};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_recycler;
+
+#[test]
+fn test_coordinates_match_recorded_location() {
+    let env = Env::default();
+    let (client, recycler) = setup_recycler(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &1500, &recycler, &6_524_400, &3_379_200);
+
+    assert_eq!(client.get_waste_coordinates(&waste_id), Some((6_524_400, 3_379_200)));
+    assert_eq!(client.get_waste_coordinates(&999), None);
+}
+
+#[test]
+fn test_coordinates_batch_preserves_order() {
+    let env = Env::default();
+    let (client, recycler) = setup_recycler(&env);
+
+    let first = client.recycle_waste(&WasteType::Paper, &1000, &recycler, &-33_868_800, &151_209_300);
+    let second = client.recycle_waste(&WasteType::Metal, &2000, &recycler, &51_507_400, &-127_800);
+
+    let coordinates = client.get_coordinates_batch(&vec![&env, second, 999, first]);
+    assert_eq!(coordinates.len(), 3);
+    assert_eq!(coordinates.get(0).unwrap(), Some((51_507_400, -127_800)));
+    assert_eq!(coordinates.get(1).unwrap(), None);
+    assert_eq!(coordinates.get(2).unwrap(), Some((-33_868_800, 151_209_300)));
+}
@@ -0,0 +1,46 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+#[test]
+fn test_get_waste_full_returns_waste_and_history() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let recycler = Address::generate(&env);
+    let collector = Address::generate(&env);
+    let manufacturer = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &1500, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &10, &20);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &30, &40);
+
+    let (waste, history) = client.get_waste_full(&waste_id).unwrap();
+    assert_eq!(waste.waste_id, waste_id);
+    assert_eq!(waste.weight, 1500);
+    assert_eq!(waste.current_owner, manufacturer);
+
+    assert_eq!(history.len(), 2);
+    let first = history.get(0).unwrap();
+    assert_eq!(first.from, recycler);
+    assert_eq!(first.to, collector);
+    let second = history.get(1).unwrap();
+    assert_eq!(second.from, collector);
+    assert_eq!(second.to, manufacturer);
+}
+
+#[test]
+fn test_get_waste_full_none_for_missing_waste() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    assert!(client.get_waste_full(&42).is_none());
+}
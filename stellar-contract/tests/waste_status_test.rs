@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, WasteStatus, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let collector = Address::generate(env);
+    let recycler = Address::generate(env);
+    let manufacturer = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("mfr"), &0, &0);
+
+    (client, collector, recycler, manufacturer)
+}
+
+#[test]
+fn test_advance_through_full_lifecycle() {
+    let env = Env::default();
+    let (client, collector, recycler, _) = setup(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    assert_eq!(material.status, WasteStatus::Submitted);
+
+    let collected = client.advance_waste_status(&material.id, &collector);
+    assert_eq!(collected.status, WasteStatus::Collected);
+
+    let processed = client.advance_waste_status(&material.id, &recycler);
+    assert_eq!(processed.status, WasteStatus::Processed);
+
+    let recycled = client.advance_waste_status(&material.id, &recycler);
+    assert_eq!(recycled.status, WasteStatus::Recycled);
+    assert_eq!(client.get_waste(&material.id).unwrap().status, WasteStatus::Recycled);
+}
+
+#[test]
+#[should_panic(expected = "Material status is final")]
+fn test_cannot_advance_past_recycled() {
+    let env = Env::default();
+    let (client, collector, recycler, _) = setup(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.advance_waste_status(&material.id, &collector);
+    client.advance_waste_status(&material.id, &recycler);
+    client.advance_waste_status(&material.id, &recycler);
+
+    client.advance_waste_status(&material.id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Caller cannot advance material to next status")]
+fn test_collector_cannot_process() {
+    let env = Env::default();
+    let (client, collector, _, _) = setup(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.advance_waste_status(&material.id, &collector);
+
+    client.advance_waste_status(&material.id, &collector);
+}
+
+#[test]
+#[should_panic(expected = "Caller cannot advance material to next status")]
+fn test_manufacturer_cannot_collect() {
+    let env = Env::default();
+    let (client, collector, _, manufacturer) = setup(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.advance_waste_status(&material.id, &manufacturer);
+}
+
+#[test]
+#[should_panic(expected = "Cannot advance retired material")]
+fn test_cannot_advance_retired_material() {
+    let env = Env::default();
+    let (client, collector, _, _) = setup(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.retire_material(&material.id, &collector);
+
+    client.advance_waste_status(&material.id, &collector);
+}
+
+#[test]
+#[should_panic(expected = "Only the owner or a recycler can advance material")]
+fn test_collector_cannot_advance_others_material() {
+    let env = Env::default();
+    let (client, collector, _, _) = setup(&env);
+    let other = Address::generate(&env);
+    client.register_participant(&other, &ParticipantRole::Collector, &symbol_short!("oth"), &0, &0);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.advance_waste_status(&material.id, &other);
+}
+
+#[test]
+fn test_admin_can_advance_any_material() {
+    let env = Env::default();
+    let (client, collector, _, _) = setup(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    assert_eq!(client.advance_waste_status(&material.id, &admin).status, WasteStatus::Collected);
+    assert_eq!(client.advance_waste_status(&material.id, &admin).status, WasteStatus::Processed);
+}
+
+#[test]
+#[should_panic(expected = "Material status is final")]
+fn test_rejected_material_leaves_lifecycle() {
+    let env = Env::default();
+    let (client, collector, recycler, _) = setup(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    client.advance_waste_status(&material.id, &collector);
+    assert_eq!(client.reject_material(&material.id, &recycler).status, WasteStatus::Rejected);
+
+    client.advance_waste_status(&material.id, &recycler);
+}
@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use soroban_sdk::{vec, Env, String};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup_collector_recycler;
+
+#[test]
+fn test_filters_by_verification_and_type() {
+    let env = Env::default();
+    let (client, collector, recycler) = setup_collector_recycler(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let metal_verified = client.submit_material(&WasteType::Metal, &1000, &collector, &desc);
+    let metal_unverified = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    let paper_verified = client.submit_material(&WasteType::Paper, &3000, &collector, &desc);
+    client.verify_material(&metal_verified.id, &recycler);
+    client.verify_material(&paper_verified.id, &recycler);
+
+    let ids = vec![&env, metal_verified.id, metal_unverified.id, paper_verified.id];
+
+    // Verified metal only
+    let results = client.get_wastes_batch_filtered(&ids, &true, &Some(WasteType::Metal));
+    assert_eq!(results.len(), 1);
+    assert_eq!(results.get(0).unwrap().id, metal_verified.id);
+
+    // Any verified material
+    let results = client.get_wastes_batch_filtered(&ids, &true, &None);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().id, metal_verified.id);
+    assert_eq!(results.get(1).unwrap().id, paper_verified.id);
+
+    // Any metal, verified or not
+    let results = client.get_wastes_batch_filtered(&ids, &false, &Some(WasteType::Metal));
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().id, metal_verified.id);
+    assert_eq!(results.get(1).unwrap().id, metal_unverified.id);
+}
+
+#[test]
+fn test_no_filters_skips_missing_ids() {
+    let env = Env::default();
+    let (client, collector, _) = setup_collector_recycler(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Glass, &1000, &collector, &desc);
+
+    let ids = vec![&env, 999, material.id, 1000];
+    let results = client.get_wastes_batch_filtered(&ids, &false, &None);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results.get(0).unwrap().id, material.id);
+}
+
+#[test]
+fn test_no_matches_returns_empty() {
+    let env = Env::default();
+    let (client, collector, _) = setup_collector_recycler(&env);
+    let desc = String::from_str(&env, "Test");
+
+    let material = client.submit_material(&WasteType::Plastic, &1000, &collector, &desc);
+
+    let ids = vec![&env, material.id];
+    assert_eq!(client.get_wastes_batch_filtered(&ids, &true, &None).len(), 0);
+    assert_eq!(client.get_wastes_batch_filtered(&ids, &false, &Some(WasteType::Paper)).len(), 0);
+}
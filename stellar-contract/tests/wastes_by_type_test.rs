@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let user = Address::generate(env);
+    env.mock_all_auths();
+
+    client.register_participant(&user, &ParticipantRole::Collector, &symbol_short!("user"), &0, &0);
+
+    (client, user)
+}
+
+#[test]
+fn test_get_wastes_by_type_returns_matching_ids_in_order() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    let description = String::from_str(&env, "Mixed");
+
+    let p1 = client.submit_material(&WasteType::Paper, &1000, &user, &description);
+    let m1 = client.submit_material(&WasteType::Metal, &1000, &user, &description);
+    let p2 = client.submit_material(&WasteType::Paper, &1000, &user, &description);
+
+    let mut batch = Vec::new(&env);
+    batch.push_back((WasteType::Glass, 1000u64, description.clone()));
+    batch.push_back((WasteType::Paper, 1000u64, description.clone()));
+    let batch_materials = client.submit_materials_batch(&batch, &user);
+    let p3 = batch_materials.get(1).unwrap();
+
+    let mut expected = Vec::new(&env);
+    expected.push_back(p1.id);
+    expected.push_back(p2.id);
+    expected.push_back(p3.id);
+    assert_eq!(client.get_wastes_by_type(&WasteType::Paper), expected);
+
+    let metal = client.get_wastes_by_type(&WasteType::Metal);
+    assert_eq!(metal.len(), 1);
+    assert_eq!(metal.get(0).unwrap(), m1.id);
+
+    assert_eq!(client.get_wastes_by_type(&WasteType::Glass).len(), 1);
+    assert_eq!(client.get_wastes_by_type(&WasteType::PetPlastic).len(), 0);
+}
+
+#[test]
+fn test_get_wastes_by_type_paged() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    let description = String::from_str(&env, "Paper");
+
+    let mut ids = Vec::new(&env);
+    for _ in 0..5 {
+        ids.push_back(client.submit_material(&WasteType::Paper, &1000, &user, &description).id);
+    }
+
+    let page1 = client.get_wastes_by_type_paged(&WasteType::Paper, &0, &2);
+    assert_eq!(page1.len(), 2);
+    assert_eq!(page1.get(0).unwrap(), ids.get(0).unwrap());
+    assert_eq!(page1.get(1).unwrap(), ids.get(1).unwrap());
+
+    let page3 = client.get_wastes_by_type_paged(&WasteType::Paper, &4, &2);
+    assert_eq!(page3.len(), 1);
+    assert_eq!(page3.get(0).unwrap(), ids.get(4).unwrap());
+
+    assert_eq!(client.get_wastes_by_type_paged(&WasteType::Paper, &5, &2).len(), 0);
+}
@@ -0,0 +1,33 @@
+#![cfg(test)]
+
+use soroban_sdk::{Env, String};
+use stellar_scavngr_contract::{WasteType, WeightUnit};
+
+mod common;
+use common::setup_collector;
+
+#[test]
+fn test_kilograms_and_grams_store_same_weight() {
+    let env = Env::default();
+    let (client, collector) = setup_collector(&env);
+    let desc = String::from_str(&env, "Cans");
+
+    let in_kg = client.submit_material_with_unit(&WasteType::Metal, &5, &WeightUnit::Kilograms, &collector, &desc);
+    let in_g = client.submit_material_with_unit(&WasteType::Metal, &5000, &WeightUnit::Grams, &collector, &desc);
+    let default = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+
+    assert_eq!(in_kg.weight, 5000);
+    assert_eq!(in_kg.weight, in_g.weight);
+    assert_eq!(in_g.weight, default.weight);
+    assert_eq!(client.get_stats(&collector).unwrap().total_weight, 15000);
+}
+
+#[test]
+#[should_panic(expected = "Weight overflow converting to grams")]
+fn test_kilogram_overflow_panics() {
+    let env = Env::default();
+    let (client, collector) = setup_collector(&env);
+    let desc = String::from_str(&env, "Huge");
+
+    client.submit_material_with_unit(&WasteType::Metal, &u64::MAX, &WeightUnit::Kilograms, &collector, &desc);
+}
@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::WasteType;
+
+mod common;
+use common::setup;
+
+#[test]
+fn test_withdraw_after_deactivation() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &2000);
+
+    // 5kg * 100 = 500 points paid out before the withdrawal
+    let desc = String::from_str(&env, "Test");
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    client.verify_material(&material.id, &recycler);
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+    assert_eq!(client.withdraw_incentive_budget(&incentive.id, &manufacturer), 1500);
+
+    let withdrawn = client.get_incentive_by_id(&incentive.id).unwrap();
+    assert_eq!(withdrawn.remaining_budget, 0);
+    assert_eq!(withdrawn.total_budget, 2000);
+    assert!(!withdrawn.active);
+
+    // Utilization counts only what was paid out
+    assert_eq!(client.get_incentive_utilization(&incentive.id), (500, 2000, 25));
+
+    // Nothing left on a second withdrawal
+    assert_eq!(client.withdraw_incentive_budget(&incentive.id, &manufacturer), 0);
+}
+
+#[test]
+#[should_panic(expected = "Deactivate before withdrawing")]
+fn test_withdraw_blocked_while_active() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &50, &1000);
+    client.withdraw_incentive_budget(&incentive.id, &manufacturer);
+}
+
+#[test]
+#[should_panic(expected = "Only incentive creator can withdraw")]
+fn test_withdraw_by_other_rewarder() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &50, &1000);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+
+    let other = Address::generate(&env);
+    client.withdraw_incentive_budget(&incentive.id, &other);
+}
+
+#[test]
+#[should_panic(expected = "Cannot reactivate exhausted incentive")]
+fn test_withdrawn_incentive_cannot_be_reactivated() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Glass, &50, &1000);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+    client.withdraw_incentive_budget(&incentive.id, &manufacturer);
+
+    client.reactivate_incentive(&incentive.id, &manufacturer);
+}